@@ -1,9 +1,10 @@
 use iced::alignment::Horizontal;
 use iced::event::{listen_with, Event};
 use iced::widget::{button, column, container, row, text, Column};
-use iced::{keyboard, window, Element, Length::Fill, Subscription, Task, Theme};
-use keybinds::{Key, KeyInput, Keybinds};
-use std::fmt::Write;
+use iced::{window, Element, Length::Fill, Subscription, Task, Theme};
+use keybinds::iced::{subscription, KeybindsEvent};
+use keybinds::Keybinds;
+use std::sync::Arc;
 
 // Actions dispatched by the key bindings
 #[derive(Clone, Copy, Debug)]
@@ -17,15 +18,16 @@ enum Action {
 #[derive(Clone, Debug)]
 enum Message {
     WindowOpen(window::Id),
-    KeyEvent(keyboard::Event),
+    Keybinds(KeybindsEvent<Action>),
     Reset,
 }
 
 struct Example {
     window_id: window::Id,
-    ongoing_input: String,
+    pending_input: String,
     last_action: String,
-    keybinds: Keybinds<Action>,
+    keybinds: Arc<Keybinds<Action>>,
+    resets: usize,
     maximized: bool,
     theme: Theme,
     help: Vec<(String, Action)>,
@@ -44,8 +46,9 @@ impl Default for Example {
 
         Self {
             window_id: window::Id::unique(),
-            ongoing_input: "".to_string(),
+            pending_input: "".to_string(),
             last_action: "".to_string(),
+            resets: 0,
             maximized: false,
             theme: Theme::Dark,
             help: keybinds
@@ -53,7 +56,7 @@ impl Default for Example {
                 .iter()
                 .map(|b| (format!("{}", b.seq), b.action))
                 .collect(),
-            keybinds,
+            keybinds: Arc::new(keybinds),
         }
     }
 }
@@ -62,43 +65,40 @@ impl Example {
     fn update(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::WindowOpen(id) => self.window_id = id,
-            Message::KeyEvent(event) => {
-                let input = KeyInput::from(&event);
-                if input.key() != Key::Ignored {
-                    println!("Key input: {input:?}");
-                    if !self.keybinds.is_ongoing() {
-                        self.last_action.clear();
-                        self.ongoing_input.clear();
-                    }
-                    if !self.ongoing_input.is_empty() {
-                        self.ongoing_input.push_str(" → ");
-                    }
-                    write!(self.ongoing_input, "{input:}").unwrap();
+            Message::Keybinds(KeybindsEvent::Pending(inputs)) => {
+                // Display the ongoing key sequence
+                self.pending_input = inputs
+                    .iter()
+                    .map(|input| input.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" → ");
+                if !inputs.is_empty() {
+                    self.last_action.clear();
                 }
+            }
+            Message::Keybinds(KeybindsEvent::Action(action)) => {
+                self.last_action = format!("{action:?}");
 
-                if let Some(action) = self.keybinds.dispatch(event) {
-                    self.last_action = format!("{action:?}");
-
-                    // Handle the dispatched action
-                    match action {
-                        Action::SayHello => println!("Hello!"),
-                        Action::ToggleMaximize => {
-                            self.maximized = !self.maximized;
-                            return window::maximize(self.window_id, self.maximized);
-                        }
-                        Action::ToggleTheme => {
-                            self.theme = match self.theme {
-                                Theme::Dark => Theme::Light,
-                                _ => Theme::Dark,
-                            };
-                        }
-                        Action::Exit => return iced::exit(),
+                // Handle the dispatched action
+                match action {
+                    Action::SayHello => println!("Hello!"),
+                    Action::ToggleMaximize => {
+                        self.maximized = !self.maximized;
+                        return window::maximize(self.window_id, self.maximized);
+                    }
+                    Action::ToggleTheme => {
+                        self.theme = match self.theme {
+                            Theme::Dark => Theme::Light,
+                            _ => Theme::Dark,
+                        };
                     }
+                    Action::Exit => return iced::exit(),
                 }
             }
             Message::Reset => {
-                self.keybinds.reset();
-                self.ongoing_input.clear();
+                // The subscription owns its own key bindings. Restart it to reset the ongoing key sequence
+                self.resets += 1;
+                self.pending_input.clear();
                 self.last_action.clear();
             }
         }
@@ -118,7 +118,7 @@ impl Example {
                 .into()
             })
             .collect();
-        let inputs = text(&self.ongoing_input).size(32.0);
+        let inputs = text(&self.pending_input).size(32.0);
         let action = text(&self.last_action).style(text::primary).size(36.0);
         let reset = button("Reset").on_press(Message::Reset);
         let content = column![help, inputs, action, reset]
@@ -130,11 +130,14 @@ impl Example {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        listen_with(|event, _, id| match event {
+        let window = listen_with(|event, _, id| match event {
             Event::Window(window::Event::Opened { .. }) => Some(Message::WindowOpen(id)),
-            Event::Keyboard(event) => Some(Message::KeyEvent(event)),
             _ => None,
-        })
+        });
+        // Dispatch actions by the key bindings and receive them as messages. The subscription is restarted when the
+        // ID is changed
+        let keybinds = subscription(self.resets, self.keybinds.clone(), Message::Keybinds);
+        Subscription::batch([window, keybinds])
     }
 
     fn theme(&self) -> Theme {
@@ -1,12 +1,12 @@
-use keybinds::winit::WinitEventConverter;
-use keybinds::{Key, Keybinds};
+use keybinds::winit::{ActionHandler, KeybindsHandler};
+use keybinds::Keybinds;
 use winit::application::ApplicationHandler;
 use winit::event::WindowEvent;
 use winit::event_loop::{ActiveEventLoop, EventLoop};
 use winit::window::{Theme, Window, WindowId};
 
 // Actions dispatched by key bindings
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum Action {
     SayHi,
     ToggleMaximized,
@@ -14,29 +14,9 @@ enum Action {
     Exit,
 }
 
+#[derive(Default)]
 struct App {
     window: Option<Window>,
-    keybinds: Keybinds<Action>,
-    converter: WinitEventConverter,
-}
-
-impl Default for App {
-    fn default() -> Self {
-        // Create a key bindings dispatcher to dispatch actions for upcoming key inputs
-        let mut keybinds = Keybinds::default();
-
-        // Key bindings to dispatch the actions
-        keybinds.bind("h i", Action::SayHi).unwrap();
-        keybinds.bind("Mod+m", Action::ToggleMaximized).unwrap();
-        keybinds.bind("Mod+Alt+t", Action::ToggleTheme).unwrap();
-        keybinds.bind("Mod+x Mod+c", Action::Exit).unwrap();
-
-        Self {
-            window: None,
-            keybinds,
-            converter: WinitEventConverter::default(),
-        }
-    }
 }
 
 impl ApplicationHandler for App {
@@ -46,42 +26,57 @@ impl ApplicationHandler for App {
         self.window = Some(window);
     }
 
+    // Window events which did not dispatch any action are received here
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
-        // Convert the window event into key input
-        let input = self.converter.convert(&event);
-        if input.key() != Key::Ignored {
-            println!("Key input: {input:?}");
-        }
-
-        // Check if the converted key input dispatches some action
-        if let Some(action) = self.keybinds.dispatch(input) {
-            println!("Action: {action:?}");
-
-            match action {
-                Action::SayHi => println!("Hi!"),
-                Action::ToggleMaximized => {
-                    let window = self.window.as_ref().unwrap();
-                    window.set_maximized(!window.is_maximized());
+        match event {
+            WindowEvent::KeyboardInput { event, .. } => {
+                if let Some(text) = event.text {
+                    println!("Text input: {text:?}");
                 }
-                Action::ToggleTheme => {
-                    let window = self.window.as_ref().unwrap();
-                    let theme = match window.theme() {
-                        Some(Theme::Dark) => Theme::Light,
-                        _ => Theme::Dark,
-                    };
-                    window.set_theme(Some(theme));
-                }
-                Action::Exit => event_loop.exit(),
             }
+            WindowEvent::CloseRequested => event_loop.exit(),
+            _ => {}
         }
+    }
+}
 
-        if let WindowEvent::CloseRequested = event {
-            event_loop.exit();
+impl ActionHandler<Action> for App {
+    // Actions dispatched by the key bindings are received here
+    fn on_action(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, action: &Action) {
+        println!("Action: {action:?}");
+
+        match action {
+            Action::SayHi => println!("Hi!"),
+            Action::ToggleMaximized => {
+                let window = self.window.as_ref().unwrap();
+                window.set_maximized(!window.is_maximized());
+            }
+            Action::ToggleTheme => {
+                let window = self.window.as_ref().unwrap();
+                let theme = match window.theme() {
+                    Some(Theme::Dark) => Theme::Light,
+                    _ => Theme::Dark,
+                };
+                window.set_theme(Some(theme));
+            }
+            Action::Exit => event_loop.exit(),
         }
     }
 }
 
 fn main() {
+    // Create a key bindings dispatcher to dispatch actions for upcoming key inputs
+    let mut keybinds = Keybinds::default();
+
+    // Key bindings to dispatch the actions
+    keybinds.bind("h i", Action::SayHi).unwrap();
+    keybinds.bind("Mod+m", Action::ToggleMaximized).unwrap();
+    keybinds.bind("Mod+Alt+t", Action::ToggleTheme).unwrap();
+    keybinds.bind("Mod+x Mod+c", Action::Exit).unwrap();
+
+    // Wrap the application handler to dispatch actions for each window
+    let mut handler = KeybindsHandler::new(App::default(), keybinds);
+
     let event_loop = EventLoop::new().unwrap();
-    event_loop.run_app(&mut App::default()).unwrap();
+    event_loop.run_app(&mut handler).unwrap();
 }
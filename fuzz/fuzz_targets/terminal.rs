@@ -0,0 +1,9 @@
+#![no_main]
+use keybinds::TerminalDecoder;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let mut decoder = TerminalDecoder::new();
+    decoder.feed(data);
+    while decoder.next_event().is_some() {}
+});
@@ -27,7 +27,7 @@
 //! let _ = Keybind::<Action>::arbitrary(&mut unstructured).unwrap();
 //! let _ = Keybinds::<Action>::arbitrary(&mut unstructured).unwrap();
 //! ```
-use crate::{KeyInput, KeySeq, Keybinds, Mods};
+use crate::{Code, Key, KeyInput, KeySeq, KeyStr, Keybind, Keybinds, Mods};
 use arbitrary::{Arbitrary, Result, Unstructured};
 
 // Note: We don't use bitflags crate's `arbitrary` feature because it is quite inefficient.
@@ -51,10 +51,60 @@ impl Arbitrary<'_> for Mods {
         if u.arbitrary()? {
             mods |= Mods::SHIFT;
         }
+        if u.arbitrary()? {
+            mods |= Mods::MOD;
+        }
+        if u.arbitrary()? {
+            mods |= Mods::SUPER;
+        }
+        for m in [
+            Mods::LCTRL,
+            Mods::RCTRL,
+            Mods::LALT,
+            Mods::RALT,
+            Mods::LSHIFT,
+            Mods::RSHIFT,
+            Mods::META,
+            Mods::HYPER,
+            Mods::ALTGR,
+            Mods::CAPS_LOCK,
+            Mods::NUM_LOCK,
+            Mods::NUMPAD,
+        ] {
+            if u.arbitrary()? {
+                mods |= m;
+            }
+        }
         Ok(mods)
     }
 }
 
+// Note: We don't intern arbitrary strings because interned strings are never freed. Fuzzing would keep allocating
+// memory until it runs out. Instead, one of the typical multi-character key values is chosen.
+impl Arbitrary<'_> for KeyStr {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        const VALUES: &[&str] = &[
+            "日本語",                 // IME commit
+            "ｶﾞ",                      // Halfwidth katakana with a voiced sound mark
+            "👍🏽",                     // Emoji with a skin tone modifier
+            "👨\u{200D}👩\u{200D}👧", // Emoji with ZWJ sequence
+        ];
+        Ok(KeyStr::new(u.choose(VALUES)?).unwrap()) // All values are valid key strings
+    }
+}
+
+// Note: We don't derive `Arbitrary` for `KeyInput` because the key is stored in the packed representation.
+impl Arbitrary<'_> for KeyInput {
+    fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
+        let key: Key = u.arbitrary()?;
+        let mods: Mods = u.arbitrary()?;
+        Ok(match u.arbitrary::<Option<Code>>()? {
+            Some(code) => Self::with_code(key, mods, code),
+            None => Self::new(key, mods),
+        })
+    }
+}
+
 // Note: We don't use `arbitrary` feature of smallvec trait because it will be removed at smallvec v2.
 impl Arbitrary<'_> for KeySeq {
     fn arbitrary(u: &mut Unstructured<'_>) -> Result<Self> {
@@ -62,6 +112,17 @@ impl Arbitrary<'_> for KeySeq {
     }
 }
 
+// Note: We don't derive `Arbitrary` for `Keybind` because the derived implementation is generic over the input type
+// while arbitrary key sequences are only generated for `KeyInput`.
+impl<'a, A: Arbitrary<'a>> Arbitrary<'a> for Keybind<A> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            seq: u.arbitrary()?,
+            action: u.arbitrary()?,
+        })
+    }
+}
+
 // Note: Do not generate arbitrary values for timeout and ongoing key sequence.
 impl<'a, A: Arbitrary<'a>> Arbitrary<'a> for Keybinds<A> {
     fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
@@ -72,7 +133,6 @@ impl<'a, A: Arbitrary<'a>> Arbitrary<'a> for Keybinds<A> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Key, Keybind};
 
     #[test]
     fn arbitrary_values() {
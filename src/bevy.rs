@@ -0,0 +1,723 @@
+//! Support for [`bevy`] crate.
+//!
+//! This module provides:
+//!
+//! - the conversions from Bevy's key types and `ButtonInput<KeyCode>` resource to [`Key`] and [`Mods`]
+//! - [`convert_keyboard_input`] function to convert Bevy's [`KeyboardInput`] message into [`KeyInput`]
+//! - [`KeybindsPlugin`] plugin to dispatch actions for keyboard inputs in each frame
+//!
+//! [`KeybindsPlugin`] stores [`Keybinds`] as a resource and writes the dispatched actions as [`Dispatched`] messages.
+//! Read them with [`MessageReader`] in your systems.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use keybinds::bevy::{Dispatched, KeybindsPlugin};
+//! use keybinds::Keybinds;
+//!
+//! // Actions dispatched by key bindings
+//! #[derive(Clone, Debug)]
+//! enum Action {
+//!     Jump,
+//!     Pause,
+//! }
+//!
+//! fn handle_actions(mut actions: MessageReader<Dispatched<Action>>) {
+//!     for Dispatched(action) in actions.read() {
+//!         println!("Action: {action:?}");
+//!     }
+//! }
+//!
+//! // Key bindings to dispatch the actions
+//! let mut keybinds = Keybinds::default();
+//! keybinds.bind("Space", Action::Jump).unwrap();
+//! keybinds.bind("Esc", Action::Pause).unwrap();
+//!
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .insert_resource(keybinds)
+//!     .add_plugins(KeybindsPlugin::<Action>::default())
+//!     .add_systems(Update, handle_actions)
+//!     .run();
+//! ```
+use crate::{Code, Key, KeyInput, Keybinds, LatinFallback, Mods};
+use bevy::app::{App, Plugin, PreUpdate};
+use bevy::ecs::message::{Message, MessageReader, MessageWriter};
+use bevy::ecs::resource::Resource;
+use bevy::ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy::ecs::system::{Local, Res, ResMut};
+use bevy::input::keyboard::{Key as BevyKey, KeyCode, KeyboardInput};
+use bevy::input::{ButtonInput, ButtonState, InputSystems};
+use std::marker::PhantomData;
+
+impl From<&BevyKey> for Key {
+    /// Convert Bevy's logical key into [`Key`]. Modifier keys such as `Key::Control` are converted into
+    /// [`Key::Ignored`].
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use bevy::input::keyboard::Key as BevyKey;
+    ///
+    /// assert_eq!(Key::from(BevyKey::ArrowUp), Key::Up);
+    /// assert_eq!(Key::from(BevyKey::Character("a".into())), Key::Char('a'));
+    /// assert_eq!(Key::from(BevyKey::Shift), Key::Ignored);
+    /// ```
+    fn from(key: &BevyKey) -> Self {
+        match key {
+            BevyKey::Character(s) => Self::from_text(s),
+            BevyKey::Space => Self::Char(' '),
+            BevyKey::ArrowUp => Self::Up,
+            BevyKey::ArrowRight => Self::Right,
+            BevyKey::ArrowDown => Self::Down,
+            BevyKey::ArrowLeft => Self::Left,
+            BevyKey::Enter => Self::Enter,
+            BevyKey::Backspace => Self::Backspace,
+            BevyKey::Delete => Self::Delete,
+            BevyKey::Home => Self::Home,
+            BevyKey::End => Self::End,
+            BevyKey::PageUp => Self::PageUp,
+            BevyKey::PageDown => Self::PageDown,
+            BevyKey::Escape => Self::Esc,
+            BevyKey::Tab => Self::Tab,
+            BevyKey::Insert => Self::Insert,
+            BevyKey::Copy => Self::Copy,
+            BevyKey::Cut => Self::Cut,
+            BevyKey::Paste => Self::Paste,
+            BevyKey::Clear => Self::Clear,
+            BevyKey::Undo => Self::Undo,
+            BevyKey::Redo => Self::Redo,
+            BevyKey::Help => Self::Help,
+            BevyKey::Accept => Self::Accept,
+            BevyKey::Again => Self::Again,
+            BevyKey::Attn => Self::Attn,
+            BevyKey::Cancel => Self::Cancel,
+            BevyKey::Execute => Self::Execute,
+            BevyKey::Find => Self::Find,
+            BevyKey::Props => Self::Props,
+            BevyKey::Select => Self::Select,
+            BevyKey::CrSel => Self::CrSel,
+            BevyKey::EraseEof => Self::EraseEof,
+            BevyKey::ExSel => Self::ExSel,
+            BevyKey::BrightnessDown => Self::BrightnessDown,
+            BevyKey::BrightnessUp => Self::BrightnessUp,
+            BevyKey::Eject => Self::Eject,
+            BevyKey::LogOff => Self::LogOff,
+            BevyKey::Power => Self::Power,
+            BevyKey::PowerOff => Self::PowerOff,
+            BevyKey::Hibernate => Self::Hibernate,
+            BevyKey::Standby => Self::Standby,
+            BevyKey::WakeUp => Self::WakeUp,
+            BevyKey::MediaFastForward => Self::FastForward,
+            BevyKey::MediaRecord => Self::Record,
+            BevyKey::BrowserBack => Self::BrowserBack,
+            BevyKey::BrowserFavorites => Self::BrowserFavorites,
+            BevyKey::BrowserForward => Self::BrowserForward,
+            BevyKey::BrowserHome => Self::BrowserHome,
+            BevyKey::BrowserRefresh => Self::BrowserRefresh,
+            BevyKey::BrowserSearch => Self::BrowserSearch,
+            BevyKey::BrowserStop => Self::BrowserStop,
+            BevyKey::LaunchApplication1 => Self::LaunchApplication1,
+            BevyKey::LaunchApplication2 => Self::LaunchApplication2,
+            BevyKey::LaunchCalendar => Self::LaunchCalendar,
+            BevyKey::LaunchContacts => Self::LaunchContacts,
+            BevyKey::LaunchMail => Self::LaunchMail,
+            BevyKey::LaunchMediaPlayer => Self::LaunchMediaPlayer,
+            BevyKey::LaunchMusicPlayer => Self::LaunchMusicPlayer,
+            BevyKey::LaunchPhone => Self::LaunchPhone,
+            BevyKey::LaunchScreenSaver => Self::LaunchScreenSaver,
+            BevyKey::LaunchSpreadsheet => Self::LaunchSpreadsheet,
+            BevyKey::LaunchWebBrowser => Self::LaunchWebBrowser,
+            BevyKey::LaunchWebCam => Self::LaunchWebCam,
+            BevyKey::LaunchWordProcessor => Self::LaunchWordProcessor,
+            BevyKey::Close => Self::Close,
+            BevyKey::New => Self::New,
+            BevyKey::Open => Self::Open,
+            BevyKey::Print => Self::Print,
+            BevyKey::Save => Self::Save,
+            BevyKey::SpellCheck => Self::SpellCheck,
+            BevyKey::MailForward => Self::MailForward,
+            BevyKey::MailReply => Self::MailReply,
+            BevyKey::MailSend => Self::MailSend,
+            BevyKey::ZoomIn => Self::ZoomIn,
+            BevyKey::ZoomOut => Self::ZoomOut,
+            BevyKey::ZoomToggle => Self::ZoomToggle,
+            BevyKey::ScrollLock => Self::ScrollLock,
+            BevyKey::NumLock => Self::NumLock,
+            BevyKey::PrintScreen => Self::PrintScreen,
+            BevyKey::ContextMenu => Self::Menu,
+            BevyKey::MediaPlay => Self::Play,
+            BevyKey::MediaPause => Self::Pause,
+            BevyKey::MediaPlayPause => Self::PlayPause,
+            BevyKey::MediaStop => Self::Stop,
+            BevyKey::MediaRewind => Self::Rewind,
+            BevyKey::MediaTrackNext => Self::NextTrack,
+            BevyKey::MediaTrackPrevious => Self::PrevTrack,
+            BevyKey::AudioVolumeUp => Self::VolumeUp,
+            BevyKey::AudioVolumeDown => Self::VolumeDown,
+            BevyKey::AudioVolumeMute => Self::Mute,
+            BevyKey::F1 => Self::F1,
+            BevyKey::F2 => Self::F2,
+            BevyKey::F3 => Self::F3,
+            BevyKey::F4 => Self::F4,
+            BevyKey::F5 => Self::F5,
+            BevyKey::F6 => Self::F6,
+            BevyKey::F7 => Self::F7,
+            BevyKey::F8 => Self::F8,
+            BevyKey::F9 => Self::F9,
+            BevyKey::F10 => Self::F10,
+            BevyKey::F11 => Self::F11,
+            BevyKey::F12 => Self::F12,
+            BevyKey::F13 => Self::F13,
+            BevyKey::F14 => Self::F14,
+            BevyKey::F15 => Self::F15,
+            BevyKey::F16 => Self::F16,
+            BevyKey::F17 => Self::F17,
+            BevyKey::F18 => Self::F18,
+            BevyKey::F19 => Self::F19,
+            BevyKey::F20 => Self::F20,
+            BevyKey::F21 => Self::F21,
+            BevyKey::F22 => Self::F22,
+            BevyKey::F23 => Self::F23,
+            BevyKey::F24 => Self::F24,
+            BevyKey::F25 => Self::F25,
+            BevyKey::F26 => Self::F26,
+            BevyKey::F27 => Self::F27,
+            BevyKey::F28 => Self::F28,
+            BevyKey::F29 => Self::F29,
+            BevyKey::F30 => Self::F30,
+            BevyKey::F31 => Self::F31,
+            BevyKey::F32 => Self::F32,
+            BevyKey::F33 => Self::F33,
+            BevyKey::F34 => Self::F34,
+            BevyKey::F35 => Self::F35,
+            BevyKey::Alt
+            | BevyKey::AltGraph
+            | BevyKey::Control
+            | BevyKey::Shift
+            | BevyKey::Super
+            | BevyKey::Hyper
+            | BevyKey::Meta
+            | BevyKey::Symbol => Self::Ignored,
+            _ => Self::Unidentified,
+        }
+    }
+}
+
+impl From<BevyKey> for Key {
+    fn from(key: BevyKey) -> Self {
+        Self::from(&key)
+    }
+}
+
+impl From<&KeyCode> for Key {
+    /// Convert Bevy's key code into [`Key::Physical`]. Key codes which cannot be represented with [`Code`] are
+    /// converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::{Code, Key};
+    /// use bevy::input::keyboard::KeyCode;
+    ///
+    /// assert_eq!(Key::from(KeyCode::KeyW), Key::Physical(Code::KeyW));
+    /// assert_eq!(Key::from(KeyCode::Fn), Key::Unidentified);
+    /// ```
+    fn from(code: &KeyCode) -> Self {
+        match code {
+            KeyCode::Backquote => Self::Physical(Code::Backquote),
+            KeyCode::Backslash => Self::Physical(Code::Backslash),
+            KeyCode::BracketLeft => Self::Physical(Code::BracketLeft),
+            KeyCode::BracketRight => Self::Physical(Code::BracketRight),
+            KeyCode::Comma => Self::Physical(Code::Comma),
+            KeyCode::Digit0 => Self::Physical(Code::Digit0),
+            KeyCode::Digit1 => Self::Physical(Code::Digit1),
+            KeyCode::Digit2 => Self::Physical(Code::Digit2),
+            KeyCode::Digit3 => Self::Physical(Code::Digit3),
+            KeyCode::Digit4 => Self::Physical(Code::Digit4),
+            KeyCode::Digit5 => Self::Physical(Code::Digit5),
+            KeyCode::Digit6 => Self::Physical(Code::Digit6),
+            KeyCode::Digit7 => Self::Physical(Code::Digit7),
+            KeyCode::Digit8 => Self::Physical(Code::Digit8),
+            KeyCode::Digit9 => Self::Physical(Code::Digit9),
+            KeyCode::Equal => Self::Physical(Code::Equal),
+            KeyCode::IntlBackslash => Self::Physical(Code::IntlBackslash),
+            KeyCode::IntlRo => Self::Physical(Code::IntlRo),
+            KeyCode::IntlYen => Self::Physical(Code::IntlYen),
+            KeyCode::KeyA => Self::Physical(Code::KeyA),
+            KeyCode::KeyB => Self::Physical(Code::KeyB),
+            KeyCode::KeyC => Self::Physical(Code::KeyC),
+            KeyCode::KeyD => Self::Physical(Code::KeyD),
+            KeyCode::KeyE => Self::Physical(Code::KeyE),
+            KeyCode::KeyF => Self::Physical(Code::KeyF),
+            KeyCode::KeyG => Self::Physical(Code::KeyG),
+            KeyCode::KeyH => Self::Physical(Code::KeyH),
+            KeyCode::KeyI => Self::Physical(Code::KeyI),
+            KeyCode::KeyJ => Self::Physical(Code::KeyJ),
+            KeyCode::KeyK => Self::Physical(Code::KeyK),
+            KeyCode::KeyL => Self::Physical(Code::KeyL),
+            KeyCode::KeyM => Self::Physical(Code::KeyM),
+            KeyCode::KeyN => Self::Physical(Code::KeyN),
+            KeyCode::KeyO => Self::Physical(Code::KeyO),
+            KeyCode::KeyP => Self::Physical(Code::KeyP),
+            KeyCode::KeyQ => Self::Physical(Code::KeyQ),
+            KeyCode::KeyR => Self::Physical(Code::KeyR),
+            KeyCode::KeyS => Self::Physical(Code::KeyS),
+            KeyCode::KeyT => Self::Physical(Code::KeyT),
+            KeyCode::KeyU => Self::Physical(Code::KeyU),
+            KeyCode::KeyV => Self::Physical(Code::KeyV),
+            KeyCode::KeyW => Self::Physical(Code::KeyW),
+            KeyCode::KeyX => Self::Physical(Code::KeyX),
+            KeyCode::KeyY => Self::Physical(Code::KeyY),
+            KeyCode::KeyZ => Self::Physical(Code::KeyZ),
+            KeyCode::Minus => Self::Physical(Code::Minus),
+            KeyCode::Period => Self::Physical(Code::Period),
+            KeyCode::Quote => Self::Physical(Code::Quote),
+            KeyCode::Semicolon => Self::Physical(Code::Semicolon),
+            KeyCode::Slash => Self::Physical(Code::Slash),
+            KeyCode::AltLeft => Self::Physical(Code::AltLeft),
+            KeyCode::AltRight => Self::Physical(Code::AltRight),
+            KeyCode::Backspace => Self::Physical(Code::Backspace),
+            KeyCode::CapsLock => Self::Physical(Code::CapsLock),
+            KeyCode::ContextMenu => Self::Physical(Code::ContextMenu),
+            KeyCode::ControlLeft => Self::Physical(Code::ControlLeft),
+            KeyCode::ControlRight => Self::Physical(Code::ControlRight),
+            KeyCode::Enter => Self::Physical(Code::Enter),
+            KeyCode::SuperLeft => Self::Physical(Code::MetaLeft),
+            KeyCode::SuperRight => Self::Physical(Code::MetaRight),
+            KeyCode::ShiftLeft => Self::Physical(Code::ShiftLeft),
+            KeyCode::ShiftRight => Self::Physical(Code::ShiftRight),
+            KeyCode::Space => Self::Physical(Code::Space),
+            KeyCode::Tab => Self::Physical(Code::Tab),
+            KeyCode::Convert => Self::Physical(Code::Convert),
+            KeyCode::KanaMode => Self::Physical(Code::KanaMode),
+            KeyCode::Lang1 => Self::Physical(Code::Lang1),
+            KeyCode::Lang2 => Self::Physical(Code::Lang2),
+            KeyCode::NonConvert => Self::Physical(Code::NonConvert),
+            KeyCode::Delete => Self::Physical(Code::Delete),
+            KeyCode::End => Self::Physical(Code::End),
+            KeyCode::Help => Self::Physical(Code::Help),
+            KeyCode::Home => Self::Physical(Code::Home),
+            KeyCode::Insert => Self::Physical(Code::Insert),
+            KeyCode::PageDown => Self::Physical(Code::PageDown),
+            KeyCode::PageUp => Self::Physical(Code::PageUp),
+            KeyCode::ArrowDown => Self::Physical(Code::ArrowDown),
+            KeyCode::ArrowLeft => Self::Physical(Code::ArrowLeft),
+            KeyCode::ArrowRight => Self::Physical(Code::ArrowRight),
+            KeyCode::ArrowUp => Self::Physical(Code::ArrowUp),
+            KeyCode::NumLock => Self::Physical(Code::NumLock),
+            KeyCode::Numpad0 => Self::Physical(Code::Numpad0),
+            KeyCode::Numpad1 => Self::Physical(Code::Numpad1),
+            KeyCode::Numpad2 => Self::Physical(Code::Numpad2),
+            KeyCode::Numpad3 => Self::Physical(Code::Numpad3),
+            KeyCode::Numpad4 => Self::Physical(Code::Numpad4),
+            KeyCode::Numpad5 => Self::Physical(Code::Numpad5),
+            KeyCode::Numpad6 => Self::Physical(Code::Numpad6),
+            KeyCode::Numpad7 => Self::Physical(Code::Numpad7),
+            KeyCode::Numpad8 => Self::Physical(Code::Numpad8),
+            KeyCode::Numpad9 => Self::Physical(Code::Numpad9),
+            KeyCode::NumpadAdd => Self::Physical(Code::NumpadAdd),
+            KeyCode::NumpadComma => Self::Physical(Code::NumpadComma),
+            KeyCode::NumpadDecimal => Self::Physical(Code::NumpadDecimal),
+            KeyCode::NumpadDivide => Self::Physical(Code::NumpadDivide),
+            KeyCode::NumpadEnter => Self::Physical(Code::NumpadEnter),
+            KeyCode::NumpadEqual => Self::Physical(Code::NumpadEqual),
+            KeyCode::NumpadMultiply => Self::Physical(Code::NumpadMultiply),
+            KeyCode::NumpadSubtract => Self::Physical(Code::NumpadSubtract),
+            KeyCode::Escape => Self::Physical(Code::Escape),
+            KeyCode::F1 => Self::Physical(Code::F1),
+            KeyCode::F2 => Self::Physical(Code::F2),
+            KeyCode::F3 => Self::Physical(Code::F3),
+            KeyCode::F4 => Self::Physical(Code::F4),
+            KeyCode::F5 => Self::Physical(Code::F5),
+            KeyCode::F6 => Self::Physical(Code::F6),
+            KeyCode::F7 => Self::Physical(Code::F7),
+            KeyCode::F8 => Self::Physical(Code::F8),
+            KeyCode::F9 => Self::Physical(Code::F9),
+            KeyCode::F10 => Self::Physical(Code::F10),
+            KeyCode::F11 => Self::Physical(Code::F11),
+            KeyCode::F12 => Self::Physical(Code::F12),
+            KeyCode::F13 => Self::Physical(Code::F13),
+            KeyCode::F14 => Self::Physical(Code::F14),
+            KeyCode::F15 => Self::Physical(Code::F15),
+            KeyCode::F16 => Self::Physical(Code::F16),
+            KeyCode::F17 => Self::Physical(Code::F17),
+            KeyCode::F18 => Self::Physical(Code::F18),
+            KeyCode::F19 => Self::Physical(Code::F19),
+            KeyCode::F20 => Self::Physical(Code::F20),
+            KeyCode::F21 => Self::Physical(Code::F21),
+            KeyCode::F22 => Self::Physical(Code::F22),
+            KeyCode::F23 => Self::Physical(Code::F23),
+            KeyCode::F24 => Self::Physical(Code::F24),
+            KeyCode::PrintScreen => Self::Physical(Code::PrintScreen),
+            KeyCode::ScrollLock => Self::Physical(Code::ScrollLock),
+            KeyCode::Pause => Self::Physical(Code::Pause),
+            _ => Self::Unidentified,
+        }
+    }
+}
+
+impl From<KeyCode> for Key {
+    fn from(code: KeyCode) -> Self {
+        Self::from(&code)
+    }
+}
+
+impl From<&ButtonInput<KeyCode>> for Mods {
+    /// Convert the modifier keys being pressed in Bevy's `ButtonInput<KeyCode>` resource into side-aware [`Mods`].
+    ///
+    /// ```
+    /// use keybinds::Mods;
+    /// use bevy::input::ButtonInput;
+    /// use bevy::input::keyboard::KeyCode;
+    ///
+    /// let mut keys = ButtonInput::<KeyCode>::default();
+    /// keys.press(KeyCode::ControlLeft);
+    /// keys.press(KeyCode::ShiftRight);
+    /// assert_eq!(Mods::from(&keys), Mods::LCTRL | Mods::RSHIFT);
+    /// ```
+    fn from(keys: &ButtonInput<KeyCode>) -> Self {
+        let mut mods = Mods::NONE;
+        for (code, m) in [
+            (KeyCode::ControlLeft, Mods::LCTRL),
+            (KeyCode::ControlRight, Mods::RCTRL),
+            (KeyCode::AltLeft, Mods::LALT),
+            (KeyCode::AltRight, Mods::RALT),
+            (KeyCode::ShiftLeft, Mods::LSHIFT),
+            (KeyCode::ShiftRight, Mods::RSHIFT),
+            (KeyCode::SuperLeft, Mods::SUPER),
+            (KeyCode::SuperRight, Mods::SUPER),
+        ] {
+            if keys.pressed(code) {
+                mods |= m;
+            }
+        }
+        mods
+    }
+}
+
+fn is_numpad(code: KeyCode) -> bool {
+    matches!(
+        code,
+        KeyCode::Numpad0
+            | KeyCode::Numpad1
+            | KeyCode::Numpad2
+            | KeyCode::Numpad3
+            | KeyCode::Numpad4
+            | KeyCode::Numpad5
+            | KeyCode::Numpad6
+            | KeyCode::Numpad7
+            | KeyCode::Numpad8
+            | KeyCode::Numpad9
+            | KeyCode::NumpadAdd
+            | KeyCode::NumpadBackspace
+            | KeyCode::NumpadClear
+            | KeyCode::NumpadClearEntry
+            | KeyCode::NumpadComma
+            | KeyCode::NumpadDecimal
+            | KeyCode::NumpadDivide
+            | KeyCode::NumpadEnter
+            | KeyCode::NumpadEqual
+            | KeyCode::NumpadHash
+            | KeyCode::NumpadMemoryAdd
+            | KeyCode::NumpadMemoryClear
+            | KeyCode::NumpadMemoryRecall
+            | KeyCode::NumpadMemoryStore
+            | KeyCode::NumpadMemorySubtract
+            | KeyCode::NumpadMultiply
+            | KeyCode::NumpadParenLeft
+            | KeyCode::NumpadParenRight
+            | KeyCode::NumpadStar
+            | KeyCode::NumpadSubtract
+    )
+}
+
+/// Convert Bevy's [`KeyboardInput`] message into [`KeyInput`] with the modifier keys being pressed in `keys`. Since
+/// Bevy's keyboard messages don't carry the modifiers state, it is taken from the `ButtonInput<KeyCode>` resource.
+///
+/// Like other GUI frameworks' key events, the physical key is also converted and non-Latin characters typed with
+/// modifiers fall back to Latin characters with [`LatinFallback::WithModifiers`]. Keys on the numeric keypad are
+/// converted with [`Mods::NUMPAD`]. Releasing keys is converted into `Key::Ignored`.
+///
+/// ```
+/// use keybinds::{Code, KeyInput, Mods};
+/// use keybinds::bevy::convert_keyboard_input;
+/// use bevy::ecs::entity::Entity;
+/// use bevy::input::{ButtonInput, ButtonState};
+/// use bevy::input::keyboard::{Key, KeyCode, KeyboardInput};
+///
+/// let mut keys = ButtonInput::<KeyCode>::default();
+/// keys.press(KeyCode::ControlLeft);
+///
+/// let event = KeyboardInput {
+///     key_code: KeyCode::KeyS,
+///     logical_key: Key::Character("s".into()),
+///     state: ButtonState::Pressed,
+///     text: Some("s".into()),
+///     repeat: false,
+///     window: Entity::PLACEHOLDER,
+/// };
+///
+/// let input = convert_keyboard_input(&event, &keys);
+/// assert_eq!(input, KeyInput::with_code('s', Mods::LCTRL, Code::KeyS));
+/// ```
+pub fn convert_keyboard_input(event: &KeyboardInput, keys: &ButtonInput<KeyCode>) -> KeyInput {
+    if event.state != ButtonState::Pressed {
+        return Key::Ignored.into();
+    }
+    let mut mods = Mods::from(keys);
+    if is_numpad(event.key_code) {
+        mods |= Mods::NUMPAD;
+    }
+    match Key::from(event.key_code) {
+        Key::Physical(code) => {
+            LatinFallback::default().apply(KeyInput::with_code(&event.logical_key, mods, code))
+        }
+        _ => KeyInput::new(&event.logical_key, mods),
+    }
+}
+
+impl<A: Send + Sync + 'static> Resource for Keybinds<A> {}
+
+/// Message written by [`KeybindsPlugin`] when a keyboard input dispatches an action.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dispatched<A>(pub A);
+
+impl<A: Send + Sync + 'static> Message for Dispatched<A> {}
+
+/// System set of the system added by [`KeybindsPlugin`]. The system runs in `PreUpdate` schedule after Bevy's input
+/// systems so the dispatched actions can be read in `Update` schedule.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, SystemSet)]
+pub struct KeybindsSystems;
+
+/// Plugin to dispatch actions for Bevy's keyboard inputs in each frame.
+///
+/// This plugin initializes [`Keybinds<A>`][Keybinds] resource unless it is already inserted. In each frame, the
+/// [`KeyboardInput`] messages are converted in order with [`convert_keyboard_input`] and dispatched by the resource.
+/// Each message is converted with the modifier keys pressed at the point, not at the end of the frame. The
+/// dispatched actions are written as [`Dispatched<A>`][Dispatched] messages. Key bindings can be modified at runtime
+/// via `ResMut<Keybinds<A>>`. See [the module document][crate::bevy] for an example.
+///
+/// This plugin requires Bevy's `InputPlugin` which is included in `DefaultPlugins`.
+pub struct KeybindsPlugin<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for KeybindsPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Clone + Send + Sync + 'static> Plugin for KeybindsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Keybinds<A>>()
+            .add_message::<Dispatched<A>>()
+            .add_systems(
+                PreUpdate,
+                dispatch_system::<A>
+                    .in_set(KeybindsSystems)
+                    .after(InputSystems),
+            );
+    }
+}
+
+fn dispatch_system<A: Clone + Send + Sync + 'static>(
+    mut inputs: MessageReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut pressed: Local<ButtonInput<KeyCode>>,
+    mut keybinds: ResMut<Keybinds<A>>,
+    mut actions: MessageWriter<Dispatched<A>>,
+) {
+    // `keys` is the state at the end of this frame. Replay the messages from the state at the end of the previous
+    // frame so that a modifier key released in the same frame is still pressed for the preceding inputs.
+    for event in inputs.read() {
+        match event.state {
+            ButtonState::Pressed => pressed.press(event.key_code),
+            ButtonState::Released => pressed.release(event.key_code),
+        }
+        if let Some(action) = keybinds.dispatch(convert_keyboard_input(event, &pressed)) {
+            actions.write(Dispatched(action.clone()));
+        }
+    }
+    *pressed = keys.clone();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::entity::Entity;
+    use bevy::ecs::message::Messages;
+    use bevy::input::InputPlugin;
+
+    fn keyboard_input(
+        key_code: KeyCode,
+        logical_key: BevyKey,
+        state: ButtonState,
+    ) -> KeyboardInput {
+        KeyboardInput {
+            key_code,
+            logical_key,
+            state,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    fn press(key_code: KeyCode, logical_key: BevyKey) -> KeyboardInput {
+        keyboard_input(key_code, logical_key, ButtonState::Pressed)
+    }
+
+    #[test]
+    fn convert_key() {
+        assert_eq!(Key::from(BevyKey::Escape), Key::Esc);
+        assert_eq!(Key::from(BevyKey::Space), Key::Char(' '));
+        assert_eq!(Key::from(BevyKey::AudioVolumeMute), Key::Mute);
+        assert_eq!(Key::from(BevyKey::F35), Key::F35);
+        assert_eq!(Key::from(BevyKey::Control), Key::Ignored);
+        assert_eq!(Key::from(BevyKey::TVInput), Key::Unidentified);
+        assert_eq!(
+            Key::from(KeyCode::Backquote),
+            Key::Physical(Code::Backquote)
+        );
+        assert_eq!(Key::from(KeyCode::SuperLeft), Key::Physical(Code::MetaLeft));
+        assert_eq!(Key::from(KeyCode::Lang3), Key::Unidentified);
+    }
+
+    #[test]
+    fn convert_input() {
+        let mut keys = ButtonInput::default();
+        assert_eq!(Mods::from(&keys), Mods::NONE);
+        keys.press(KeyCode::AltRight);
+        keys.press(KeyCode::SuperLeft);
+        assert_eq!(Mods::from(&keys), Mods::RALT | Mods::SUPER);
+
+        let event = press(KeyCode::NumpadEnter, BevyKey::Enter);
+        assert_eq!(
+            convert_keyboard_input(&event, &keys),
+            KeyInput::with_code(
+                Key::Enter,
+                Mods::RALT | Mods::SUPER | Mods::NUMPAD,
+                Code::NumpadEnter,
+            ),
+        );
+
+        // Latin fallback on non-Latin layouts
+        let mut keys = ButtonInput::default();
+        keys.press(KeyCode::ControlLeft);
+        let event = press(KeyCode::KeyC, BevyKey::Character("с".into()));
+        assert_eq!(
+            convert_keyboard_input(&event, &keys),
+            KeyInput::with_code('c', Mods::LCTRL, Code::KeyC),
+        );
+
+        let event = keyboard_input(
+            KeyCode::KeyC,
+            BevyKey::Character("c".into()),
+            ButtonState::Released,
+        );
+        assert_eq!(
+            convert_keyboard_input(&event, &keys),
+            KeyInput::from(Key::Ignored),
+        );
+    }
+
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    enum A {
+        Save,
+        Quit,
+    }
+
+    fn dispatched(app: &mut App) -> Vec<A> {
+        app.world_mut()
+            .resource_mut::<Messages<Dispatched<A>>>()
+            .drain()
+            .map(|Dispatched(a)| a)
+            .collect()
+    }
+
+    #[test]
+    fn plugin_dispatch() {
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+s", A::Save).unwrap();
+        keybinds.bind("q q", A::Quit).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(InputPlugin)
+            .insert_resource(keybinds)
+            .add_plugins(KeybindsPlugin::<A>::default());
+
+        let world = app.world_mut();
+        world.write_message(press(KeyCode::ControlLeft, BevyKey::Control));
+        world.write_message(press(KeyCode::KeyS, BevyKey::Character("s".into())));
+        app.update();
+        assert_eq!(dispatched(&mut app), [A::Save]);
+
+        let world = app.world_mut();
+        world.write_message(keyboard_input(
+            KeyCode::ControlLeft,
+            BevyKey::Control,
+            ButtonState::Released,
+        ));
+        world.write_message(press(KeyCode::KeyQ, BevyKey::Character("q".into())));
+        app.update();
+        assert_eq!(dispatched(&mut app), []);
+        assert!(app.world().resource::<Keybinds<A>>().is_ongoing());
+
+        app.world_mut()
+            .write_message(press(KeyCode::KeyQ, BevyKey::Character("q".into())));
+        app.update();
+        assert_eq!(dispatched(&mut app), [A::Quit]);
+
+        // Key bindings can be modified via the resource
+        app.world_mut()
+            .resource_mut::<Keybinds<A>>()
+            .bind("x", A::Quit)
+            .unwrap();
+        app.world_mut()
+            .write_message(press(KeyCode::KeyX, BevyKey::Character("x".into())));
+        app.update();
+        assert_eq!(dispatched(&mut app), [A::Quit]);
+    }
+
+    #[test]
+    fn plugin_dispatch_modifiers_in_frame() {
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+s", A::Save).unwrap();
+        keybinds.bind("s", A::Quit).unwrap();
+
+        let mut app = App::new();
+        app.add_plugins(InputPlugin)
+            .insert_resource(keybinds)
+            .add_plugins(KeybindsPlugin::<A>::default());
+
+        let world = app.world_mut();
+        world.write_message(press(KeyCode::ControlLeft, BevyKey::Control));
+        world.write_message(press(KeyCode::KeyS, BevyKey::Character("s".into())));
+        world.write_message(keyboard_input(
+            KeyCode::ControlLeft,
+            BevyKey::Control,
+            ButtonState::Released,
+        ));
+        app.update();
+        assert_eq!(dispatched(&mut app), [A::Save]);
+
+        // Ctrl key pressed in the previous frame is still pressed
+        app.world_mut()
+            .write_message(press(KeyCode::ControlRight, BevyKey::Control));
+        app.update();
+        let world = app.world_mut();
+        world.write_message(press(KeyCode::KeyS, BevyKey::Character("s".into())));
+        world.write_message(keyboard_input(
+            KeyCode::ControlRight,
+            BevyKey::Control,
+            ButtonState::Released,
+        ));
+        world.write_message(press(KeyCode::KeyS, BevyKey::Character("s".into())));
+        app.update();
+        assert_eq!(dispatched(&mut app), [A::Save, A::Quit]);
+    }
+
+    #[test]
+    fn plugin_default_resource() {
+        let mut app = App::new();
+        app.add_plugins((InputPlugin, KeybindsPlugin::<A>::default()));
+        app.update();
+        assert!(app.world().resource::<Keybinds<A>>().as_slice().is_empty());
+    }
+}
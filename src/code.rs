@@ -0,0 +1,578 @@
+use crate::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::Arbitrary;
+
+/// Single physical key on keyboard.
+///
+/// The 'physical key' is the position of the key on keyboard regardless of the keyboard layout. For example,
+/// `Code::KeyW` is the key at the position of <kbd>W</kbd> on the US QWERTY layout, and it is <kbd>Z</kbd> on the French
+/// AZERTY layout. Physical keys are useful for games or applications which assign actions to key positions like
+/// <kbd>W</kbd> <kbd>A</kbd> <kbd>S</kbd> <kbd>D</kbd> keys.
+///
+/// The variants are named after the values of [`KeyboardEvent.code`](https://www.w3.org/TR/uievents-code/) defined by
+/// W3C. The physical key is used as a key via [`Key::Physical`][crate::Key::Physical] variant.
+///
+/// ```
+/// use keybinds::{Code, Key};
+///
+/// let code: Code = "KeyW".parse().unwrap();
+/// assert_eq!(code, Code::KeyW);
+/// assert_eq!(code.to_string(), "KeyW");
+///
+/// // Physical keys are written in brackets in the key binding syntax
+/// assert_eq!("[KeyW]".parse(), Ok(Key::Physical(Code::KeyW)));
+/// ```
+///
+/// This enum is non-exhaustive because more keys may be added in the future.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+pub enum Code {
+    Backquote,
+    Backslash,
+    BracketLeft,
+    BracketRight,
+    Comma,
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    Equal,
+    IntlBackslash,
+    IntlRo,
+    IntlYen,
+    KeyA,
+    KeyB,
+    KeyC,
+    KeyD,
+    KeyE,
+    KeyF,
+    KeyG,
+    KeyH,
+    KeyI,
+    KeyJ,
+    KeyK,
+    KeyL,
+    KeyM,
+    KeyN,
+    KeyO,
+    KeyP,
+    KeyQ,
+    KeyR,
+    KeyS,
+    KeyT,
+    KeyU,
+    KeyV,
+    KeyW,
+    KeyX,
+    KeyY,
+    KeyZ,
+    Minus,
+    Period,
+    Quote,
+    Semicolon,
+    Slash,
+    AltLeft,
+    AltRight,
+    Backspace,
+    CapsLock,
+    ContextMenu,
+    ControlLeft,
+    ControlRight,
+    Enter,
+    MetaLeft,
+    MetaRight,
+    ShiftLeft,
+    ShiftRight,
+    Space,
+    Tab,
+    Convert,
+    KanaMode,
+    Lang1,
+    Lang2,
+    NonConvert,
+    Delete,
+    End,
+    Help,
+    Home,
+    Insert,
+    PageDown,
+    PageUp,
+    ArrowDown,
+    ArrowLeft,
+    ArrowRight,
+    ArrowUp,
+    NumLock,
+    Numpad0,
+    Numpad1,
+    Numpad2,
+    Numpad3,
+    Numpad4,
+    Numpad5,
+    Numpad6,
+    Numpad7,
+    Numpad8,
+    Numpad9,
+    NumpadAdd,
+    NumpadComma,
+    NumpadDecimal,
+    NumpadDivide,
+    NumpadEnter,
+    NumpadEqual,
+    NumpadMultiply,
+    NumpadSubtract,
+    Escape,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    PrintScreen,
+    ScrollLock,
+    Pause,
+}
+
+impl Code {
+    /// Return the character typed by the physical key without any modifiers on the US QWERTY layout. `None` is
+    /// returned when the key does not type a character.
+    ///
+    /// ```
+    /// use keybinds::Code;
+    ///
+    /// assert_eq!(Code::KeyC.qwerty_char(), Some('c'));
+    /// assert_eq!(Code::Digit1.qwerty_char(), Some('1'));
+    /// assert_eq!(Code::Slash.qwerty_char(), Some('/'));
+    /// assert_eq!(Code::Enter.qwerty_char(), None);
+    /// ```
+    pub fn qwerty_char(self) -> Option<char> {
+        let c = match self {
+            Self::KeyA => 'a',
+            Self::KeyB => 'b',
+            Self::KeyC => 'c',
+            Self::KeyD => 'd',
+            Self::KeyE => 'e',
+            Self::KeyF => 'f',
+            Self::KeyG => 'g',
+            Self::KeyH => 'h',
+            Self::KeyI => 'i',
+            Self::KeyJ => 'j',
+            Self::KeyK => 'k',
+            Self::KeyL => 'l',
+            Self::KeyM => 'm',
+            Self::KeyN => 'n',
+            Self::KeyO => 'o',
+            Self::KeyP => 'p',
+            Self::KeyQ => 'q',
+            Self::KeyR => 'r',
+            Self::KeyS => 's',
+            Self::KeyT => 't',
+            Self::KeyU => 'u',
+            Self::KeyV => 'v',
+            Self::KeyW => 'w',
+            Self::KeyX => 'x',
+            Self::KeyY => 'y',
+            Self::KeyZ => 'z',
+            Self::Digit0 => '0',
+            Self::Digit1 => '1',
+            Self::Digit2 => '2',
+            Self::Digit3 => '3',
+            Self::Digit4 => '4',
+            Self::Digit5 => '5',
+            Self::Digit6 => '6',
+            Self::Digit7 => '7',
+            Self::Digit8 => '8',
+            Self::Digit9 => '9',
+            Self::Backquote => '`',
+            Self::Minus => '-',
+            Self::Equal => '=',
+            Self::BracketLeft => '[',
+            Self::BracketRight => ']',
+            Self::Backslash => '\\',
+            Self::Semicolon => ';',
+            Self::Quote => '\'',
+            Self::Comma => ',',
+            Self::Period => '.',
+            Self::Slash => '/',
+            Self::Space => ' ',
+            _ => return None,
+        };
+        Some(c)
+    }
+}
+
+impl FromStr for Code {
+    type Err = Error;
+
+    /// Parse the physical key from [`str`]. The name must be one of the values of [`KeyboardEvent.code`](https://www.w3.org/TR/uievents-code/)
+    /// such as `"KeyA"` or `"Digit1"`. Unlike the names of logical keys, the name is case-sensitive.
+    ///
+    /// ```
+    /// use keybinds::Code;
+    ///
+    /// assert_eq!("KeyA".parse(), Ok(Code::KeyA));
+    /// assert_eq!("Digit1".parse(), Ok(Code::Digit1));
+    /// assert_eq!("ArrowUp".parse(), Ok(Code::ArrowUp));
+    ///
+    /// assert!("keya".parse::<Code>().is_err());
+    /// assert!("".parse::<Code>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim_ascii() {
+            "Backquote" => Ok(Self::Backquote),
+            "Backslash" => Ok(Self::Backslash),
+            "BracketLeft" => Ok(Self::BracketLeft),
+            "BracketRight" => Ok(Self::BracketRight),
+            "Comma" => Ok(Self::Comma),
+            "Digit0" => Ok(Self::Digit0),
+            "Digit1" => Ok(Self::Digit1),
+            "Digit2" => Ok(Self::Digit2),
+            "Digit3" => Ok(Self::Digit3),
+            "Digit4" => Ok(Self::Digit4),
+            "Digit5" => Ok(Self::Digit5),
+            "Digit6" => Ok(Self::Digit6),
+            "Digit7" => Ok(Self::Digit7),
+            "Digit8" => Ok(Self::Digit8),
+            "Digit9" => Ok(Self::Digit9),
+            "Equal" => Ok(Self::Equal),
+            "IntlBackslash" => Ok(Self::IntlBackslash),
+            "IntlRo" => Ok(Self::IntlRo),
+            "IntlYen" => Ok(Self::IntlYen),
+            "KeyA" => Ok(Self::KeyA),
+            "KeyB" => Ok(Self::KeyB),
+            "KeyC" => Ok(Self::KeyC),
+            "KeyD" => Ok(Self::KeyD),
+            "KeyE" => Ok(Self::KeyE),
+            "KeyF" => Ok(Self::KeyF),
+            "KeyG" => Ok(Self::KeyG),
+            "KeyH" => Ok(Self::KeyH),
+            "KeyI" => Ok(Self::KeyI),
+            "KeyJ" => Ok(Self::KeyJ),
+            "KeyK" => Ok(Self::KeyK),
+            "KeyL" => Ok(Self::KeyL),
+            "KeyM" => Ok(Self::KeyM),
+            "KeyN" => Ok(Self::KeyN),
+            "KeyO" => Ok(Self::KeyO),
+            "KeyP" => Ok(Self::KeyP),
+            "KeyQ" => Ok(Self::KeyQ),
+            "KeyR" => Ok(Self::KeyR),
+            "KeyS" => Ok(Self::KeyS),
+            "KeyT" => Ok(Self::KeyT),
+            "KeyU" => Ok(Self::KeyU),
+            "KeyV" => Ok(Self::KeyV),
+            "KeyW" => Ok(Self::KeyW),
+            "KeyX" => Ok(Self::KeyX),
+            "KeyY" => Ok(Self::KeyY),
+            "KeyZ" => Ok(Self::KeyZ),
+            "Minus" => Ok(Self::Minus),
+            "Period" => Ok(Self::Period),
+            "Quote" => Ok(Self::Quote),
+            "Semicolon" => Ok(Self::Semicolon),
+            "Slash" => Ok(Self::Slash),
+            "AltLeft" => Ok(Self::AltLeft),
+            "AltRight" => Ok(Self::AltRight),
+            "Backspace" => Ok(Self::Backspace),
+            "CapsLock" => Ok(Self::CapsLock),
+            "ContextMenu" => Ok(Self::ContextMenu),
+            "ControlLeft" => Ok(Self::ControlLeft),
+            "ControlRight" => Ok(Self::ControlRight),
+            "Enter" => Ok(Self::Enter),
+            "MetaLeft" => Ok(Self::MetaLeft),
+            "MetaRight" => Ok(Self::MetaRight),
+            "ShiftLeft" => Ok(Self::ShiftLeft),
+            "ShiftRight" => Ok(Self::ShiftRight),
+            "Space" => Ok(Self::Space),
+            "Tab" => Ok(Self::Tab),
+            "Convert" => Ok(Self::Convert),
+            "KanaMode" => Ok(Self::KanaMode),
+            "Lang1" => Ok(Self::Lang1),
+            "Lang2" => Ok(Self::Lang2),
+            "NonConvert" => Ok(Self::NonConvert),
+            "Delete" => Ok(Self::Delete),
+            "End" => Ok(Self::End),
+            "Help" => Ok(Self::Help),
+            "Home" => Ok(Self::Home),
+            "Insert" => Ok(Self::Insert),
+            "PageDown" => Ok(Self::PageDown),
+            "PageUp" => Ok(Self::PageUp),
+            "ArrowDown" => Ok(Self::ArrowDown),
+            "ArrowLeft" => Ok(Self::ArrowLeft),
+            "ArrowRight" => Ok(Self::ArrowRight),
+            "ArrowUp" => Ok(Self::ArrowUp),
+            "NumLock" => Ok(Self::NumLock),
+            "Numpad0" => Ok(Self::Numpad0),
+            "Numpad1" => Ok(Self::Numpad1),
+            "Numpad2" => Ok(Self::Numpad2),
+            "Numpad3" => Ok(Self::Numpad3),
+            "Numpad4" => Ok(Self::Numpad4),
+            "Numpad5" => Ok(Self::Numpad5),
+            "Numpad6" => Ok(Self::Numpad6),
+            "Numpad7" => Ok(Self::Numpad7),
+            "Numpad8" => Ok(Self::Numpad8),
+            "Numpad9" => Ok(Self::Numpad9),
+            "NumpadAdd" => Ok(Self::NumpadAdd),
+            "NumpadComma" => Ok(Self::NumpadComma),
+            "NumpadDecimal" => Ok(Self::NumpadDecimal),
+            "NumpadDivide" => Ok(Self::NumpadDivide),
+            "NumpadEnter" => Ok(Self::NumpadEnter),
+            "NumpadEqual" => Ok(Self::NumpadEqual),
+            "NumpadMultiply" => Ok(Self::NumpadMultiply),
+            "NumpadSubtract" => Ok(Self::NumpadSubtract),
+            "Escape" => Ok(Self::Escape),
+            "F1" => Ok(Self::F1),
+            "F2" => Ok(Self::F2),
+            "F3" => Ok(Self::F3),
+            "F4" => Ok(Self::F4),
+            "F5" => Ok(Self::F5),
+            "F6" => Ok(Self::F6),
+            "F7" => Ok(Self::F7),
+            "F8" => Ok(Self::F8),
+            "F9" => Ok(Self::F9),
+            "F10" => Ok(Self::F10),
+            "F11" => Ok(Self::F11),
+            "F12" => Ok(Self::F12),
+            "F13" => Ok(Self::F13),
+            "F14" => Ok(Self::F14),
+            "F15" => Ok(Self::F15),
+            "F16" => Ok(Self::F16),
+            "F17" => Ok(Self::F17),
+            "F18" => Ok(Self::F18),
+            "F19" => Ok(Self::F19),
+            "F20" => Ok(Self::F20),
+            "F21" => Ok(Self::F21),
+            "F22" => Ok(Self::F22),
+            "F23" => Ok(Self::F23),
+            "F24" => Ok(Self::F24),
+            "PrintScreen" => Ok(Self::PrintScreen),
+            "ScrollLock" => Ok(Self::ScrollLock),
+            "Pause" => Ok(Self::Pause),
+            "" => Err(Error::EmptyKey),
+            s => Err(Error::UnknownKey(s.into())),
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    /// Generate the name of the physical key. The name is the value of [`KeyboardEvent.code`](https://www.w3.org/TR/uievents-code/).
+    ///
+    /// ```
+    /// use keybinds::Code;
+    ///
+    /// assert_eq!(format!("{}", Code::KeyA), "KeyA");
+    /// assert_eq!(format!("{}", Code::NumpadEnter), "NumpadEnter");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Backquote => "Backquote",
+            Self::Backslash => "Backslash",
+            Self::BracketLeft => "BracketLeft",
+            Self::BracketRight => "BracketRight",
+            Self::Comma => "Comma",
+            Self::Digit0 => "Digit0",
+            Self::Digit1 => "Digit1",
+            Self::Digit2 => "Digit2",
+            Self::Digit3 => "Digit3",
+            Self::Digit4 => "Digit4",
+            Self::Digit5 => "Digit5",
+            Self::Digit6 => "Digit6",
+            Self::Digit7 => "Digit7",
+            Self::Digit8 => "Digit8",
+            Self::Digit9 => "Digit9",
+            Self::Equal => "Equal",
+            Self::IntlBackslash => "IntlBackslash",
+            Self::IntlRo => "IntlRo",
+            Self::IntlYen => "IntlYen",
+            Self::KeyA => "KeyA",
+            Self::KeyB => "KeyB",
+            Self::KeyC => "KeyC",
+            Self::KeyD => "KeyD",
+            Self::KeyE => "KeyE",
+            Self::KeyF => "KeyF",
+            Self::KeyG => "KeyG",
+            Self::KeyH => "KeyH",
+            Self::KeyI => "KeyI",
+            Self::KeyJ => "KeyJ",
+            Self::KeyK => "KeyK",
+            Self::KeyL => "KeyL",
+            Self::KeyM => "KeyM",
+            Self::KeyN => "KeyN",
+            Self::KeyO => "KeyO",
+            Self::KeyP => "KeyP",
+            Self::KeyQ => "KeyQ",
+            Self::KeyR => "KeyR",
+            Self::KeyS => "KeyS",
+            Self::KeyT => "KeyT",
+            Self::KeyU => "KeyU",
+            Self::KeyV => "KeyV",
+            Self::KeyW => "KeyW",
+            Self::KeyX => "KeyX",
+            Self::KeyY => "KeyY",
+            Self::KeyZ => "KeyZ",
+            Self::Minus => "Minus",
+            Self::Period => "Period",
+            Self::Quote => "Quote",
+            Self::Semicolon => "Semicolon",
+            Self::Slash => "Slash",
+            Self::AltLeft => "AltLeft",
+            Self::AltRight => "AltRight",
+            Self::Backspace => "Backspace",
+            Self::CapsLock => "CapsLock",
+            Self::ContextMenu => "ContextMenu",
+            Self::ControlLeft => "ControlLeft",
+            Self::ControlRight => "ControlRight",
+            Self::Enter => "Enter",
+            Self::MetaLeft => "MetaLeft",
+            Self::MetaRight => "MetaRight",
+            Self::ShiftLeft => "ShiftLeft",
+            Self::ShiftRight => "ShiftRight",
+            Self::Space => "Space",
+            Self::Tab => "Tab",
+            Self::Convert => "Convert",
+            Self::KanaMode => "KanaMode",
+            Self::Lang1 => "Lang1",
+            Self::Lang2 => "Lang2",
+            Self::NonConvert => "NonConvert",
+            Self::Delete => "Delete",
+            Self::End => "End",
+            Self::Help => "Help",
+            Self::Home => "Home",
+            Self::Insert => "Insert",
+            Self::PageDown => "PageDown",
+            Self::PageUp => "PageUp",
+            Self::ArrowDown => "ArrowDown",
+            Self::ArrowLeft => "ArrowLeft",
+            Self::ArrowRight => "ArrowRight",
+            Self::ArrowUp => "ArrowUp",
+            Self::NumLock => "NumLock",
+            Self::Numpad0 => "Numpad0",
+            Self::Numpad1 => "Numpad1",
+            Self::Numpad2 => "Numpad2",
+            Self::Numpad3 => "Numpad3",
+            Self::Numpad4 => "Numpad4",
+            Self::Numpad5 => "Numpad5",
+            Self::Numpad6 => "Numpad6",
+            Self::Numpad7 => "Numpad7",
+            Self::Numpad8 => "Numpad8",
+            Self::Numpad9 => "Numpad9",
+            Self::NumpadAdd => "NumpadAdd",
+            Self::NumpadComma => "NumpadComma",
+            Self::NumpadDecimal => "NumpadDecimal",
+            Self::NumpadDivide => "NumpadDivide",
+            Self::NumpadEnter => "NumpadEnter",
+            Self::NumpadEqual => "NumpadEqual",
+            Self::NumpadMultiply => "NumpadMultiply",
+            Self::NumpadSubtract => "NumpadSubtract",
+            Self::Escape => "Escape",
+            Self::F1 => "F1",
+            Self::F2 => "F2",
+            Self::F3 => "F3",
+            Self::F4 => "F4",
+            Self::F5 => "F5",
+            Self::F6 => "F6",
+            Self::F7 => "F7",
+            Self::F8 => "F8",
+            Self::F9 => "F9",
+            Self::F10 => "F10",
+            Self::F11 => "F11",
+            Self::F12 => "F12",
+            Self::F13 => "F13",
+            Self::F14 => "F14",
+            Self::F15 => "F15",
+            Self::F16 => "F16",
+            Self::F17 => "F17",
+            Self::F18 => "F18",
+            Self::F19 => "F19",
+            Self::F20 => "F20",
+            Self::F21 => "F21",
+            Self::F22 => "F22",
+            Self::F23 => "F23",
+            Self::F24 => "F24",
+            Self::PrintScreen => "PrintScreen",
+            Self::ScrollLock => "ScrollLock",
+            Self::Pause => "Pause",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_display_code() {
+        for code in [
+            Code::Backquote,
+            Code::Digit0,
+            Code::KeyA,
+            Code::KeyZ,
+            Code::IntlYen,
+            Code::ShiftLeft,
+            Code::ArrowLeft,
+            Code::Numpad9,
+            Code::NumpadEnter,
+            Code::Escape,
+            Code::F24,
+            Code::Pause,
+        ] {
+            let name = code.to_string();
+            assert_eq!(name.parse(), Ok(code), "name={name:?}");
+        }
+    }
+
+    #[test]
+    fn code_qwerty_char() {
+        for (code, expected) in [
+            (Code::KeyA, Some('a')),
+            (Code::KeyZ, Some('z')),
+            (Code::Digit0, Some('0')),
+            (Code::Backslash, Some('\\')),
+            (Code::Quote, Some('\'')),
+            (Code::Space, Some(' ')),
+            (Code::IntlYen, None),
+            (Code::Numpad1, None),
+            (Code::ShiftLeft, None),
+        ] {
+            assert_eq!(code.qwerty_char(), expected, "code={code:?}");
+        }
+    }
+
+    #[test]
+    fn parse_code_error() {
+        for (input, expected) in [
+            ("", Error::EmptyKey),
+            ("  ", Error::EmptyKey),
+            ("keyw", Error::UnknownKey("keyw".into())),
+            ("W", Error::UnknownKey("W".into())),
+            ("F25", Error::UnknownKey("F25".into())),
+        ] {
+            assert_eq!(input.parse::<Code>(), Err(expected), "input={input:?}");
+        }
+    }
+}
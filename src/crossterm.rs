@@ -1,7 +1,8 @@
 //! Support for [`crossterm`] crate.
 //!
 //! This module provides the conversions from crossterm's event types to [`Key`], [`Mods`],
-//! and [`KeyInput`].
+//! and [`KeyInput`]. The reverse conversion from [`KeyInput`] to crossterm's [`KeyEvent`] is also provided to
+//! synthesize key events.
 //!
 //! ```no_run
 //! use crossterm::event::{read, Event};
@@ -38,8 +39,11 @@
 //!
 //! disable_raw_mode().unwrap();
 //! ```
-use crate::{Key, KeyInput, Mods};
-use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MediaKeyCode};
+use crate::{Error, Key, KeyInput, Mods, Platform, Result};
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode, MouseButton,
+    MouseEvent, MouseEventKind,
+};
 
 impl From<KeyCode> for Key {
     /// Convert crossterm's key code into [`Key`].
@@ -106,12 +110,14 @@ impl From<KeyCode> for Key {
             KeyCode::Media(MediaKeyCode::Pause) => Self::Pause,
             KeyCode::Media(MediaKeyCode::PlayPause) => Self::PlayPause,
             KeyCode::Media(MediaKeyCode::Stop) => Self::Stop,
+            KeyCode::Media(MediaKeyCode::FastForward) => Self::FastForward,
             KeyCode::Media(MediaKeyCode::Rewind) => Self::Rewind,
             KeyCode::Media(MediaKeyCode::TrackNext) => Self::NextTrack,
             KeyCode::Media(MediaKeyCode::TrackPrevious) => Self::PrevTrack,
             KeyCode::Media(MediaKeyCode::LowerVolume) => Self::VolumeDown,
             KeyCode::Media(MediaKeyCode::RaiseVolume) => Self::VolumeUp,
             KeyCode::Media(MediaKeyCode::MuteVolume) => Self::Mute,
+            KeyCode::Media(MediaKeyCode::Record) => Self::Record,
             KeyCode::Modifier(_) | KeyCode::Null => Self::Ignored,
             _ => Self::Unidentified,
         }
@@ -119,14 +125,22 @@ impl From<KeyCode> for Key {
 }
 
 impl From<KeyModifiers> for Mods {
+    /// Convert crossterm's modifiers into [`Mods`]. `META` and `HYPER` are converted into [`Mods::META`] and
+    /// [`Mods::HYPER`]. Note that they are only reported by terminals supporting the [kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/).
     fn from(from: KeyModifiers) -> Self {
         let mut to = Mods::NONE;
         if from.contains(KeyModifiers::CONTROL) {
             to |= Mods::CTRL;
         }
-        if from.intersects(KeyModifiers::ALT | KeyModifiers::META) {
+        if from.contains(KeyModifiers::ALT) {
             to |= Mods::ALT;
         }
+        if from.contains(KeyModifiers::META) {
+            to |= Mods::META;
+        }
+        if from.contains(KeyModifiers::HYPER) {
+            to |= Mods::HYPER;
+        }
         if from.contains(KeyModifiers::SUPER) {
             to |= Mods::SUPER;
         }
@@ -140,14 +154,12 @@ impl From<KeyModifiers> for Mods {
 impl From<&KeyEvent> for KeyInput {
     /// Convert crossterm's key events to [`KeyInput`]. The key release events are converted into `Key::Ignored` with no
     /// modifiers.
+    ///
+    /// Keys on the numeric keypad are converted with [`Mods::NUMPAD`]. It is ignored on matching unless key bindings
+    /// specify it. The CapsLock and NumLock states in the event are not converted. Use [`convert_with_lock_states`]
+    /// to keep them.
     fn from(event: &KeyEvent) -> Self {
-        if event.kind == KeyEventKind::Release {
-            return Key::Ignored.into();
-        }
-        if event.code == KeyCode::BackTab {
-            return Self::new(Key::Tab, Mods::from(event.modifiers) | Mods::SHIFT);
-        }
-        Self::new(event.code, event.modifiers)
+        convert(event, false)
     }
 }
 
@@ -157,12 +169,44 @@ impl From<KeyEvent> for KeyInput {
     }
 }
 
+impl From<&MouseEvent> for KeyInput {
+    /// Convert crossterm's mouse events to [`KeyInput`]. Pressing mouse buttons is converted into [`Key::MouseLeft`],
+    /// [`Key::MouseRight`] or [`Key::MouseMiddle`], and scrolling is converted into [`Key::WheelUp`],
+    /// [`Key::WheelDown`], [`Key::WheelLeft`] or [`Key::WheelRight`]. Other mouse events such as releasing buttons,
+    /// dragging, and moving the cursor are converted into `Key::Ignored` with no modifiers.
+    ///
+    /// Note that terminals report mouse events only when mouse capture is enabled by
+    /// [`crossterm::event::EnableMouseCapture`].
+    fn from(event: &MouseEvent) -> Self {
+        let key = match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => Key::MouseLeft,
+            MouseEventKind::Down(MouseButton::Right) => Key::MouseRight,
+            MouseEventKind::Down(MouseButton::Middle) => Key::MouseMiddle,
+            MouseEventKind::ScrollUp => Key::WheelUp,
+            MouseEventKind::ScrollDown => Key::WheelDown,
+            MouseEventKind::ScrollLeft => Key::WheelLeft,
+            MouseEventKind::ScrollRight => Key::WheelRight,
+            MouseEventKind::Up(_) | MouseEventKind::Drag(_) | MouseEventKind::Moved => {
+                return Key::Ignored.into();
+            }
+        };
+        Self::new(key, event.modifiers)
+    }
+}
+
+impl From<MouseEvent> for KeyInput {
+    fn from(event: MouseEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
 impl From<&Event> for KeyInput {
-    /// Convert crossterm's events to [`KeyInput`]. Events unrelated to key presses are converted into `Key::Ignored`
-    /// with no modifiers.
+    /// Convert crossterm's events to [`KeyInput`]. Key events and mouse events are converted in the same way as
+    /// converting [`KeyEvent`] and [`MouseEvent`]. Other events are converted into `Key::Ignored` with no modifiers.
     fn from(event: &Event) -> Self {
         match event {
             Event::Key(event) => event.into(),
+            Event::Mouse(event) => event.into(),
             _ => Key::Ignored.into(),
         }
     }
@@ -174,10 +218,217 @@ impl From<Event> for KeyInput {
     }
 }
 
+/// Convert crossterm's key event to [`KeyInput`] with the CapsLock and NumLock states. The states in the event are
+/// converted into [`Mods::CAPS_LOCK`] and [`Mods::NUM_LOCK`]. They are ignored on matching unless key bindings specify
+/// them. Otherwise this is the same as `KeyInput::from`.
+///
+/// Note that terminals only report the lock states with the [kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/)
+/// when all keys are reported as escape codes.
+///
+/// ```
+/// use keybinds::{KeyInput, Mods};
+/// use keybinds::crossterm::convert_with_lock_states;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+///
+/// let event = KeyEvent {
+///     code: KeyCode::Char('a'),
+///     modifiers: KeyModifiers::CONTROL,
+///     kind: KeyEventKind::Press,
+///     state: KeyEventState::CAPS_LOCK,
+/// };
+///
+/// assert_eq!(convert_with_lock_states(&event), KeyInput::new('a', Mods::CTRL | Mods::CAPS_LOCK));
+/// assert_eq!(KeyInput::from(event), KeyInput::new('a', Mods::CTRL));
+/// ```
+pub fn convert_with_lock_states(event: &KeyEvent) -> KeyInput {
+    convert(event, true)
+}
+
+fn convert(event: &KeyEvent, lock_states: bool) -> KeyInput {
+    if event.kind == KeyEventKind::Release {
+        return Key::Ignored.into();
+    }
+    let mut mods = Mods::from(event.modifiers);
+    if lock_states && event.state.contains(KeyEventState::CAPS_LOCK) {
+        mods |= Mods::CAPS_LOCK;
+    }
+    if lock_states && event.state.contains(KeyEventState::NUM_LOCK) {
+        mods |= Mods::NUM_LOCK;
+    }
+    if event.state.contains(KeyEventState::KEYPAD) {
+        mods |= Mods::NUMPAD;
+    }
+    if event.code == KeyCode::BackTab {
+        return KeyInput::new(Key::Tab, mods | Mods::SHIFT);
+    }
+    KeyInput::new(event.code, mods)
+}
+
+// Convert the key into crossterm's key code. This is the reverse of `From<KeyCode> for Key`.
+fn key_code(key: Key) -> Option<KeyCode> {
+    let code = match key {
+        Key::Char(c) => KeyCode::Char(c),
+        Key::Backspace => KeyCode::Backspace,
+        Key::Enter => KeyCode::Enter,
+        Key::Left => KeyCode::Left,
+        Key::Right => KeyCode::Right,
+        Key::Up => KeyCode::Up,
+        Key::Down => KeyCode::Down,
+        Key::Home => KeyCode::Home,
+        Key::End => KeyCode::End,
+        Key::PageUp => KeyCode::PageUp,
+        Key::PageDown => KeyCode::PageDown,
+        Key::Tab => KeyCode::Tab,
+        Key::Delete => KeyCode::Delete,
+        Key::Insert => KeyCode::Insert,
+        Key::F1 => KeyCode::F(1),
+        Key::F2 => KeyCode::F(2),
+        Key::F3 => KeyCode::F(3),
+        Key::F4 => KeyCode::F(4),
+        Key::F5 => KeyCode::F(5),
+        Key::F6 => KeyCode::F(6),
+        Key::F7 => KeyCode::F(7),
+        Key::F8 => KeyCode::F(8),
+        Key::F9 => KeyCode::F(9),
+        Key::F10 => KeyCode::F(10),
+        Key::F11 => KeyCode::F(11),
+        Key::F12 => KeyCode::F(12),
+        Key::F13 => KeyCode::F(13),
+        Key::F14 => KeyCode::F(14),
+        Key::F15 => KeyCode::F(15),
+        Key::F16 => KeyCode::F(16),
+        Key::F17 => KeyCode::F(17),
+        Key::F18 => KeyCode::F(18),
+        Key::F19 => KeyCode::F(19),
+        Key::F20 => KeyCode::F(20),
+        Key::F21 => KeyCode::F(21),
+        Key::F22 => KeyCode::F(22),
+        Key::F23 => KeyCode::F(23),
+        Key::F24 => KeyCode::F(24),
+        Key::F25 => KeyCode::F(25),
+        Key::F26 => KeyCode::F(26),
+        Key::F27 => KeyCode::F(27),
+        Key::F28 => KeyCode::F(28),
+        Key::F29 => KeyCode::F(29),
+        Key::F30 => KeyCode::F(30),
+        Key::F31 => KeyCode::F(31),
+        Key::F32 => KeyCode::F(32),
+        Key::F33 => KeyCode::F(33),
+        Key::F34 => KeyCode::F(34),
+        Key::F35 => KeyCode::F(35),
+        Key::Esc => KeyCode::Esc,
+        Key::ScrollLock => KeyCode::ScrollLock,
+        Key::NumLock => KeyCode::NumLock,
+        Key::PrintScreen => KeyCode::PrintScreen,
+        Key::Menu => KeyCode::Menu,
+        Key::Play => KeyCode::Media(MediaKeyCode::Play),
+        Key::Pause => KeyCode::Media(MediaKeyCode::Pause),
+        Key::PlayPause => KeyCode::Media(MediaKeyCode::PlayPause),
+        Key::Stop => KeyCode::Media(MediaKeyCode::Stop),
+        Key::FastForward => KeyCode::Media(MediaKeyCode::FastForward),
+        Key::Rewind => KeyCode::Media(MediaKeyCode::Rewind),
+        Key::NextTrack => KeyCode::Media(MediaKeyCode::TrackNext),
+        Key::PrevTrack => KeyCode::Media(MediaKeyCode::TrackPrevious),
+        Key::VolumeDown => KeyCode::Media(MediaKeyCode::LowerVolume),
+        Key::VolumeUp => KeyCode::Media(MediaKeyCode::RaiseVolume),
+        Key::Mute => KeyCode::Media(MediaKeyCode::MuteVolume),
+        Key::Record => KeyCode::Media(MediaKeyCode::Record),
+        _ => return None,
+    };
+    Some(code)
+}
+
+impl TryFrom<KeyInput> for KeyEvent {
+    type Error = Error;
+
+    /// Convert [`KeyInput`] into crossterm's key press event. [`Mods::CAPS_LOCK`], [`Mods::NUM_LOCK`] and
+    /// [`Mods::NUMPAD`] are converted into the state of the event and <kbd>Shift</kbd> + <kbd>Tab</kbd> is converted
+    /// into `KeyCode::BackTab`. crossterm has no left and right modifiers nor physical keys so they are dropped.
+    /// [`Mods::ALTGR`] causes [`Error::UnconvertibleInput`]. See the error for the common behavior of the conversions.
+    ///
+    /// ```
+    /// use crossterm::event::{KeyCode, KeyEvent, KeyEventState, KeyModifiers};
+    /// use keybinds::{Key, KeyInput, Mods};
+    ///
+    /// let input = KeyInput::new(Key::Tab, Mods::SHIFT);
+    /// let event = KeyEvent::try_from(input).unwrap();
+    /// assert_eq!(event.code, KeyCode::BackTab);
+    /// assert_eq!(event.modifiers, KeyModifiers::SHIFT);
+    /// assert_eq!(KeyInput::from(event), input);
+    ///
+    /// let input = KeyInput::new(Key::Enter, Mods::NUMPAD);
+    /// let event = KeyEvent::try_from(input).unwrap();
+    /// assert_eq!(event.state, KeyEventState::KEYPAD);
+    /// ```
+    fn try_from(input: KeyInput) -> Result<Self> {
+        to_key_event(input, Platform::current())
+    }
+}
+
+/// Convert [`KeyInput`] into crossterm's key press event on the given platform. See [`Platform`] for which to pass.
+///
+/// ```
+/// use crossterm::event::{KeyCode, KeyModifiers};
+/// use keybinds::crossterm::to_key_event;
+/// use keybinds::{KeyInput, Mods, Platform};
+///
+/// let input = KeyInput::new('s', Mods::MOD);
+/// let event = to_key_event(input, Platform::MacOS).unwrap();
+/// assert_eq!(event.code, KeyCode::Char('s'));
+/// assert_eq!(event.modifiers, KeyModifiers::SUPER);
+///
+/// let event = to_key_event(input, Platform::Linux).unwrap();
+/// assert_eq!(event.modifiers, KeyModifiers::CONTROL);
+/// ```
+pub fn to_key_event(input: KeyInput, platform: Platform) -> Result<KeyEvent> {
+    let unconvertible = || Error::UnconvertibleInput(input);
+    let mods = input.physical_mods().resolve(platform);
+    if mods.contains(Mods::ALTGR) {
+        return Err(unconvertible());
+    }
+
+    let mut modifiers = KeyModifiers::NONE;
+    for (from, to) in [
+        (Mods::CTRL, KeyModifiers::CONTROL),
+        (Mods::ALT, KeyModifiers::ALT),
+        (Mods::CMD, KeyModifiers::SUPER),
+        (Mods::WIN, KeyModifiers::SUPER),
+        (Mods::META, KeyModifiers::META),
+        (Mods::HYPER, KeyModifiers::HYPER),
+        (Mods::SHIFT, KeyModifiers::SHIFT),
+    ] {
+        if mods.contains(from) {
+            modifiers |= to;
+        }
+    }
+
+    let mut state = KeyEventState::NONE;
+    for (from, to) in [
+        (Mods::CAPS_LOCK, KeyEventState::CAPS_LOCK),
+        (Mods::NUM_LOCK, KeyEventState::NUM_LOCK),
+        (Mods::NUMPAD, KeyEventState::KEYPAD),
+    ] {
+        if mods.contains(from) {
+            state |= to;
+        }
+    }
+
+    let code = match input.key() {
+        Key::Tab if mods.contains(Mods::SHIFT) => KeyCode::BackTab,
+        key => key_code(key).ok_or_else(unconvertible)?,
+    };
+    Ok(KeyEvent::new_with_kind_and_state(
+        code,
+        modifiers,
+        KeyEventKind::Press,
+        state,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crossterm::event::{KeyEventState, ModifierKeyCode};
+    use crossterm::event::ModifierKeyCode;
 
     #[test]
     fn convert_key_code() {
@@ -191,6 +442,11 @@ mod tests {
             Key::Ignored,
         );
         assert_eq!(Key::from(KeyCode::Media(MediaKeyCode::Play)), Key::Play);
+        assert_eq!(
+            Key::from(KeyCode::Media(MediaKeyCode::FastForward)),
+            Key::FastForward,
+        );
+        assert_eq!(Key::from(KeyCode::Media(MediaKeyCode::Record)), Key::Record,);
         assert_eq!(Key::from(KeyCode::F(12)), Key::F12);
         assert_eq!(Key::from(KeyCode::BackTab), Key::Unidentified);
     }
@@ -205,9 +461,15 @@ mod tests {
                     | KeyModifiers::ALT
                     | KeyModifiers::META
             ),
-            Mods::CTRL | Mods::ALT | Mods::SHIFT,
+            Mods::CTRL | Mods::ALT | Mods::META | Mods::SHIFT,
         );
         assert_eq!(Mods::from(KeyModifiers::SUPER), Mods::SUPER);
+        assert_eq!(Mods::from(KeyModifiers::META), Mods::META);
+        assert_eq!(Mods::from(KeyModifiers::HYPER), Mods::HYPER);
+        assert_eq!(
+            Mods::from(KeyModifiers::ALT | KeyModifiers::META),
+            Mods::ALT | Mods::META,
+        );
     }
 
     #[test]
@@ -250,6 +512,203 @@ mod tests {
             }),
             KeyInput::new(Key::Tab, Mods::SHIFT),
         );
+        let locked = KeyEvent {
+            code: KeyCode::Char('a'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::CAPS_LOCK | KeyEventState::NUM_LOCK,
+        };
+        assert_eq!(KeyInput::from(locked), KeyInput::new('a', Mods::CTRL));
+        assert_eq!(
+            convert_with_lock_states(&locked),
+            KeyInput::new('a', Mods::CTRL | Mods::CAPS_LOCK | Mods::NUM_LOCK),
+        );
+        assert_eq!(
+            KeyInput::from(locked).to_string(),
+            "Ctrl+a",
+            "lock states must not appear by default",
+        );
+        assert_eq!(
+            KeyInput::from(KeyEvent {
+                code: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+                kind: KeyEventKind::Press,
+                state: KeyEventState::KEYPAD,
+            }),
+            KeyInput::new(Key::Enter, Mods::NUMPAD),
+        );
+    }
+
+    #[test]
+    fn convert_key_input_to_key_event() {
+        let event = KeyEvent::try_from(KeyInput::new('A', Mods::CTRL | Mods::ALT)).unwrap();
+        assert_eq!(event.code, KeyCode::Char('A'));
+        assert_eq!(event.modifiers, KeyModifiers::CONTROL | KeyModifiers::ALT);
+        assert_eq!(event.kind, KeyEventKind::Press);
+        assert_eq!(event.state, KeyEventState::NONE);
+
+        // Sides of modifiers and physical key code are dropped
+        let input = KeyInput::with_code('x', Mods::LCTRL | Mods::RALT, crate::Code::KeyX);
+        let event = KeyEvent::try_from(input).unwrap();
+        assert_eq!(event.code, KeyCode::Char('x'));
+        assert_eq!(event.modifiers, KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Tab, Mods::SHIFT)).unwrap();
+        assert_eq!(event.code, KeyCode::BackTab);
+        assert_eq!(event.modifiers, KeyModifiers::SHIFT);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Enter, Mods::NUMPAD)).unwrap();
+        assert_eq!(event.code, KeyCode::Enter);
+        assert_eq!(event.state, KeyEventState::KEYPAD);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Up, Mods::SUPER)).unwrap();
+        assert_eq!(event.modifiers, KeyModifiers::SUPER);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Play, Mods::NONE)).unwrap();
+        assert_eq!(event.code, KeyCode::Media(MediaKeyCode::Play));
+    }
+
+    #[test]
+    fn convert_key_input_to_key_event_on_platform() {
+        for (platform, expected) in [
+            (Platform::MacOS, KeyModifiers::SUPER),
+            (Platform::Linux, KeyModifiers::CONTROL),
+            (Platform::Windows, KeyModifiers::CONTROL),
+        ] {
+            let event = to_key_event(KeyInput::new('a', Mods::MOD), platform).unwrap();
+            assert_eq!(event.modifiers, expected, "{platform:?}");
+        }
+        for platform in [Platform::MacOS, Platform::Linux, Platform::Windows] {
+            let event = to_key_event(KeyInput::new('a', Mods::SUPER), platform).unwrap();
+            assert_eq!(event.modifiers, KeyModifiers::SUPER, "{platform:?}");
+        }
+        let input = KeyInput::new('a', Mods::MOD);
+        assert_eq!(
+            KeyEvent::try_from(input),
+            to_key_event(input, Platform::current()),
+        );
+    }
+
+    #[test]
+    fn convert_key_input_to_key_event_state() {
+        for (input, state) in [
+            (KeyInput::new('x', Mods::CTRL), KeyEventState::NONE),
+            (
+                KeyInput::new('x', Mods::CAPS_LOCK),
+                KeyEventState::CAPS_LOCK,
+            ),
+            (
+                KeyInput::new(Key::Enter, Mods::NUMPAD | Mods::NUM_LOCK),
+                KeyEventState::KEYPAD | KeyEventState::NUM_LOCK,
+            ),
+        ] {
+            let event = KeyEvent::try_from(input).unwrap();
+            assert_eq!(event.state, state, "{input:?}");
+            assert_eq!(convert_with_lock_states(&event), input, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn convert_key_input_without_key_code() {
+        for input in [
+            KeyInput::new(Key::MouseLeft, Mods::NONE),
+            KeyInput::new(Key::Physical(crate::Code::KeyA), Mods::NONE),
+            KeyInput::new(Key::Ignored, Mods::NONE),
+            KeyInput::new(Key::Copy, Mods::NONE),
+            KeyInput::new(Key::Str(crate::KeyStr::new("あい").unwrap()), Mods::NONE),
+            KeyInput::new('@', Mods::ALTGR),
+        ] {
+            assert_eq!(
+                KeyEvent::try_from(input),
+                Err(Error::UnconvertibleInput(input)),
+                "{input:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn key_code_round_trip() {
+        for code in [
+            KeyCode::Backspace,
+            KeyCode::Char('x'),
+            KeyCode::Char(' '),
+            KeyCode::F(12),
+            KeyCode::F(35),
+            KeyCode::PageDown,
+            KeyCode::Menu,
+            KeyCode::Media(MediaKeyCode::Stop),
+            KeyCode::Media(MediaKeyCode::TrackNext),
+            KeyCode::Media(MediaKeyCode::MuteVolume),
+            KeyCode::Media(MediaKeyCode::FastForward),
+            KeyCode::Media(MediaKeyCode::Record),
+            KeyCode::PrintScreen,
+        ] {
+            for modifiers in [
+                KeyModifiers::NONE,
+                KeyModifiers::CONTROL | KeyModifiers::ALT,
+                KeyModifiers::SUPER | KeyModifiers::META | KeyModifiers::HYPER,
+            ] {
+                let event = KeyEvent::new(code, modifiers);
+                let input = KeyInput::from(event);
+                assert_eq!(
+                    KeyEvent::try_from(input),
+                    Ok(event),
+                    "{code:?} {modifiers:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn convert_mouse_event() {
+        let mouse = |kind, modifiers| MouseEvent {
+            kind,
+            column: 3,
+            row: 5,
+            modifiers,
+        };
+        assert_eq!(
+            KeyInput::from(mouse(
+                MouseEventKind::Down(MouseButton::Left),
+                KeyModifiers::CONTROL,
+            )),
+            KeyInput::new(Key::MouseLeft, Mods::CTRL),
+        );
+        assert_eq!(
+            KeyInput::from(mouse(
+                MouseEventKind::Down(MouseButton::Right),
+                KeyModifiers::NONE,
+            )),
+            KeyInput::new(Key::MouseRight, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(mouse(
+                MouseEventKind::Down(MouseButton::Middle),
+                KeyModifiers::SHIFT,
+            )),
+            KeyInput::new(Key::MouseMiddle, Mods::SHIFT),
+        );
+        for (kind, key) in [
+            (MouseEventKind::ScrollUp, Key::WheelUp),
+            (MouseEventKind::ScrollDown, Key::WheelDown),
+            (MouseEventKind::ScrollLeft, Key::WheelLeft),
+            (MouseEventKind::ScrollRight, Key::WheelRight),
+        ] {
+            assert_eq!(
+                KeyInput::from(mouse(kind, KeyModifiers::ALT)),
+                KeyInput::new(key, Mods::ALT),
+            );
+        }
+        for kind in [
+            MouseEventKind::Up(MouseButton::Left),
+            MouseEventKind::Drag(MouseButton::Left),
+            MouseEventKind::Moved,
+        ] {
+            assert_eq!(
+                KeyInput::from(mouse(kind, KeyModifiers::CONTROL)),
+                KeyInput::new(Key::Ignored, Mods::NONE),
+            );
+        }
     }
 
     #[test]
@@ -263,6 +722,15 @@ mod tests {
             })),
             KeyInput::new('A', Mods::CTRL),
         );
+        assert_eq!(
+            KeyInput::from(Event::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollUp,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::ALT,
+            })),
+            KeyInput::new(Key::WheelUp, Mods::ALT),
+        );
         assert_eq!(
             KeyInput::from(Event::FocusGained),
             KeyInput::new(Key::Ignored, Mods::NONE),
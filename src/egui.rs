@@ -0,0 +1,549 @@
+//! Support for [`egui`] crate.
+//!
+//! This module provides:
+//!
+//! - the conversion from egui's key, modifiers and event types to [`Key`], [`Mods`] and [`KeyInput`]
+//! - [`dispatch_input`] function to dispatch actions for the key events in egui's [`InputState`] each frame
+//!
+//! ```no_run
+//! use keybinds::egui::dispatch_input;
+//! use keybinds::Keybinds;
+//!
+//! // Actions dispatched by key bindings
+//! #[derive(Clone, Debug)]
+//! enum Action {
+//!     SayHi,
+//!     Save,
+//! }
+//!
+//! struct App {
+//!     keybinds: Keybinds<Action>,
+//!     log: Vec<String>,
+//! }
+//!
+//! impl App {
+//!     fn new() -> Self {
+//!         // Create a key bindings dispatcher to dispatch actions for upcoming key inputs
+//!         let mut keybinds = Keybinds::default();
+//!
+//!         // Key bindings to dispatch the actions
+//!         keybinds.bind("h i", Action::SayHi).unwrap();
+//!         keybinds.bind("Mod+s", Action::Save).unwrap();
+//!
+//!         Self { keybinds, log: vec![] }
+//!     }
+//!
+//!     // Call this method in each frame
+//!     fn ui(&mut self, ctx: &egui::Context) {
+//!         // Dispatch actions for the key events in this frame. The key events which dispatched actions are consumed
+//!         // so that widgets don't handle them.
+//!         let mut actions = vec![];
+//!         ctx.input_mut(|input| {
+//!             dispatch_input(&mut self.keybinds, input, |action| actions.push(action.clone()));
+//!         });
+//!
+//!         for action in actions {
+//!             self.log.push(format!("{action:?}"));
+//!         }
+//!
+//!         egui::CentralPanel::default().show(ctx, |ui| {
+//!             for line in &self.log {
+//!                 ui.label(line);
+//!             }
+//!         });
+//!     }
+//! }
+//! ```
+use crate::{Code, Key, KeyInput, Keybinds, Mods};
+use egui::{Event, InputState, Key as EguiKey, Modifiers};
+
+impl From<EguiKey> for Key {
+    /// Convert egui's [`Key`][EguiKey] into [`Key`]. Since egui's keys are case-insensitive, letter keys are converted
+    /// into lower case characters.
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use egui::Key as EguiKey;
+    ///
+    /// assert_eq!(Key::from(EguiKey::ArrowUp), Key::Up);
+    /// assert_eq!(Key::from(EguiKey::A), Key::Char('a'));
+    /// assert_eq!(Key::from(EguiKey::Questionmark), Key::Char('?'));
+    /// ```
+    fn from(key: EguiKey) -> Self {
+        match key {
+            EguiKey::ArrowDown => Self::Down,
+            EguiKey::ArrowLeft => Self::Left,
+            EguiKey::ArrowRight => Self::Right,
+            EguiKey::ArrowUp => Self::Up,
+            EguiKey::Escape => Self::Esc,
+            EguiKey::Tab => Self::Tab,
+            EguiKey::Backspace => Self::Backspace,
+            EguiKey::Enter => Self::Enter,
+            EguiKey::Insert => Self::Insert,
+            EguiKey::Delete => Self::Delete,
+            EguiKey::Home => Self::Home,
+            EguiKey::End => Self::End,
+            EguiKey::PageUp => Self::PageUp,
+            EguiKey::PageDown => Self::PageDown,
+            EguiKey::Copy => Self::Copy,
+            EguiKey::Cut => Self::Cut,
+            EguiKey::Paste => Self::Paste,
+            EguiKey::Space => Self::Char(' '),
+            EguiKey::Colon => Self::Char(':'),
+            EguiKey::Comma => Self::Char(','),
+            EguiKey::Backslash => Self::Char('\\'),
+            EguiKey::Slash => Self::Char('/'),
+            EguiKey::Pipe => Self::Char('|'),
+            EguiKey::Questionmark => Self::Char('?'),
+            EguiKey::Exclamationmark => Self::Char('!'),
+            EguiKey::OpenBracket => Self::Char('['),
+            EguiKey::CloseBracket => Self::Char(']'),
+            EguiKey::OpenCurlyBracket => Self::Char('{'),
+            EguiKey::CloseCurlyBracket => Self::Char('}'),
+            EguiKey::Backtick => Self::Char('`'),
+            EguiKey::Minus => Self::Char('-'),
+            EguiKey::Period => Self::Char('.'),
+            EguiKey::Plus => Self::Char('+'),
+            EguiKey::Equals => Self::Char('='),
+            EguiKey::Semicolon => Self::Char(';'),
+            EguiKey::Quote => Self::Char('\''),
+            EguiKey::Num0 => Self::Char('0'),
+            EguiKey::Num1 => Self::Char('1'),
+            EguiKey::Num2 => Self::Char('2'),
+            EguiKey::Num3 => Self::Char('3'),
+            EguiKey::Num4 => Self::Char('4'),
+            EguiKey::Num5 => Self::Char('5'),
+            EguiKey::Num6 => Self::Char('6'),
+            EguiKey::Num7 => Self::Char('7'),
+            EguiKey::Num8 => Self::Char('8'),
+            EguiKey::Num9 => Self::Char('9'),
+            EguiKey::A => Self::Char('a'),
+            EguiKey::B => Self::Char('b'),
+            EguiKey::C => Self::Char('c'),
+            EguiKey::D => Self::Char('d'),
+            EguiKey::E => Self::Char('e'),
+            EguiKey::F => Self::Char('f'),
+            EguiKey::G => Self::Char('g'),
+            EguiKey::H => Self::Char('h'),
+            EguiKey::I => Self::Char('i'),
+            EguiKey::J => Self::Char('j'),
+            EguiKey::K => Self::Char('k'),
+            EguiKey::L => Self::Char('l'),
+            EguiKey::M => Self::Char('m'),
+            EguiKey::N => Self::Char('n'),
+            EguiKey::O => Self::Char('o'),
+            EguiKey::P => Self::Char('p'),
+            EguiKey::Q => Self::Char('q'),
+            EguiKey::R => Self::Char('r'),
+            EguiKey::S => Self::Char('s'),
+            EguiKey::T => Self::Char('t'),
+            EguiKey::U => Self::Char('u'),
+            EguiKey::V => Self::Char('v'),
+            EguiKey::W => Self::Char('w'),
+            EguiKey::X => Self::Char('x'),
+            EguiKey::Y => Self::Char('y'),
+            EguiKey::Z => Self::Char('z'),
+            EguiKey::F1 => Self::F1,
+            EguiKey::F2 => Self::F2,
+            EguiKey::F3 => Self::F3,
+            EguiKey::F4 => Self::F4,
+            EguiKey::F5 => Self::F5,
+            EguiKey::F6 => Self::F6,
+            EguiKey::F7 => Self::F7,
+            EguiKey::F8 => Self::F8,
+            EguiKey::F9 => Self::F9,
+            EguiKey::F10 => Self::F10,
+            EguiKey::F11 => Self::F11,
+            EguiKey::F12 => Self::F12,
+            EguiKey::F13 => Self::F13,
+            EguiKey::F14 => Self::F14,
+            EguiKey::F15 => Self::F15,
+            EguiKey::F16 => Self::F16,
+            EguiKey::F17 => Self::F17,
+            EguiKey::F18 => Self::F18,
+            EguiKey::F19 => Self::F19,
+            EguiKey::F20 => Self::F20,
+            EguiKey::F21 => Self::F21,
+            EguiKey::F22 => Self::F22,
+            EguiKey::F23 => Self::F23,
+            EguiKey::F24 => Self::F24,
+            EguiKey::F25 => Self::F25,
+            EguiKey::F26 => Self::F26,
+            EguiKey::F27 => Self::F27,
+            EguiKey::F28 => Self::F28,
+            EguiKey::F29 => Self::F29,
+            EguiKey::F30 => Self::F30,
+            EguiKey::F31 => Self::F31,
+            EguiKey::F32 => Self::F32,
+            EguiKey::F33 => Self::F33,
+            EguiKey::F34 => Self::F34,
+            EguiKey::F35 => Self::F35,
+            EguiKey::BrowserBack => Self::BrowserBack,
+        }
+    }
+}
+
+impl From<&EguiKey> for Key {
+    fn from(key: &EguiKey) -> Self {
+        Self::from(*key)
+    }
+}
+
+// Convert egui's physical key into the physical key position. Keys representing shifted characters such as `Colon`
+// have no position.
+fn physical_code(key: EguiKey) -> Option<Code> {
+    let code = match key {
+        EguiKey::ArrowDown => Code::ArrowDown,
+        EguiKey::ArrowLeft => Code::ArrowLeft,
+        EguiKey::ArrowRight => Code::ArrowRight,
+        EguiKey::ArrowUp => Code::ArrowUp,
+        EguiKey::Escape => Code::Escape,
+        EguiKey::Tab => Code::Tab,
+        EguiKey::Backspace => Code::Backspace,
+        EguiKey::Enter => Code::Enter,
+        EguiKey::Insert => Code::Insert,
+        EguiKey::Delete => Code::Delete,
+        EguiKey::Home => Code::Home,
+        EguiKey::End => Code::End,
+        EguiKey::PageUp => Code::PageUp,
+        EguiKey::PageDown => Code::PageDown,
+        EguiKey::Space => Code::Space,
+        EguiKey::Comma => Code::Comma,
+        EguiKey::Backslash => Code::Backslash,
+        EguiKey::Slash => Code::Slash,
+        EguiKey::OpenBracket => Code::BracketLeft,
+        EguiKey::CloseBracket => Code::BracketRight,
+        EguiKey::Backtick => Code::Backquote,
+        EguiKey::Minus => Code::Minus,
+        EguiKey::Period => Code::Period,
+        EguiKey::Equals => Code::Equal,
+        EguiKey::Semicolon => Code::Semicolon,
+        EguiKey::Quote => Code::Quote,
+        EguiKey::Num0 => Code::Digit0,
+        EguiKey::Num1 => Code::Digit1,
+        EguiKey::Num2 => Code::Digit2,
+        EguiKey::Num3 => Code::Digit3,
+        EguiKey::Num4 => Code::Digit4,
+        EguiKey::Num5 => Code::Digit5,
+        EguiKey::Num6 => Code::Digit6,
+        EguiKey::Num7 => Code::Digit7,
+        EguiKey::Num8 => Code::Digit8,
+        EguiKey::Num9 => Code::Digit9,
+        EguiKey::A => Code::KeyA,
+        EguiKey::B => Code::KeyB,
+        EguiKey::C => Code::KeyC,
+        EguiKey::D => Code::KeyD,
+        EguiKey::E => Code::KeyE,
+        EguiKey::F => Code::KeyF,
+        EguiKey::G => Code::KeyG,
+        EguiKey::H => Code::KeyH,
+        EguiKey::I => Code::KeyI,
+        EguiKey::J => Code::KeyJ,
+        EguiKey::K => Code::KeyK,
+        EguiKey::L => Code::KeyL,
+        EguiKey::M => Code::KeyM,
+        EguiKey::N => Code::KeyN,
+        EguiKey::O => Code::KeyO,
+        EguiKey::P => Code::KeyP,
+        EguiKey::Q => Code::KeyQ,
+        EguiKey::R => Code::KeyR,
+        EguiKey::S => Code::KeyS,
+        EguiKey::T => Code::KeyT,
+        EguiKey::U => Code::KeyU,
+        EguiKey::V => Code::KeyV,
+        EguiKey::W => Code::KeyW,
+        EguiKey::X => Code::KeyX,
+        EguiKey::Y => Code::KeyY,
+        EguiKey::Z => Code::KeyZ,
+        EguiKey::F1 => Code::F1,
+        EguiKey::F2 => Code::F2,
+        EguiKey::F3 => Code::F3,
+        EguiKey::F4 => Code::F4,
+        EguiKey::F5 => Code::F5,
+        EguiKey::F6 => Code::F6,
+        EguiKey::F7 => Code::F7,
+        EguiKey::F8 => Code::F8,
+        EguiKey::F9 => Code::F9,
+        EguiKey::F10 => Code::F10,
+        EguiKey::F11 => Code::F11,
+        EguiKey::F12 => Code::F12,
+        EguiKey::F13 => Code::F13,
+        EguiKey::F14 => Code::F14,
+        EguiKey::F15 => Code::F15,
+        EguiKey::F16 => Code::F16,
+        EguiKey::F17 => Code::F17,
+        EguiKey::F18 => Code::F18,
+        EguiKey::F19 => Code::F19,
+        EguiKey::F20 => Code::F20,
+        EguiKey::F21 => Code::F21,
+        EguiKey::F22 => Code::F22,
+        EguiKey::F23 => Code::F23,
+        EguiKey::F24 => Code::F24,
+        _ => return None,
+    };
+    Some(code)
+}
+
+impl From<&Modifiers> for Mods {
+    /// Convert egui's [`Modifiers`] into [`Mods`]. `command` is converted into [`Mods::MOD`] because it is
+    /// <kbd>Command</kbd> key on macOS and <kbd>Ctrl</kbd> key on other platforms. `mac_cmd` is converted into
+    /// [`Mods::CMD`].
+    ///
+    /// ```
+    /// use keybinds::Mods;
+    /// use egui::Modifiers;
+    ///
+    /// assert_eq!(Mods::from(Modifiers::CTRL), Mods::CTRL);
+    /// assert_eq!(Mods::from(Modifiers::ALT | Modifiers::SHIFT), Mods::ALT | Mods::SHIFT);
+    /// assert_eq!(Mods::from(Modifiers::COMMAND), Mods::MOD);
+    /// assert_eq!(Mods::from(Modifiers::MAC_CMD), Mods::CMD);
+    /// ```
+    ///
+    /// egui sets both `ctrl` and `command` when <kbd>Ctrl</kbd> key is pressed on Windows or Linux, and both `mac_cmd`
+    /// and `command` when <kbd>Command</kbd> key is pressed on macOS. The concrete modifier is replaced with
+    /// [`Mods::MOD`] in the case. It is resolved on matching so the input matches to both `Ctrl+S` and `Mod+S` key
+    /// bindings on Windows or Linux.
+    fn from(from: &Modifiers) -> Self {
+        let mut to = Mods::NONE;
+        if from.ctrl {
+            to |= Mods::CTRL;
+        }
+        if from.alt {
+            to |= Mods::ALT;
+        }
+        if from.shift {
+            to |= Mods::SHIFT;
+        }
+        if from.mac_cmd {
+            to |= Mods::CMD;
+        }
+        if from.command {
+            to.remove(if from.mac_cmd { Mods::CMD } else { Mods::CTRL });
+            to |= Mods::MOD;
+        }
+        to
+    }
+}
+
+impl From<Modifiers> for Mods {
+    fn from(mods: Modifiers) -> Self {
+        Self::from(&mods)
+    }
+}
+
+impl From<&Event> for KeyInput {
+    /// Convert egui's [`Event`] into [`KeyInput`]. Key press events including key repeats are converted into the key
+    /// inputs. Other events are converted into `Key::Ignored`.
+    ///
+    /// Letter keys pressed with <kbd>Shift</kbd> are converted into upper case characters so that key bindings like
+    /// `Ctrl+A` match. When the event has the physical key, it is also converted so that physical key bindings like
+    /// `[KeyW]` can match to the input. See [`KeyInput::with_code`] for more details.
+    ///
+    /// ```
+    /// use keybinds::{Code, KeyInput, Mods};
+    /// use egui::{Event, Key, Modifiers};
+    ///
+    /// let event = Event::Key {
+    ///     key: Key::A,
+    ///     physical_key: Some(Key::A),
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL | Modifiers::SHIFT,
+    /// };
+    /// assert_eq!(KeyInput::from(&event), KeyInput::with_code('A', Mods::CTRL | Mods::SHIFT, Code::KeyA));
+    ///
+    /// let event = Event::Text("a".into());
+    /// assert_eq!(KeyInput::from(&event), KeyInput::from(keybinds::Key::Ignored));
+    /// ```
+    fn from(event: &Event) -> Self {
+        let Event::Key {
+            key,
+            physical_key,
+            pressed: true,
+            modifiers,
+            ..
+        } = event
+        else {
+            return Key::Ignored.into();
+        };
+
+        let mods = Mods::from(modifiers);
+        let key = match Key::from(key) {
+            Key::Char(c) if mods.contains(Mods::SHIFT) => Key::Char(c.to_ascii_uppercase()),
+            key => key,
+        };
+        match physical_key.and_then(physical_code) {
+            Some(code) => KeyInput::with_code(key, mods, code),
+            None => KeyInput::new(key, mods),
+        }
+    }
+}
+
+impl From<Event> for KeyInput {
+    fn from(event: Event) -> Self {
+        Self::from(&event)
+    }
+}
+
+/// Dispatch actions for the key events in egui's [`InputState`]. This function should be called in each frame.
+/// `on_action` is called with each dispatched action.
+///
+/// The key events which dispatched actions or are in the middle of matching key sequences are removed from the events
+/// of the input state so that widgets don't handle them twice. The `Event::Text` events following the removed key
+/// events are also removed not to insert the typed characters into text edits.
+///
+/// ```
+/// use keybinds::egui::dispatch_input;
+/// use keybinds::Keybinds;
+/// use egui::{Event, InputState, Key, Modifiers};
+///
+/// let mut keybinds = Keybinds::default();
+/// keybinds.bind("Ctrl+s", "save").unwrap();
+///
+/// let mut input = InputState::default();
+/// input.events.push(Event::Key {
+///     key: Key::S,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::CTRL,
+/// });
+///
+/// let mut dispatched = vec![];
+/// dispatch_input(&mut keybinds, &mut input, |action| dispatched.push(*action));
+///
+/// assert_eq!(dispatched, ["save"]);
+/// // The key event was consumed
+/// assert!(input.events.is_empty());
+/// ```
+pub fn dispatch_input<A, F>(keybinds: &mut Keybinds<A>, input: &mut InputState, mut on_action: F)
+where
+    F: FnMut(&A),
+{
+    let mut consumed = false;
+    input.events.retain(|event| match event {
+        Event::Key { pressed: true, .. } => {
+            consumed = match keybinds.dispatch(event) {
+                Some(action) => {
+                    on_action(action);
+                    true
+                }
+                None => keybinds.is_ongoing(),
+            };
+            !consumed
+        }
+        Event::Text(_) if consumed => {
+            consumed = false;
+            false
+        }
+        _ => true,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeySeq, Match, Platform};
+
+    fn key_event(key: EguiKey, modifiers: Modifiers) -> Event {
+        Event::Key {
+            key,
+            physical_key: Some(key),
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn convert_key() {
+        assert_eq!(Key::from(EguiKey::Escape), Key::Esc);
+        assert_eq!(Key::from(EguiKey::Space), Key::Char(' '));
+        assert_eq!(Key::from(EguiKey::Num5), Key::Char('5'));
+        assert_eq!(Key::from(EguiKey::Backslash), Key::Char('\\'));
+        assert_eq!(Key::from(EguiKey::F35), Key::F35);
+        assert_eq!(Key::from(&EguiKey::Z), Key::Char('z'));
+        assert_eq!(physical_code(EguiKey::Equals), Some(Code::Equal));
+        assert_eq!(physical_code(EguiKey::Num0), Some(Code::Digit0));
+        assert_eq!(physical_code(EguiKey::Pipe), None);
+        assert_eq!(physical_code(EguiKey::F25), None);
+    }
+
+    #[test]
+    fn convert_event() {
+        let input = KeyInput::from(key_event(EguiKey::Enter, Modifiers::SHIFT));
+        assert_eq!(
+            input,
+            KeyInput::with_code(Key::Enter, Mods::SHIFT, Code::Enter)
+        );
+
+        let input = KeyInput::from(key_event(EguiKey::S, Modifiers::CTRL | Modifiers::COMMAND));
+        assert_eq!(input, KeyInput::with_code('s', Mods::MOD, Code::KeyS));
+        for seq in ["Ctrl+s", "Mod+s", "Ctrl+[KeyS]"] {
+            let seq: KeySeq = seq.parse().unwrap();
+            assert_eq!(seq.match_to_on(&[input], Platform::Linux), Match::Matched);
+        }
+
+        let input = KeyInput::from(key_event(
+            EguiKey::S,
+            Modifiers::MAC_CMD | Modifiers::COMMAND,
+        ));
+        assert_eq!(input, KeyInput::with_code('s', Mods::MOD, Code::KeyS));
+        for seq in ["Cmd+s", "Mod+s", "Cmd+[KeyS]"] {
+            let seq: KeySeq = seq.parse().unwrap();
+            assert_eq!(seq.match_to_on(&[input], Platform::MacOS), Match::Matched);
+        }
+
+        let input = KeyInput::from(Event::Key {
+            key: EguiKey::Colon,
+            physical_key: None,
+            pressed: true,
+            repeat: true,
+            modifiers: Modifiers::SHIFT,
+        });
+        assert_eq!(input, KeyInput::new(':', Mods::NONE));
+
+        let released = Event::Key {
+            key: EguiKey::A,
+            physical_key: None,
+            pressed: false,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        };
+        assert_eq!(KeyInput::from(released), KeyInput::from(Key::Ignored));
+        assert_eq!(KeyInput::from(Event::Copy), KeyInput::from(Key::Ignored));
+    }
+
+    #[test]
+    fn dispatch_and_consume() {
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("g g", 1).unwrap();
+        keybinds.bind("Ctrl+X", 2).unwrap();
+
+        let mut input = InputState::default();
+        input.events = vec![
+            key_event(EguiKey::A, Modifiers::NONE),
+            Event::Text("a".into()),
+            key_event(EguiKey::G, Modifiers::NONE),
+            Event::Text("g".into()),
+            key_event(EguiKey::G, Modifiers::NONE),
+            Event::Text("g".into()),
+            Event::Copy,
+            key_event(EguiKey::X, Modifiers::CTRL | Modifiers::SHIFT),
+        ];
+
+        let mut dispatched = vec![];
+        dispatch_input(&mut keybinds, &mut input, |a| dispatched.push(*a));
+
+        assert_eq!(dispatched, [1, 2]);
+        assert_eq!(
+            input.events,
+            [
+                key_event(EguiKey::A, Modifiers::NONE),
+                Event::Text("a".into()),
+                Event::Copy,
+            ],
+        );
+        assert!(!keybinds.is_ongoing());
+    }
+}
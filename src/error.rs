@@ -1,4 +1,4 @@
-use crate::Key;
+use crate::{Key, KeyInput};
 use std::error;
 use std::fmt;
 
@@ -16,6 +16,7 @@ use std::fmt;
 /// let error = "Foo+a".parse::<KeySeq>().unwrap_err();
 /// assert_eq!(error, Error::UnknownModifier("Foo".into()));
 /// ```
+#[non_exhaustive]
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum Error {
     /// Error raised when parsing an unknown key like `"Fooo"`.
@@ -31,8 +32,24 @@ pub enum Error {
     /// Error raised when `Shift` modifier key is not allowed with the key.
     ///
     /// `Shift` modifier is only available with named keys so key inputs such as `Shift+x` are not allowed. Please read
-    /// the top level document of this crate for more details.
+    /// the top level document of this crate for more details. To accept `Shift` modifier with character keys, parse
+    /// the key sequence with a keyboard layout. See [`Layout`][crate::Layout] for more details.
     ShiftUnavailable(Key),
+    /// Error raised when the key input cannot be encoded into a terminal escape sequence such as a mouse button or
+    /// a key which has no escape sequence. See [`TerminalEvent::encode_legacy`][crate::TerminalEvent::encode_legacy]
+    /// and [`TerminalEvent::encode_kitty`][crate::TerminalEvent::encode_kitty].
+    UnencodableInput(KeyInput),
+    /// Error raised when the key input cannot be converted into the key event of a framework such as a mouse button or
+    /// a key or a modifier which the framework does not support. This is returned by the `TryFrom<KeyInput>`
+    /// implementations of the framework's key event types such as crossterm's `KeyEvent`, which are useful to
+    /// synthesize key events for UI tests or macro playback.
+    ///
+    /// These conversions resolve the virtual modifiers [`Mods::MOD`][crate::Mods::MOD] and
+    /// [`Mods::SUPER`][crate::Mods::SUPER] for the platform this program is compiled for. The `to_key_event` function
+    /// of each framework module resolves them for another platform. The side of modifiers such as
+    /// [`Mods::LCTRL`][crate::Mods::LCTRL] and the physical key ([`KeyInput::code`]) are dropped without this error
+    /// when the framework's key event cannot hold them.
+    UnconvertibleInput(KeyInput),
 }
 
 impl fmt::Display for Error {
@@ -57,6 +74,18 @@ impl fmt::Display for Error {
             Self::ShiftUnavailable(key) => {
                 write!(f, "Shift modifier is only available with named keys and key \"{key}\" is not a named key")
             }
+            Self::UnencodableInput(input) => {
+                write!(
+                    f,
+                    "Key input \"{input}\" cannot be encoded into terminal escape sequence"
+                )
+            }
+            Self::UnconvertibleInput(input) => {
+                write!(
+                    f,
+                    "Key input \"{input}\" cannot be converted into the key event of the framework"
+                )
+            }
         }
     }
 }
@@ -68,7 +97,8 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 #[cfg(test)]
 mod tests {
-    use crate::{Key, KeySeq};
+    use super::*;
+    use crate::{KeySeq, Mods};
 
     #[test]
     fn error_message() {
@@ -90,5 +120,15 @@ mod tests {
             format!("{error}"),
             r#"Shift modifier is only available with named keys and key "a" is not a named key"#,
         );
+        let error = Error::UnencodableInput(KeyInput::new(Key::MouseLeft, Mods::CTRL));
+        assert_eq!(
+            format!("{error}"),
+            r#"Key input "Ctrl+MouseLeft" cannot be encoded into terminal escape sequence"#,
+        );
+        let error = Error::UnconvertibleInput(KeyInput::new(Key::MouseLeft, Mods::CTRL));
+        assert_eq!(
+            format!("{error}"),
+            r#"Key input "Ctrl+MouseLeft" cannot be converted into the key event of the framework"#,
+        );
     }
 }
@@ -0,0 +1,157 @@
+use crate::{Key, KeyInput, Mods};
+
+/// Policy to fall back from a non-Latin logical key to the Latin key at the same physical key position.
+///
+/// With a non-Latin keyboard layout such as Russian or Greek, pressing <kbd>Ctrl</kbd> + <kbd>C</kbd> produces the
+/// logical key input `Ctrl+с` (Cyrillic) so key bindings like `Ctrl+c` never match. Following the behavior of web
+/// browsers and VS Code, the converters of GUI frameworks (e.g. [`WinitEventConverter`] and iced's) replace the
+/// non-Latin character with the character typed by the physical key on the US QWERTY layout. See
+/// [`Code::qwerty_char`][crate::Code::qwerty_char].
+///
+/// The fallback only happens when the key input has its physical key (see [`KeyInput::with_code`]) and the logical key
+/// is a non-Latin letter. Characters typed by Latin keyboard layouts like `é` or `ß` are never replaced.
+///
+/// ```
+/// use keybinds::{LatinFallback, KeyInput, Code, Mods};
+///
+/// let ctrl_c = KeyInput::with_code('с', Mods::CTRL, Code::KeyC); // Cyrillic 'с'
+/// assert_eq!(LatinFallback::WithModifiers.apply(ctrl_c), KeyInput::with_code('c', Mods::CTRL, Code::KeyC));
+/// assert_eq!(LatinFallback::Never.apply(ctrl_c), ctrl_c);
+///
+/// // Typing text without modifiers is not affected by default
+/// let c = KeyInput::with_code('с', Mods::NONE, Code::KeyC);
+/// assert_eq!(LatinFallback::WithModifiers.apply(c), c);
+/// assert_eq!(LatinFallback::Always.apply(c), KeyInput::with_code('c', Mods::NONE, Code::KeyC));
+/// ```
+///
+/// This enum is non-exhaustive because more policies may be added in the future.
+///
+/// [`WinitEventConverter`]: https://docs.rs/keybinds/latest/keybinds/winit/struct.WinitEventConverter.html
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum LatinFallback {
+    /// Never fall back. The logical key is used as-is.
+    Never,
+    /// Fall back only when some modifiers except for <kbd>Shift</kbd> and <kbd>AltGr</kbd> are pressed. The states
+    /// like [`Mods::CAPS_LOCK`] and [`Mods::NUMPAD`] are not regarded as modifiers. This is the default policy.
+    #[default]
+    WithModifiers,
+    /// Always fall back even if no modifier is pressed.
+    Always,
+}
+
+impl LatinFallback {
+    /// Apply the fallback policy to the key input. When the fallback happens, the logical key of the input is
+    /// replaced with the Latin character. The case of the character follows the original logical key.
+    ///
+    /// ```
+    /// use keybinds::{LatinFallback, KeyInput, Code, Mods};
+    ///
+    /// // Ctrl+Shift+Σ on Greek layout
+    /// let input = KeyInput::with_code('Σ', Mods::CTRL | Mods::SHIFT, Code::KeyS);
+    /// let input = LatinFallback::default().apply(input);
+    /// assert_eq!(input, KeyInput::with_code('S', Mods::CTRL | Mods::SHIFT, Code::KeyS));
+    /// ```
+    pub fn apply(self, input: KeyInput) -> KeyInput {
+        let (Key::Char(c), Some(code)) = (input.key(), input.code()) else {
+            return input;
+        };
+
+        let enabled = match self {
+            Self::Never => false,
+            Self::WithModifiers => {
+                !(input.mods() - Mods::ANY_SHIFT - Mods::ALTGR - Mods::STATES).is_empty()
+            }
+            Self::Always => true,
+        };
+        if !enabled || !c.is_alphabetic() || is_latin(c) {
+            return input;
+        }
+
+        match code.qwerty_char() {
+            Some(latin) if c.is_uppercase() => {
+                KeyInput::with_code(latin.to_ascii_uppercase(), input.physical_mods(), code)
+            }
+            Some(latin) => KeyInput::with_code(latin, input.physical_mods(), code),
+            None => input,
+        }
+    }
+}
+
+fn is_latin(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0000}'..='\u{024F}' // Basic Latin, Latin-1 Supplement, Latin Extended-A and B
+            | '\u{1E00}'..='\u{1EFF}' // Latin Extended Additional
+            | '\u{2C60}'..='\u{2C7F}' // Latin Extended-C
+            | '\u{A720}'..='\u{A7FF}' // Latin Extended-D
+            | '\u{AB30}'..='\u{AB6F}' // Latin Extended-E
+            | '\u{FF21}'..='\u{FF3A}' // Fullwidth Latin capital letters
+            | '\u{FF41}'..='\u{FF5A}' // Fullwidth Latin small letters
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Code;
+
+    #[test]
+    fn fallback_to_latin_key() {
+        let tests = [
+            // Russian
+            (KeyInput::with_code('с', Mods::CTRL, Code::KeyC), Some('c')),
+            (KeyInput::with_code('ф', Mods::ALT, Code::KeyA), Some('a')),
+            (
+                KeyInput::with_code('Я', Mods::CMD | Mods::SHIFT, Code::KeyZ),
+                Some('Z'),
+            ),
+            // Greek
+            (KeyInput::with_code('ψ', Mods::CTRL, Code::KeyC), Some('c')),
+            // Hebrew
+            (KeyInput::with_code('ב', Mods::CTRL, Code::KeyC), Some('c')),
+            // Latin characters are not replaced
+            (KeyInput::with_code('z', Mods::CTRL, Code::KeyW), None),
+            (KeyInput::with_code('é', Mods::CTRL, Code::Digit2), None),
+            (KeyInput::with_code('ß', Mods::CTRL, Code::Minus), None),
+            // Non-letters are not replaced
+            (KeyInput::with_code('。', Mods::CTRL, Code::Period), None),
+            // The physical key types no character on US QWERTY layout
+            (KeyInput::with_code('ё', Mods::CTRL, Code::IntlYen), None),
+            // No modifiers except for Shift
+            (KeyInput::with_code('с', Mods::NONE, Code::KeyC), None),
+            (KeyInput::with_code('С', Mods::SHIFT, Code::KeyC), None),
+            (KeyInput::with_code('С', Mods::CAPS_LOCK, Code::KeyC), None),
+            (KeyInput::with_code('ђ', Mods::ALTGR, Code::KeyC), None),
+            (
+                KeyInput::with_code('с', Mods::CTRL | Mods::NUM_LOCK, Code::KeyC),
+                Some('c'),
+            ),
+            // No physical key
+            (KeyInput::new('с', Mods::CTRL), None),
+            (KeyInput::new(Key::Enter, Mods::CTRL), None),
+        ];
+
+        for (input, expected) in tests {
+            let expected = match (expected, input.code()) {
+                (Some(c), Some(code)) => KeyInput::with_code(c, input.physical_mods(), code),
+                _ => input,
+            };
+            assert_eq!(
+                LatinFallback::WithModifiers.apply(input),
+                expected,
+                "input={input:?}",
+            );
+            assert_eq!(LatinFallback::Never.apply(input), input, "input={input:?}");
+        }
+    }
+
+    #[test]
+    fn always_fallback_to_latin_key() {
+        let input = KeyInput::with_code('с', Mods::NONE, Code::KeyC);
+        let expected = KeyInput::with_code('c', Mods::NONE, Code::KeyC);
+        assert_eq!(LatinFallback::Always.apply(input), expected);
+        assert_eq!(LatinFallback::WithModifiers.apply(input), input);
+        assert_eq!(LatinFallback::default(), LatinFallback::WithModifiers);
+    }
+}
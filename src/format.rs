@@ -0,0 +1,685 @@
+use crate::{Key, KeyInput, KeySeq, Locale, Mods, Platform};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// Style of the human-readable representation generated by [`KeySeqFormatter`].
+///
+/// This enum is non-exhaustive because more styles may be added in the future.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FormatStyle {
+    /// ASCII representation compatible with the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md)
+    /// like "Ctrl+Alt+x". The names are always in English regardless of the locale so that the output can be parsed
+    /// again.
+    Ascii,
+    /// Symbols used in macOS menus like "⌃⌥X" or "⇧⌘Z".
+    MacSymbols,
+    /// Style used in Windows menus like "Ctrl+Alt+X" or "Ctrl+Shift+Z".
+    Windows,
+    /// Short style used in Emacs like "C-M-x".
+    Short,
+}
+
+impl FormatStyle {
+    /// Return the style natively used on the platform.
+    ///
+    /// ```
+    /// use keybinds::{FormatStyle, Platform};
+    ///
+    /// assert_eq!(FormatStyle::native(Platform::MacOS), FormatStyle::MacSymbols);
+    /// assert_eq!(FormatStyle::native(Platform::Windows), FormatStyle::Windows);
+    /// assert_eq!(FormatStyle::native(Platform::Linux), FormatStyle::Ascii);
+    /// ```
+    pub fn native(platform: Platform) -> Self {
+        match platform {
+            Platform::MacOS => Self::MacSymbols,
+            Platform::Windows => Self::Windows,
+            _ => Self::Ascii,
+        }
+    }
+
+    fn mods_order(self) -> [Mods; 11] {
+        let [a, b, c, d, e] = match self {
+            Self::Ascii => [Mods::CTRL, Mods::CMD, Mods::ALT, Mods::WIN, Mods::SHIFT],
+            Self::MacSymbols => [Mods::CTRL, Mods::ALT, Mods::SHIFT, Mods::CMD, Mods::WIN],
+            Self::Windows => [Mods::WIN, Mods::CTRL, Mods::ALT, Mods::SHIFT, Mods::CMD],
+            Self::Short => [Mods::CTRL, Mods::ALT, Mods::SHIFT, Mods::CMD, Mods::WIN],
+        };
+        // Less common modifiers follow the common ones in all styles
+        [
+            a,
+            b,
+            c,
+            d,
+            e,
+            Mods::ALTGR,
+            Mods::META,
+            Mods::HYPER,
+            Mods::CAPS_LOCK,
+            Mods::NUM_LOCK,
+            Mods::NUMPAD,
+        ]
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Self::Ascii | Self::Windows => "+",
+            Self::MacSymbols => "",
+            Self::Short => "-",
+        }
+    }
+
+    fn sequence_separator(self) -> &'static str {
+        match self {
+            Self::Windows => ", ",
+            _ => " ",
+        }
+    }
+}
+
+/// Formatter to generate human-readable representations of key sequences for menus, cheat sheets, and so on.
+///
+/// While [`std::fmt::Display`] implementations of [`KeySeq`] and [`KeyInput`] generate the representation following
+/// the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md), this formatter generates
+/// the representation in the [`FormatStyle`] familiar to users of the [`Platform`]. The platform is a runtime
+/// parameter so a program running on Linux can render key bindings for macOS.
+///
+/// ```
+/// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeySeq};
+///
+/// let seq: KeySeq = "Ctrl+Alt+x".parse().unwrap();
+///
+/// let ascii = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+/// assert_eq!(ascii.format(&seq), "Ctrl+Alt+x");
+///
+/// let mac = KeySeqFormatter::new(FormatStyle::MacSymbols, Platform::MacOS);
+/// assert_eq!(mac.format(&seq), "⌃⌥X");
+///
+/// let windows = KeySeqFormatter::new(FormatStyle::Windows, Platform::Windows);
+/// assert_eq!(windows.format(&seq), "Ctrl+Alt+X");
+///
+/// let short = KeySeqFormatter::new(FormatStyle::Short, Platform::Linux);
+/// assert_eq!(short.format(&seq), "C-M-x");
+/// ```
+///
+/// The virtual modifiers `Mod` and `Super` are resolved for the platform.
+///
+/// ```
+/// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeySeq};
+///
+/// let seq: KeySeq = "Mod+s".parse().unwrap();
+///
+/// let mac = KeySeqFormatter::new(FormatStyle::MacSymbols, Platform::MacOS);
+/// assert_eq!(mac.format(&seq), "⌘S");
+///
+/// let windows = KeySeqFormatter::new(FormatStyle::Windows, Platform::Windows);
+/// assert_eq!(windows.format(&seq), "Ctrl+S");
+/// ```
+///
+/// The order of modifiers, the separator, and the names of keys can be customized.
+///
+/// ```
+/// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeySeq, Key, Mods};
+///
+/// let mut formatter = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+/// formatter.set_mods_order(&[Mods::SHIFT, Mods::ALT, Mods::CTRL]);
+/// formatter.set_separator(" + ");
+/// formatter.set_key_name(Key::Enter, "Return");
+///
+/// let seq: KeySeq = "Ctrl+Shift+Enter".parse().unwrap();
+/// assert_eq!(formatter.format(&seq), "Shift + Ctrl + Return");
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KeySeqFormatter {
+    style: FormatStyle,
+    platform: Platform,
+    mods_order: Vec<Mods>,
+    separator: Box<str>,
+    sequence_separator: Box<str>,
+    locale: Locale,
+    key_names: HashMap<Key, Box<str>>,
+    mod_names: HashMap<Mods, Box<str>>,
+}
+
+impl Default for KeySeqFormatter {
+    /// Create a formatter with the native style for the current platform.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform};
+    ///
+    /// let formatter = KeySeqFormatter::default();
+    /// assert_eq!(formatter.platform(), Platform::current());
+    /// assert_eq!(formatter.style(), FormatStyle::native(Platform::current()));
+    /// ```
+    fn default() -> Self {
+        Self::native(Platform::current())
+    }
+}
+
+impl KeySeqFormatter {
+    /// Create a new formatter with the style and the platform.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeyInput, Mods};
+    ///
+    /// let formatter = KeySeqFormatter::new(FormatStyle::MacSymbols, Platform::MacOS);
+    ///
+    /// assert_eq!(formatter.format_input(KeyInput::new('z', Mods::CMD)), "⌘Z");
+    /// ```
+    pub fn new(style: FormatStyle, platform: Platform) -> Self {
+        Self {
+            style,
+            platform,
+            mods_order: style.mods_order().to_vec(),
+            separator: style.separator().into(),
+            sequence_separator: style.sequence_separator().into(),
+            locale: Locale::English,
+            key_names: HashMap::new(),
+            mod_names: HashMap::new(),
+        }
+    }
+
+    /// Create a new formatter with the native style for the platform. See [`FormatStyle::native`] for the native
+    /// styles.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform};
+    ///
+    /// let formatter = KeySeqFormatter::native(Platform::Windows);
+    /// assert_eq!(formatter.style(), FormatStyle::Windows);
+    /// ```
+    pub fn native(platform: Platform) -> Self {
+        Self::new(FormatStyle::native(platform), platform)
+    }
+
+    /// Return the style of the formatter.
+    pub fn style(&self) -> FormatStyle {
+        self.style
+    }
+
+    /// Return the platform of the formatter.
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    /// Set the order of modifiers. Modifiers which are not included in the given order are put after the given ones
+    /// in the default order of the style.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeyInput, Mods};
+    ///
+    /// let mut formatter = KeySeqFormatter::new(FormatStyle::MacSymbols, Platform::MacOS);
+    /// let input = KeyInput::new('Z', Mods::CMD);
+    ///
+    /// // The default order is ⌃⌥⇧⌘
+    /// assert_eq!(formatter.format_input(input), "⇧⌘Z");
+    ///
+    /// formatter.set_mods_order(&[Mods::CMD]);
+    /// assert_eq!(formatter.format_input(input), "⌘⇧Z");
+    /// ```
+    pub fn set_mods_order(&mut self, order: &[Mods]) {
+        let mut mods_order: Vec<Mods> = vec![];
+        for m in order.iter().flat_map(|m| m.iter()) {
+            if !mods_order.contains(&m) {
+                mods_order.push(m);
+            }
+        }
+        for m in self.style.mods_order() {
+            if !mods_order.contains(&m) {
+                mods_order.push(m);
+            }
+        }
+        self.mods_order = mods_order;
+    }
+
+    /// Set the separator put between modifiers and a key.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeyInput, Mods};
+    ///
+    /// let mut formatter = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+    /// formatter.set_separator("-");
+    ///
+    /// assert_eq!(formatter.format_input(KeyInput::new('x', Mods::CTRL | Mods::ALT)), "Ctrl-Alt-x");
+    /// ```
+    pub fn set_separator(&mut self, sep: &str) {
+        self.separator = sep.into();
+    }
+
+    /// Set the separator put between key inputs in a key sequence.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeySeq};
+    ///
+    /// let mut formatter = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+    /// formatter.set_sequence_separator(" → ");
+    ///
+    /// let seq: KeySeq = "Ctrl+x Ctrl+s".parse().unwrap();
+    /// assert_eq!(formatter.format(&seq), "Ctrl+x → Ctrl+s");
+    /// ```
+    pub fn set_sequence_separator(&mut self, sep: &str) {
+        self.sequence_separator = sep.into();
+    }
+
+    /// Replace the name of the key. The name takes precedence over the name defined by the style.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeyInput, Key, Mods};
+    ///
+    /// let mut formatter = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+    /// formatter.set_key_name(Key::Esc, "Escape");
+    ///
+    /// assert_eq!(formatter.format_input(KeyInput::new(Key::Esc, Mods::SHIFT)), "Shift+Escape");
+    /// ```
+    pub fn set_key_name(&mut self, key: Key, name: &str) {
+        self.key_names.insert(key, name.into());
+    }
+
+    /// Replace the name of the single modifier key. The name takes precedence over the name defined by the style and
+    /// the locale.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeyInput, Mods};
+    ///
+    /// let mut formatter = KeySeqFormatter::new(FormatStyle::Ascii, Platform::MacOS);
+    /// formatter.set_mod_name(Mods::ALT, "Option");
+    ///
+    /// assert_eq!(formatter.format_input(KeyInput::new('x', Mods::ALT)), "Option+x");
+    /// ```
+    pub fn set_mod_name(&mut self, m: Mods, name: &str) {
+        self.mod_names.insert(m, name.into());
+    }
+
+    /// Set the [`Locale`] of the names of keys and modifiers. The default locale is English.
+    ///
+    /// The localized names are used in the [`FormatStyle::Windows`] style. The [`FormatStyle::MacSymbols`] style uses
+    /// them only for keys which have no symbol. The [`FormatStyle::Ascii`] and [`FormatStyle::Short`] styles do not use
+    /// them so that the [`FormatStyle::Ascii`] output can always be parsed again. Names set by [`KeySeqFormatter::set_key_name`] and [`KeySeqFormatter::set_mod_name`]
+    /// take precedence over the localized names.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, Locale, KeyInput, Key, Mods};
+    ///
+    /// let mut formatter = KeySeqFormatter::new(FormatStyle::Windows, Platform::Windows);
+    /// formatter.set_locale(Locale::German);
+    ///
+    /// assert_eq!(formatter.format_input(KeyInput::new(Key::Delete, Mods::CTRL)), "Strg+Entf");
+    /// ```
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    /// Return the locale of the formatter.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Generate the representation of the key sequence.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeySeq};
+    ///
+    /// let formatter = KeySeqFormatter::new(FormatStyle::Windows, Platform::Windows);
+    /// let seq: KeySeq = "Ctrl+k Ctrl+c".parse().unwrap();
+    ///
+    /// assert_eq!(formatter.format(&seq), "Ctrl+K, Ctrl+C");
+    /// ```
+    pub fn format(&self, seq: &KeySeq) -> String {
+        let mut out = String::new();
+        for (idx, input) in seq.as_slice().iter().enumerate() {
+            if idx > 0 {
+                out.push_str(&self.sequence_separator);
+            }
+            self.write_input(&mut out, *input);
+        }
+        out
+    }
+
+    /// Generate the representation of the single key input.
+    ///
+    /// ```
+    /// use keybinds::{KeySeqFormatter, FormatStyle, Platform, KeyInput, Key, Mods};
+    ///
+    /// let formatter = KeySeqFormatter::new(FormatStyle::Short, Platform::Linux);
+    ///
+    /// assert_eq!(formatter.format_input(KeyInput::new(Key::Enter, Mods::CTRL)), "C-RET");
+    /// ```
+    pub fn format_input(&self, input: KeyInput) -> String {
+        let mut out = String::new();
+        self.write_input(&mut out, input);
+        out
+    }
+
+    fn write_input(&self, out: &mut String, input: KeyInput) {
+        let key = input.key();
+        let mut mods = input.mods().resolve(self.platform);
+        let name = match self.key_names.get(&key) {
+            Some(name) => Cow::Borrowed(name.as_ref()),
+            None => self.key_name(key, &mut mods),
+        };
+        for &m in self.mods_order.iter() {
+            if mods.contains(m) {
+                out.push_str(self.mod_name(m));
+                out.push_str(&self.separator);
+            }
+        }
+        out.push_str(&name);
+    }
+
+    fn mod_name(&self, m: Mods) -> &str {
+        if let Some(name) = self.mod_names.get(&m) {
+            return name;
+        }
+        match self.style {
+            FormatStyle::MacSymbols => match m {
+                Mods::CTRL => "⌃",
+                Mods::CMD => "⌘",
+                Mods::ALT => "⌥",
+                Mods::WIN => "❖",
+                Mods::CAPS_LOCK => "⇪",
+                Mods::NUM_LOCK => "⇭",
+                Mods::SHIFT => "⇧",
+                _ => Self::other_mod_name(m),
+            },
+            FormatStyle::Short => match m {
+                Mods::CTRL => "C",
+                Mods::CMD | Mods::WIN => "s",
+                Mods::ALT | Mods::META => "M",
+                Mods::HYPER => "H",
+                Mods::SHIFT => "S",
+                _ => Self::other_mod_name(m),
+            },
+            FormatStyle::Ascii | FormatStyle::Windows => {
+                if self.style == FormatStyle::Windows {
+                    if let Some(name) = self.locale.mod_name(m) {
+                        return name;
+                    }
+                }
+                match m {
+                    Mods::CTRL => "Ctrl",
+                    Mods::CMD => "Cmd",
+                    Mods::ALT => "Alt",
+                    Mods::WIN
+                        if self.style == FormatStyle::Ascii
+                            && matches!(self.platform, Platform::Linux | Platform::Other) =>
+                    {
+                        "Super"
+                    }
+                    Mods::WIN => "Win",
+                    Mods::SHIFT => "Shift",
+                    _ => Self::other_mod_name(m),
+                }
+            }
+        }
+    }
+
+    // Names of the modifiers which have no style-specific names
+    fn other_mod_name(m: Mods) -> &'static str {
+        match m {
+            Mods::ALTGR => "AltGr",
+            Mods::META => "Meta",
+            Mods::HYPER => "Hyper",
+            Mods::CAPS_LOCK => "CapsLock",
+            Mods::NUM_LOCK => "NumLock",
+            _ => "Num",
+        }
+    }
+
+    // Menus on macOS and Windows show alphabets in upper case. Upper case alphabets imply the Shift modifier.
+    fn key_name(&self, key: Key, mods: &mut Mods) -> Cow<'static, str> {
+        match self.style {
+            FormatStyle::Ascii => key.to_string().into(),
+            FormatStyle::MacSymbols => match key {
+                Key::Char(c) if c.is_ascii_alphabetic() => {
+                    if c.is_ascii_uppercase() {
+                        *mods |= Mods::SHIFT;
+                    }
+                    c.to_ascii_uppercase().to_string().into()
+                }
+                Key::Char('+') => "+".into(),
+                Key::Up => "↑".into(),
+                Key::Right => "→".into(),
+                Key::Down => "↓".into(),
+                Key::Left => "←".into(),
+                Key::Enter => "↩".into(),
+                Key::Backspace => "⌫".into(),
+                Key::Delete => "⌦".into(),
+                Key::Home => "↖".into(),
+                Key::End => "↘".into(),
+                Key::PageUp => "⇞".into(),
+                Key::PageDown => "⇟".into(),
+                Key::Esc => "⎋".into(),
+                Key::Tab => "⇥".into(),
+                Key::Clear => "⌧".into(),
+                _ => self.localized_key_name(key),
+            },
+            FormatStyle::Windows => match key {
+                Key::Char(c) if c.is_ascii_alphabetic() => {
+                    if c.is_ascii_uppercase() {
+                        *mods |= Mods::SHIFT;
+                    }
+                    c.to_ascii_uppercase().to_string().into()
+                }
+                _ if self.locale.key_name(key).is_some() => self.localized_key_name(key),
+                Key::Delete => "Del".into(),
+                Key::Insert => "Ins".into(),
+                Key::PageUp => "PgUp".into(),
+                Key::PageDown => "PgDn".into(),
+                Key::PrintScreen => "PrtSc".into(),
+                _ => key.to_string().into(),
+            },
+            FormatStyle::Short => match key {
+                Key::Char(' ') => "SPC".into(),
+                Key::Char(c) => c.to_string().into(),
+                Key::Enter => "RET".into(),
+                Key::Tab => "TAB".into(),
+                Key::Esc => "ESC".into(),
+                Key::Backspace => "DEL".into(),
+                Key::Physical(_) => key.to_string().into(),
+                _ => format!("<{}>", key.to_string().to_ascii_lowercase()).into(),
+            },
+        }
+    }
+
+    fn localized_key_name(&self, key: Key) -> Cow<'static, str> {
+        match self.locale.key_name(key) {
+            Some(name) => name.into(),
+            None => key.to_string().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seq(s: &str) -> KeySeq {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn format_ascii() {
+        let f = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Windows);
+        for (input, expected) in [
+            ("a", "a"),
+            ("A", "A"),
+            ("Ctrl+Alt+x", "Ctrl+Alt+x"),
+            ("Shift+Enter", "Shift+Enter"),
+            ("Ctrl+x Ctrl+s", "Ctrl+x Ctrl+s"),
+            ("Space Plus", "Space Plus"),
+        ] {
+            assert_eq!(f.format(&seq(input)), expected, "input={input:?}");
+        }
+
+        let seq = KeySeq::from(KeyInput::new('x', Mods::WIN));
+        assert_eq!(f.format(&seq), "Win+x");
+        let f = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+        assert_eq!(f.format(&seq), "Super+x");
+    }
+
+    #[test]
+    fn format_virtual_mods() {
+        let seq = seq("Mod+x Super+y");
+        for (style, platform, expected) in [
+            (FormatStyle::Ascii, Platform::Linux, "Ctrl+x Super+y"),
+            (FormatStyle::Ascii, Platform::MacOS, "Cmd+x Cmd+y"),
+            (FormatStyle::MacSymbols, Platform::MacOS, "⌘X ⌘Y"),
+            (FormatStyle::Windows, Platform::Windows, "Ctrl+X, Win+Y"),
+            (FormatStyle::Short, Platform::Linux, "C-x s-y"),
+        ] {
+            let f = KeySeqFormatter::new(style, platform);
+            assert_eq!(
+                f.format(&seq),
+                expected,
+                "style={style:?} platform={platform:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn format_extra_mods() {
+        let seq = seq("AltGr+Enter Meta+Hyper+x CapsLock+NumLock+Ctrl+y Numpad+Enter");
+        for (style, expected) in [
+            (
+                FormatStyle::Ascii,
+                "AltGr+Enter Meta+Hyper+x Ctrl+CapsLock+NumLock+y Num+Enter",
+            ),
+            (FormatStyle::MacSymbols, "AltGr↩ MetaHyperX ⌃⇪⇭Y Num↩"),
+            (
+                FormatStyle::Windows,
+                "AltGr+Enter, Meta+Hyper+X, Ctrl+CapsLock+NumLock+Y, Num+Enter",
+            ),
+            (
+                FormatStyle::Short,
+                "AltGr-RET M-H-x C-CapsLock-NumLock-y Num-RET",
+            ),
+        ] {
+            let f = KeySeqFormatter::new(style, Platform::Linux);
+            assert_eq!(f.format(&seq), expected, "style={style:?}");
+        }
+    }
+
+    #[test]
+    fn format_mac_symbols() {
+        let f = KeySeqFormatter::new(FormatStyle::MacSymbols, Platform::MacOS);
+        for (input, expected) in [
+            (KeyInput::new('z', Mods::CMD), "⌘Z"),
+            (KeyInput::new('Z', Mods::CMD), "⇧⌘Z"),
+            (KeyInput::new('x', Mods::CTRL | Mods::ALT), "⌃⌥X"),
+            (KeyInput::new('?', Mods::CMD), "⌘?"),
+            (KeyInput::new(Key::Up, Mods::SHIFT | Mods::ALT), "⌥⇧↑"),
+            (KeyInput::new(Key::Backspace, Mods::CMD), "⌘⌫"),
+            (KeyInput::new(Key::Enter, Mods::NONE), "↩"),
+            (KeyInput::new(' ', Mods::CTRL), "⌃Space"),
+            (KeyInput::new(Key::F5, Mods::NONE), "F5"),
+        ] {
+            assert_eq!(f.format_input(input), expected, "input={input:?}");
+        }
+        assert_eq!(f.format(&seq("Cmd+k Cmd+c")), "⌘K ⌘C");
+    }
+
+    #[test]
+    fn format_windows() {
+        let f = KeySeqFormatter::new(FormatStyle::Windows, Platform::Windows);
+        for (input, expected) in [
+            (KeyInput::new('z', Mods::CTRL), "Ctrl+Z"),
+            (KeyInput::new('Z', Mods::CTRL), "Ctrl+Shift+Z"),
+            (
+                KeyInput::new(Key::Delete, Mods::CTRL | Mods::ALT),
+                "Ctrl+Alt+Del",
+            ),
+            (KeyInput::new('E', Mods::WIN), "Win+Shift+E"),
+            (KeyInput::new(Key::PageDown, Mods::NONE), "PgDn"),
+        ] {
+            assert_eq!(f.format_input(input), expected, "input={input:?}");
+        }
+        assert_eq!(f.format(&seq("Ctrl+k Ctrl+c")), "Ctrl+K, Ctrl+C");
+    }
+
+    #[test]
+    fn format_short() {
+        let f = KeySeqFormatter::new(FormatStyle::Short, Platform::Linux);
+        for (input, expected) in [
+            ("Ctrl+x Ctrl+s", "C-x C-s"),
+            ("Ctrl+Alt+x", "C-M-x"),
+            ("Alt+Space", "M-SPC"),
+            ("Shift+Up", "S-<up>"),
+            ("F1 Esc Tab Backspace", "<f1> ESC TAB DEL"),
+            ("Ctrl+Plus", "C-+"),
+            ("Ctrl+[KeyW]", "C-[KeyW]"),
+        ] {
+            assert_eq!(f.format(&seq(input)), expected, "input={input:?}");
+        }
+    }
+
+    #[test]
+    fn customize_formatter() {
+        let mut f = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+        f.set_mods_order(&[Mods::SHIFT | Mods::ALT]);
+        f.set_separator("-");
+        f.set_sequence_separator(", ");
+        f.set_key_name(Key::Char('x'), "X");
+        f.set_key_name(Key::Enter, "Return");
+        assert_eq!(
+            f.format(&seq("Ctrl+Alt+x Shift+Ctrl+Enter")),
+            "Alt-Ctrl-X, Shift-Ctrl-Return",
+        );
+    }
+
+    #[test]
+    fn format_localized() {
+        let mut f = KeySeqFormatter::new(FormatStyle::Windows, Platform::Windows);
+        f.set_locale(Locale::German);
+        assert_eq!(f.locale(), Locale::German);
+        for (input, expected) in [
+            (KeyInput::new(Key::Delete, Mods::CTRL), "Strg+Entf"),
+            (KeyInput::new('Z', Mods::CTRL), "Strg+Umschalt+Z"),
+            (KeyInput::new(Key::F4, Mods::ALT), "Alt+F4"),
+            (KeyInput::new(' ', Mods::CTRL), "Strg+Leertaste"),
+        ] {
+            assert_eq!(f.format_input(input), expected, "input={input:?}");
+        }
+
+        let mut f = KeySeqFormatter::new(FormatStyle::Windows, Platform::Linux);
+        f.set_locale(Locale::French);
+        assert_eq!(f.format(&seq("Shift+Esc Ctrl+x")), "Maj+Échap, Ctrl+X");
+
+        // The ASCII style is not localized so that it can be parsed again
+        let mut f = KeySeqFormatter::new(FormatStyle::Ascii, Platform::Linux);
+        f.set_locale(Locale::German);
+        let formatted = f.format(&seq("Shift+Esc Ctrl+Delete"));
+        assert_eq!(formatted, "Shift+Esc Ctrl+Delete");
+        assert_eq!(seq(&formatted), seq("Shift+Esc Ctrl+Delete"));
+
+        let mut f = KeySeqFormatter::new(FormatStyle::MacSymbols, Platform::MacOS);
+        f.set_locale(Locale::Japanese);
+        assert_eq!(f.format(&seq("Cmd+Space Cmd+Up")), "⌘スペース ⌘↑");
+
+        let mut f = KeySeqFormatter::new(FormatStyle::Short, Platform::Linux);
+        f.set_locale(Locale::German);
+        assert_eq!(f.format(&seq("Ctrl+Delete")), "C-<delete>");
+    }
+
+    #[test]
+    fn custom_names_precede_locale() {
+        let mut f = KeySeqFormatter::new(FormatStyle::Windows, Platform::Linux);
+        f.set_locale(Locale::German);
+        f.set_key_name(Key::Delete, "Löschen");
+        f.set_mod_name(Mods::CTRL, "Steuerung");
+        assert_eq!(f.format(&seq("Ctrl+Delete")), "Steuerung+Löschen");
+    }
+
+    #[test]
+    fn native_formatter() {
+        for (platform, style) in [
+            (Platform::MacOS, FormatStyle::MacSymbols),
+            (Platform::Windows, FormatStyle::Windows),
+            (Platform::Linux, FormatStyle::Ascii),
+            (Platform::Other, FormatStyle::Ascii),
+        ] {
+            let f = KeySeqFormatter::native(platform);
+            assert_eq!(f.style(), style);
+            assert_eq!(f.platform(), platform);
+        }
+    }
+}
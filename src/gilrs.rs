@@ -0,0 +1,580 @@
+//! Support for [`gilrs`] crate.
+//!
+//! This module provides [`GamepadInput`] to bind gamepad buttons and D-pad directions to actions, and the conversions
+//! from gilrs's event types to it. Since [`GamepadInput`] implements [`Input`],
+//! [`GenericKeybinds`][crate::GenericKeybinds] can dispatch actions for combo sequences such as
+//! `Down DownRight Right A` in the same way as key sequences.
+//!
+//! ```no_run
+//! use gilrs::Gilrs;
+//! use keybinds::gilrs::{GamepadInput, GilrsEventConverter};
+//! use keybinds::GenericKeybinds;
+//! use std::collections::HashMap;
+//!
+//! // Actions dispatched by gamepad bindings
+//! enum Action {
+//!     Hadouken,
+//!     Pause,
+//! }
+//!
+//! // Create a dispatcher to dispatch actions for upcoming gamepad inputs
+//! let mut keybinds = GenericKeybinds::<Action, GamepadInput>::new(vec![]);
+//!
+//! // Bindings to dispatch the actions
+//! keybinds.bind("Down DownRight Right A", Action::Hadouken).unwrap();
+//! keybinds.bind("Start", Action::Pause).unwrap();
+//!
+//! let mut gilrs = Gilrs::new().unwrap();
+//!
+//! // One converter per gamepad to track the D-pad state of each gamepad
+//! let mut converters = HashMap::new();
+//!
+//! loop {
+//!     while let Some(event) = gilrs.next_event() {
+//!         let converter = converters
+//!             .entry(event.id)
+//!             .or_insert_with(GilrsEventConverter::default);
+//!
+//!         // Convert gilrs's `EventType` into `GamepadInput` and dispatch the action
+//!         let input = converter.convert(&event.event);
+//!         if let Some(action) = keybinds.dispatch(input) {
+//!             match action {
+//!                 Action::Hadouken => println!("Hadouken!"),
+//!                 Action::Pause => return,
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+use crate::{Error, Input};
+use gilrs::{Button, Event, EventType};
+use std::fmt;
+use std::str::FromStr;
+
+/// A gamepad input which can be bound to actions. Buttons are named after their positions like gilrs's [`Button`].
+///
+/// The D-pad directions include the diagonal directions such as [`GamepadInput::DownRight`] for combo sequences. The
+/// diagonal directions are generated by [`GilrsEventConverter`] from the D-pad buttons being held at the same time.
+///
+/// In addition to the names of the variants, the following aliases are accepted by the parser.
+///
+/// | Input                           | Aliases                 |
+/// |---------------------------------|-------------------------|
+/// | [`GamepadInput::South`]         | `A`                     |
+/// | [`GamepadInput::East`]          | `B`                     |
+/// | [`GamepadInput::West`]          | `X`                     |
+/// | [`GamepadInput::North`]         | `Y`                     |
+/// | [`GamepadInput::LeftTrigger`]   | `LB`, `L1`              |
+/// | [`GamepadInput::LeftTrigger2`]  | `LT`, `L2`              |
+/// | [`GamepadInput::RightTrigger`]  | `RB`, `R1`              |
+/// | [`GamepadInput::RightTrigger2`] | `RT`, `R2`              |
+/// | [`GamepadInput::LeftThumb`]     | `LS`, `L3`              |
+/// | [`GamepadInput::RightThumb`]    | `RS`, `R3`              |
+/// | [`GamepadInput::Select`]        | `Back`                  |
+/// | [`GamepadInput::Mode`]          | `Guide`                 |
+/// | [`GamepadInput::Up`]            | `DPadUp`                |
+/// | [`GamepadInput::Down`]          | `DPadDown`              |
+/// | [`GamepadInput::Left`]          | `DPadLeft`              |
+/// | [`GamepadInput::Right`]         | `DPadRight`             |
+///
+/// Like keys, the names are also accepted in lower case and upper case.
+///
+/// ```
+/// use keybinds::gilrs::GamepadInput;
+///
+/// assert_eq!("South".parse(), Ok(GamepadInput::South));
+/// assert_eq!("A".parse(), Ok(GamepadInput::South));
+/// assert_eq!("downright".parse(), Ok(GamepadInput::DownRight));
+/// assert_eq!("LB".parse(), Ok(GamepadInput::LeftTrigger));
+///
+/// // Formatted with the canonical names
+/// assert_eq!(GamepadInput::South.to_string(), "South");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum GamepadInput {
+    /// The bottom action button. "A" on Xbox controllers and "Cross" on PlayStation controllers.
+    South,
+    /// The right action button. "B" on Xbox controllers and "Circle" on PlayStation controllers.
+    East,
+    /// The top action button. "Y" on Xbox controllers and "Triangle" on PlayStation controllers.
+    North,
+    /// The left action button. "X" on Xbox controllers and "Square" on PlayStation controllers.
+    West,
+    /// "C" button available on some gamepads.
+    C,
+    /// "Z" button available on some gamepads.
+    Z,
+    /// The left shoulder button. "LB" on Xbox controllers and "L1" on PlayStation controllers.
+    LeftTrigger,
+    /// The left trigger. "LT" on Xbox controllers and "L2" on PlayStation controllers.
+    LeftTrigger2,
+    /// The right shoulder button. "RB" on Xbox controllers and "R1" on PlayStation controllers.
+    RightTrigger,
+    /// The right trigger. "RT" on Xbox controllers and "R2" on PlayStation controllers.
+    RightTrigger2,
+    /// "Select" or "Back" button.
+    Select,
+    /// "Start" button.
+    Start,
+    /// The vendor button such as "Guide" on Xbox controllers and "PS" on PlayStation controllers.
+    Mode,
+    /// Pressing the left stick.
+    LeftThumb,
+    /// Pressing the right stick.
+    RightThumb,
+    /// D-pad up direction.
+    Up,
+    /// D-pad down direction.
+    Down,
+    /// D-pad left direction.
+    Left,
+    /// D-pad right direction.
+    Right,
+    /// D-pad up-left diagonal direction.
+    UpLeft,
+    /// D-pad up-right diagonal direction.
+    UpRight,
+    /// D-pad down-left diagonal direction.
+    DownLeft,
+    /// D-pad down-right diagonal direction.
+    DownRight,
+    /// Button which gilrs could not identify.
+    Unidentified,
+    /// Input which should be ignored by the dispatcher such as releasing buttons or moving axes.
+    Ignored,
+}
+
+impl Input for GamepadInput {
+    type Context = ();
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        match s {
+            "south" | "South" | "SOUTH" | "a" | "A" => Ok(Self::South),
+            "east" | "East" | "EAST" | "b" | "B" => Ok(Self::East),
+            "north" | "North" | "NORTH" | "y" | "Y" => Ok(Self::North),
+            "west" | "West" | "WEST" | "x" | "X" => Ok(Self::West),
+            "c" | "C" => Ok(Self::C),
+            "z" | "Z" => Ok(Self::Z),
+            "lefttrigger" | "LeftTrigger" | "LEFTTRIGGER" | "lb" | "LB" | "l1" | "L1" => {
+                Ok(Self::LeftTrigger)
+            }
+            "lefttrigger2" | "LeftTrigger2" | "LEFTTRIGGER2" | "lt" | "LT" | "l2" | "L2" => {
+                Ok(Self::LeftTrigger2)
+            }
+            "righttrigger" | "RightTrigger" | "RIGHTTRIGGER" | "rb" | "RB" | "r1" | "R1" => {
+                Ok(Self::RightTrigger)
+            }
+            "righttrigger2" | "RightTrigger2" | "RIGHTTRIGGER2" | "rt" | "RT" | "r2" | "R2" => {
+                Ok(Self::RightTrigger2)
+            }
+            "select" | "Select" | "SELECT" | "back" | "Back" | "BACK" => Ok(Self::Select),
+            "start" | "Start" | "START" => Ok(Self::Start),
+            "mode" | "Mode" | "MODE" | "guide" | "Guide" | "GUIDE" => Ok(Self::Mode),
+            "leftthumb" | "LeftThumb" | "LEFTTHUMB" | "ls" | "LS" | "l3" | "L3" => {
+                Ok(Self::LeftThumb)
+            }
+            "rightthumb" | "RightThumb" | "RIGHTTHUMB" | "rs" | "RS" | "r3" | "R3" => {
+                Ok(Self::RightThumb)
+            }
+            "up" | "Up" | "UP" | "dpadup" | "DPadUp" | "DPADUP" => Ok(Self::Up),
+            "down" | "Down" | "DOWN" | "dpaddown" | "DPadDown" | "DPADDOWN" => Ok(Self::Down),
+            "left" | "Left" | "LEFT" | "dpadleft" | "DPadLeft" | "DPADLEFT" => Ok(Self::Left),
+            "right" | "Right" | "RIGHT" | "dpadright" | "DPadRight" | "DPADRIGHT" => {
+                Ok(Self::Right)
+            }
+            "upleft" | "UpLeft" | "UPLEFT" => Ok(Self::UpLeft),
+            "upright" | "UpRight" | "UPRIGHT" => Ok(Self::UpRight),
+            "downleft" | "DownLeft" | "DOWNLEFT" => Ok(Self::DownLeft),
+            "downright" | "DownRight" | "DOWNRIGHT" => Ok(Self::DownRight),
+            "unidentified" | "Unidentified" | "UNIDENTIFIED" => Ok(Self::Unidentified),
+            "" => Err(Error::EmptyKey),
+            _ => Err(Error::UnknownKey(s.into())),
+        }
+    }
+
+    fn is_ignored(&self) -> bool {
+        *self == Self::Ignored
+    }
+}
+
+impl FromStr for GamepadInput {
+    type Err = Error;
+
+    /// Parse a gamepad input from its name. See [`GamepadInput`] for the accepted names.
+    ///
+    /// ```
+    /// use keybinds::gilrs::GamepadInput;
+    /// use keybinds::Error;
+    ///
+    /// assert_eq!("RightTrigger2".parse(), Ok(GamepadInput::RightTrigger2));
+    /// assert_eq!("Foo".parse::<GamepadInput>(), Err(Error::UnknownKey("Foo".into())));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Self as Input>::parse(s)
+    }
+}
+
+impl fmt::Display for GamepadInput {
+    /// Generate the canonical name of the gamepad input. The generated string can be parsed again.
+    ///
+    /// ```
+    /// use keybinds::gilrs::GamepadInput;
+    ///
+    /// assert_eq!(GamepadInput::West.to_string(), "West");
+    /// assert_eq!(GamepadInput::UpLeft.to_string(), "UpLeft");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::South => "South",
+            Self::East => "East",
+            Self::North => "North",
+            Self::West => "West",
+            Self::C => "C",
+            Self::Z => "Z",
+            Self::LeftTrigger => "LeftTrigger",
+            Self::LeftTrigger2 => "LeftTrigger2",
+            Self::RightTrigger => "RightTrigger",
+            Self::RightTrigger2 => "RightTrigger2",
+            Self::Select => "Select",
+            Self::Start => "Start",
+            Self::Mode => "Mode",
+            Self::LeftThumb => "LeftThumb",
+            Self::RightThumb => "RightThumb",
+            Self::Up => "Up",
+            Self::Down => "Down",
+            Self::Left => "Left",
+            Self::Right => "Right",
+            Self::UpLeft => "UpLeft",
+            Self::UpRight => "UpRight",
+            Self::DownLeft => "DownLeft",
+            Self::DownRight => "DownRight",
+            Self::Unidentified => "Unidentified",
+            Self::Ignored => "Ignored",
+        };
+        f.write_str(name)
+    }
+}
+
+impl From<Button> for GamepadInput {
+    /// Convert gilrs's [`Button`] into [`GamepadInput`]. The D-pad buttons are converted into the straight directions.
+    ///
+    /// ```
+    /// use gilrs::Button;
+    /// use keybinds::gilrs::GamepadInput;
+    ///
+    /// assert_eq!(GamepadInput::from(Button::South), GamepadInput::South);
+    /// assert_eq!(GamepadInput::from(Button::DPadDown), GamepadInput::Down);
+    /// assert_eq!(GamepadInput::from(Button::Unknown), GamepadInput::Unidentified);
+    /// ```
+    fn from(button: Button) -> Self {
+        match button {
+            Button::South => Self::South,
+            Button::East => Self::East,
+            Button::North => Self::North,
+            Button::West => Self::West,
+            Button::C => Self::C,
+            Button::Z => Self::Z,
+            Button::LeftTrigger => Self::LeftTrigger,
+            Button::LeftTrigger2 => Self::LeftTrigger2,
+            Button::RightTrigger => Self::RightTrigger,
+            Button::RightTrigger2 => Self::RightTrigger2,
+            Button::Select => Self::Select,
+            Button::Start => Self::Start,
+            Button::Mode => Self::Mode,
+            Button::LeftThumb => Self::LeftThumb,
+            Button::RightThumb => Self::RightThumb,
+            Button::DPadUp => Self::Up,
+            Button::DPadDown => Self::Down,
+            Button::DPadLeft => Self::Left,
+            Button::DPadRight => Self::Right,
+            Button::Unknown => Self::Unidentified,
+        }
+    }
+}
+
+impl From<&EventType> for GamepadInput {
+    /// Convert gilrs's [`EventType`] into [`GamepadInput`]. Pressing and repeating buttons are converted into the
+    /// inputs and other events are converted into [`GamepadInput::Ignored`]. This conversion does not track the D-pad
+    /// state so no diagonal direction is generated. Use [`GilrsEventConverter`] for combo sequences.
+    ///
+    /// ```
+    /// use gilrs::EventType;
+    /// use keybinds::gilrs::GamepadInput;
+    ///
+    /// assert_eq!(GamepadInput::from(&EventType::Connected), GamepadInput::Ignored);
+    /// ```
+    fn from(event: &EventType) -> Self {
+        match event {
+            EventType::ButtonPressed(button, _) | EventType::ButtonRepeated(button, _) => {
+                Self::from(*button)
+            }
+            _ => Self::Ignored,
+        }
+    }
+}
+
+impl From<EventType> for GamepadInput {
+    fn from(event: EventType) -> Self {
+        Self::from(&event)
+    }
+}
+
+impl From<&Event> for GamepadInput {
+    /// Convert gilrs's [`Event`] into [`GamepadInput`]. See the conversion from [`EventType`] for more details.
+    fn from(event: &Event) -> Self {
+        Self::from(&event.event)
+    }
+}
+
+impl From<Event> for GamepadInput {
+    fn from(event: Event) -> Self {
+        Self::from(&event.event)
+    }
+}
+
+const DPAD_UP: u8 = 0b0001;
+const DPAD_DOWN: u8 = 0b0010;
+const DPAD_LEFT: u8 = 0b0100;
+const DPAD_RIGHT: u8 = 0b1000;
+
+fn dpad_bit(button: Button) -> Option<u8> {
+    match button {
+        Button::DPadUp => Some(DPAD_UP),
+        Button::DPadDown => Some(DPAD_DOWN),
+        Button::DPadLeft => Some(DPAD_LEFT),
+        Button::DPadRight => Some(DPAD_RIGHT),
+        _ => None,
+    }
+}
+
+/// Converter from gilrs's events into [`GamepadInput`] with tracking the D-pad buttons being held.
+///
+/// While a D-pad button is held, pressing the adjacent D-pad button is converted into the diagonal direction such as
+/// [`GamepadInput::DownRight`]. Releasing one of the two D-pad buttons is converted into the direction of the other
+/// button being still held. So rolling the D-pad from down to right generates `Down DownRight Right` which can match
+/// to the combo sequences in fighting games. Releasing the last D-pad button and opposite D-pad buttons held at once
+/// such as up and down are converted into [`GamepadInput::Ignored`].
+///
+/// Other events are converted in the same way as `GamepadInput::from`. Since the D-pad state is per gamepad, create
+/// one converter for each gamepad. See [the module document][crate::gilrs] for an example.
+#[derive(Default, Debug)]
+pub struct GilrsEventConverter {
+    dpad: u8,
+}
+
+impl GilrsEventConverter {
+    /// Returns the D-pad direction currently being held. [`GamepadInput::Ignored`] is returned when no D-pad button is
+    /// held.
+    ///
+    /// ```
+    /// use keybinds::gilrs::{GamepadInput, GilrsEventConverter};
+    ///
+    /// let converter = GilrsEventConverter::default();
+    /// assert_eq!(converter.direction(), GamepadInput::Ignored);
+    /// ```
+    pub fn direction(&self) -> GamepadInput {
+        let vertical = match self.dpad & (DPAD_UP | DPAD_DOWN) {
+            DPAD_UP => Some(true),
+            DPAD_DOWN => Some(false),
+            _ => None,
+        };
+        let horizontal = match self.dpad & (DPAD_LEFT | DPAD_RIGHT) {
+            DPAD_LEFT => Some(true),
+            DPAD_RIGHT => Some(false),
+            _ => None,
+        };
+        match (vertical, horizontal) {
+            (Some(true), None) => GamepadInput::Up,
+            (Some(false), None) => GamepadInput::Down,
+            (None, Some(true)) => GamepadInput::Left,
+            (None, Some(false)) => GamepadInput::Right,
+            (Some(true), Some(true)) => GamepadInput::UpLeft,
+            (Some(true), Some(false)) => GamepadInput::UpRight,
+            (Some(false), Some(true)) => GamepadInput::DownLeft,
+            (Some(false), Some(false)) => GamepadInput::DownRight,
+            (None, None) => GamepadInput::Ignored,
+        }
+    }
+
+    fn on_pressed(&mut self, button: Button) -> GamepadInput {
+        match dpad_bit(button) {
+            Some(bit) => {
+                self.dpad |= bit;
+                self.direction()
+            }
+            None => button.into(),
+        }
+    }
+
+    fn on_released(&mut self, button: Button) -> GamepadInput {
+        match dpad_bit(button) {
+            Some(bit) if self.dpad & bit != 0 => {
+                self.dpad &= !bit;
+                self.direction()
+            }
+            _ => GamepadInput::Ignored,
+        }
+    }
+
+    /// Convert gilrs's [`EventType`] into [`GamepadInput`] with tracking the D-pad state. Disconnecting the gamepad
+    /// resets the state. See [`GilrsEventConverter`] for more details.
+    pub fn convert(&mut self, event: &EventType) -> GamepadInput {
+        match event {
+            EventType::ButtonPressed(button, _) | EventType::ButtonRepeated(button, _) => {
+                self.on_pressed(*button)
+            }
+            EventType::ButtonReleased(button, _) => self.on_released(*button),
+            EventType::Disconnected | EventType::Dropped => {
+                self.dpad = 0;
+                GamepadInput::Ignored
+            }
+            _ => GamepadInput::Ignored,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GenericKeySeq, GenericKeybinds, Match};
+
+    #[test]
+    fn parse_input() {
+        for (name, expected) in [
+            ("South", GamepadInput::South),
+            ("south", GamepadInput::South),
+            ("SOUTH", GamepadInput::South),
+            ("a", GamepadInput::South),
+            ("B", GamepadInput::East),
+            ("X", GamepadInput::West),
+            ("Y", GamepadInput::North),
+            ("L1", GamepadInput::LeftTrigger),
+            ("LT", GamepadInput::LeftTrigger2),
+            ("rb", GamepadInput::RightTrigger),
+            ("R2", GamepadInput::RightTrigger2),
+            ("L3", GamepadInput::LeftThumb),
+            ("RS", GamepadInput::RightThumb),
+            ("Back", GamepadInput::Select),
+            ("Guide", GamepadInput::Mode),
+            ("DPadUp", GamepadInput::Up),
+            ("downleft", GamepadInput::DownLeft),
+            ("UPRIGHT", GamepadInput::UpRight),
+        ] {
+            assert_eq!(name.parse(), Ok(expected), "{name:?}");
+        }
+
+        for name in ["Ignored", "Foo", "dOwN", "Shift+A"] {
+            assert_eq!(
+                name.parse::<GamepadInput>(),
+                Err(Error::UnknownKey(name.into())),
+            );
+        }
+        assert_eq!("".parse::<GamepadInput>(), Err(Error::EmptyKey));
+    }
+
+    #[test]
+    fn display_input() {
+        for input in [
+            GamepadInput::South,
+            GamepadInput::LeftTrigger2,
+            GamepadInput::RightThumb,
+            GamepadInput::Select,
+            GamepadInput::Up,
+            GamepadInput::DownRight,
+            GamepadInput::Unidentified,
+        ] {
+            assert_eq!(input.to_string().parse(), Ok(input));
+        }
+        assert_eq!(GamepadInput::Ignored.to_string(), "Ignored");
+    }
+
+    #[test]
+    fn convert_button() {
+        assert_eq!(GamepadInput::from(Button::East), GamepadInput::East);
+        assert_eq!(
+            GamepadInput::from(Button::RightTrigger2),
+            GamepadInput::RightTrigger2,
+        );
+        assert_eq!(GamepadInput::from(Button::DPadLeft), GamepadInput::Left);
+        assert_eq!(
+            GamepadInput::from(Button::Unknown),
+            GamepadInput::Unidentified,
+        );
+        assert_eq!(
+            GamepadInput::from(EventType::Connected),
+            GamepadInput::Ignored,
+        );
+    }
+
+    #[test]
+    fn converter_dpad() {
+        let mut conv = GilrsEventConverter::default();
+        assert_eq!(conv.on_pressed(Button::DPadDown), GamepadInput::Down);
+        assert_eq!(conv.on_pressed(Button::DPadRight), GamepadInput::DownRight);
+        assert_eq!(conv.direction(), GamepadInput::DownRight);
+        assert_eq!(conv.on_released(Button::DPadDown), GamepadInput::Right);
+        assert_eq!(conv.on_pressed(Button::South), GamepadInput::South);
+        assert_eq!(conv.on_released(Button::South), GamepadInput::Ignored);
+        assert_eq!(conv.on_released(Button::DPadRight), GamepadInput::Ignored);
+        assert_eq!(conv.direction(), GamepadInput::Ignored);
+
+        // Releasing a button which was not held
+        assert_eq!(conv.on_released(Button::DPadUp), GamepadInput::Ignored);
+
+        assert_eq!(conv.on_pressed(Button::DPadUp), GamepadInput::Up);
+        assert_eq!(conv.on_pressed(Button::DPadLeft), GamepadInput::UpLeft);
+        assert_eq!(conv.on_pressed(Button::DPadDown), GamepadInput::Left);
+        assert_eq!(conv.on_pressed(Button::DPadRight), GamepadInput::Ignored);
+        assert_eq!(conv.on_released(Button::DPadUp), GamepadInput::Down);
+
+        assert_eq!(
+            conv.convert(&EventType::Disconnected),
+            GamepadInput::Ignored
+        );
+        assert_eq!(conv.direction(), GamepadInput::Ignored);
+        assert_eq!(conv.on_pressed(Button::DPadRight), GamepadInput::Right);
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum A {
+        Hadouken,
+        Jump,
+    }
+
+    #[test]
+    fn dispatch_combo() {
+        let seq: GenericKeySeq<GamepadInput> = "Down DownRight Right A".parse().unwrap();
+        assert_eq!(seq.to_string(), "Down DownRight Right South");
+        assert_eq!(
+            seq.match_to(&[GamepadInput::Down, GamepadInput::DownRight]),
+            Match::Prefix,
+        );
+
+        let mut keybinds = GenericKeybinds::<A, GamepadInput>::new(vec![]);
+        keybinds
+            .bind("Down DownRight Right A", A::Hadouken)
+            .unwrap();
+        keybinds.bind("Up", A::Jump).unwrap();
+        keybinds.bind("Down Foo", A::Jump).unwrap_err();
+
+        let mut conv = GilrsEventConverter::default();
+        let events = [
+            conv.on_pressed(Button::DPadDown),
+            conv.on_pressed(Button::DPadRight),
+            conv.on_released(Button::DPadDown),
+            conv.on_released(Button::DPadRight),
+        ];
+        for input in events {
+            assert_eq!(keybinds.dispatch(input), None);
+        }
+        assert_eq!(
+            keybinds.dispatch(conv.on_pressed(Button::South)),
+            Some(&A::Hadouken),
+        );
+        assert_eq!(
+            keybinds.dispatch(conv.on_pressed(Button::DPadUp)),
+            Some(&A::Jump),
+        );
+    }
+}
@@ -1,10 +1,13 @@
 //! Support for [`iced`] crate.
 //!
 //! This module provides the conversions from iced's event or key types to [`Key`], [`Mods`], and
-//! [`KeyInput`].
+//! [`KeyInput`]. To customize the conversion of non-Latin keys, see [`convert_with_fallback`]. To distinguish the left
+//! and right modifier keys, use [`IcedEventConverter`]. The reverse conversion from [`KeyInput`] to iced's
+//! [`keyboard::Event`][KeyEvent] is also provided to synthesize key events.
 //!
-//! Put [`Keybinds`][crate::Keybinds] as a part of state of your application and
-//! dispatch the action in the `update` method. Key events can be subscribed as [`iced::Subscription`].
+//! Put [`Keybinds`] as a part of state of your application and
+//! dispatch the action in the `update` method. Key events can be subscribed as [`iced::Subscription`]. To receive the
+//! dispatched actions as messages directly, see [`subscription`].
 //!
 //! ```no_run
 //! use keybinds::Keybinds;
@@ -69,22 +72,28 @@
 //!
 //! iced::run("My App", App::update, App::view).unwrap();
 //! ```
-use crate::{Key, KeyInput, Mods};
-use iced::keyboard::key::Named;
-use iced::keyboard::{Event as KeyEvent, Key as IcedKey, Modifiers};
-use iced::Event;
+use crate::{Code, Error, Key, KeyInput, Keybinds, LatinFallback, Mods, Platform, Result};
+use iced::advanced::subscription::{self as iced_subscription, EventStream, Hasher, Recipe};
+use iced::event::Status;
+use iced::futures::future::{self, Either, Future};
+use iced::futures::stream::{self, StreamExt};
+#[cfg(not(target_arch = "wasm32"))]
+use iced::futures::{channel::oneshot, FutureExt as _};
+use iced::keyboard::key::{Code as IcedCode, Named, NativeCode, Physical};
+use iced::keyboard::{Event as KeyEvent, Key as IcedKey, Location, Modifiers};
+use iced::mouse::{Button, Event as MouseEvent, ScrollDelta};
+use iced::{Event, Subscription};
+use std::any::TypeId;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Instant;
 
 impl From<&IcedKey> for Key {
     fn from(key: &IcedKey) -> Self {
         match key {
-            IcedKey::Character(s) => {
-                let mut chars = s.chars();
-                if let (Some(c), None) = (chars.next(), chars.next()) {
-                    Self::Char(c)
-                } else {
-                    Self::Unidentified
-                }
-            }
+            IcedKey::Character(s) => Self::from_text(s),
             IcedKey::Named(named) => match named {
                 Named::Space => Self::Char(' '),
                 Named::ArrowUp => Self::Up,
@@ -108,6 +117,57 @@ impl From<&IcedKey> for Key {
                 Named::Undo => Self::Undo,
                 Named::Redo => Self::Redo,
                 Named::Help => Self::Help,
+                Named::Accept => Self::Accept,
+                Named::Again => Self::Again,
+                Named::Attn => Self::Attn,
+                Named::Cancel => Self::Cancel,
+                Named::Execute => Self::Execute,
+                Named::Find => Self::Find,
+                Named::Props => Self::Props,
+                Named::Select => Self::Select,
+                Named::CrSel => Self::CrSel,
+                Named::EraseEof => Self::EraseEof,
+                Named::ExSel => Self::ExSel,
+                Named::BrightnessDown => Self::BrightnessDown,
+                Named::BrightnessUp => Self::BrightnessUp,
+                Named::Eject => Self::Eject,
+                Named::LogOff => Self::LogOff,
+                Named::Power => Self::Power,
+                Named::PowerOff => Self::PowerOff,
+                Named::Hibernate => Self::Hibernate,
+                Named::Standby => Self::Standby,
+                Named::WakeUp => Self::WakeUp,
+                Named::MediaFastForward => Self::FastForward,
+                Named::MediaRecord => Self::Record,
+                Named::BrowserBack => Self::BrowserBack,
+                Named::BrowserFavorites => Self::BrowserFavorites,
+                Named::BrowserForward => Self::BrowserForward,
+                Named::BrowserHome => Self::BrowserHome,
+                Named::BrowserRefresh => Self::BrowserRefresh,
+                Named::BrowserSearch => Self::BrowserSearch,
+                Named::BrowserStop => Self::BrowserStop,
+                Named::LaunchApplication1 => Self::LaunchApplication1,
+                Named::LaunchApplication2 => Self::LaunchApplication2,
+                Named::LaunchCalendar => Self::LaunchCalendar,
+                Named::LaunchContacts => Self::LaunchContacts,
+                Named::LaunchMail => Self::LaunchMail,
+                Named::LaunchMediaPlayer => Self::LaunchMediaPlayer,
+                Named::LaunchMusicPlayer => Self::LaunchMusicPlayer,
+                Named::LaunchPhone => Self::LaunchPhone,
+                Named::LaunchScreenSaver => Self::LaunchScreenSaver,
+                Named::LaunchSpreadsheet => Self::LaunchSpreadsheet,
+                Named::LaunchWebBrowser => Self::LaunchWebBrowser,
+                Named::LaunchWebCam => Self::LaunchWebCam,
+                Named::LaunchWordProcessor => Self::LaunchWordProcessor,
+                Named::Close => Self::Close,
+                Named::New => Self::New,
+                Named::Open => Self::Open,
+                Named::Print => Self::Print,
+                Named::Save => Self::Save,
+                Named::SpellCheck => Self::SpellCheck,
+                Named::MailForward => Self::MailForward,
+                Named::MailReply => Self::MailReply,
+                Named::MailSend => Self::MailSend,
                 Named::ZoomIn => Self::ZoomIn,
                 Named::ZoomOut => Self::ZoomOut,
                 Named::ZoomToggle => Self::ZoomToggle,
@@ -161,6 +221,7 @@ impl From<&IcedKey> for Key {
                 Named::F34 => Self::F34,
                 Named::F35 => Self::F35,
                 Named::Alt
+                | Named::AltGraph
                 | Named::Control
                 | Named::Shift
                 | Named::Super
@@ -180,6 +241,160 @@ impl From<IcedKey> for Key {
     }
 }
 
+impl From<&Physical> for Key {
+    /// Convert iced's physical key into [`Key::Physical`]. Physical keys which cannot be represented with [`Code`]
+    /// are converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::{Key, Code};
+    /// use iced::keyboard::key::{Code as IcedCode, Physical};
+    ///
+    /// assert_eq!(Key::from(Physical::Code(IcedCode::KeyW)), Key::Physical(Code::KeyW));
+    /// assert_eq!(Key::from(Physical::Code(IcedCode::Fn)), Key::Unidentified);
+    /// ```
+    fn from(key: &Physical) -> Self {
+        match key {
+            Physical::Code(code) => match code {
+                IcedCode::Backquote => Self::Physical(Code::Backquote),
+                IcedCode::Backslash => Self::Physical(Code::Backslash),
+                IcedCode::BracketLeft => Self::Physical(Code::BracketLeft),
+                IcedCode::BracketRight => Self::Physical(Code::BracketRight),
+                IcedCode::Comma => Self::Physical(Code::Comma),
+                IcedCode::Digit0 => Self::Physical(Code::Digit0),
+                IcedCode::Digit1 => Self::Physical(Code::Digit1),
+                IcedCode::Digit2 => Self::Physical(Code::Digit2),
+                IcedCode::Digit3 => Self::Physical(Code::Digit3),
+                IcedCode::Digit4 => Self::Physical(Code::Digit4),
+                IcedCode::Digit5 => Self::Physical(Code::Digit5),
+                IcedCode::Digit6 => Self::Physical(Code::Digit6),
+                IcedCode::Digit7 => Self::Physical(Code::Digit7),
+                IcedCode::Digit8 => Self::Physical(Code::Digit8),
+                IcedCode::Digit9 => Self::Physical(Code::Digit9),
+                IcedCode::Equal => Self::Physical(Code::Equal),
+                IcedCode::IntlBackslash => Self::Physical(Code::IntlBackslash),
+                IcedCode::IntlRo => Self::Physical(Code::IntlRo),
+                IcedCode::IntlYen => Self::Physical(Code::IntlYen),
+                IcedCode::KeyA => Self::Physical(Code::KeyA),
+                IcedCode::KeyB => Self::Physical(Code::KeyB),
+                IcedCode::KeyC => Self::Physical(Code::KeyC),
+                IcedCode::KeyD => Self::Physical(Code::KeyD),
+                IcedCode::KeyE => Self::Physical(Code::KeyE),
+                IcedCode::KeyF => Self::Physical(Code::KeyF),
+                IcedCode::KeyG => Self::Physical(Code::KeyG),
+                IcedCode::KeyH => Self::Physical(Code::KeyH),
+                IcedCode::KeyI => Self::Physical(Code::KeyI),
+                IcedCode::KeyJ => Self::Physical(Code::KeyJ),
+                IcedCode::KeyK => Self::Physical(Code::KeyK),
+                IcedCode::KeyL => Self::Physical(Code::KeyL),
+                IcedCode::KeyM => Self::Physical(Code::KeyM),
+                IcedCode::KeyN => Self::Physical(Code::KeyN),
+                IcedCode::KeyO => Self::Physical(Code::KeyO),
+                IcedCode::KeyP => Self::Physical(Code::KeyP),
+                IcedCode::KeyQ => Self::Physical(Code::KeyQ),
+                IcedCode::KeyR => Self::Physical(Code::KeyR),
+                IcedCode::KeyS => Self::Physical(Code::KeyS),
+                IcedCode::KeyT => Self::Physical(Code::KeyT),
+                IcedCode::KeyU => Self::Physical(Code::KeyU),
+                IcedCode::KeyV => Self::Physical(Code::KeyV),
+                IcedCode::KeyW => Self::Physical(Code::KeyW),
+                IcedCode::KeyX => Self::Physical(Code::KeyX),
+                IcedCode::KeyY => Self::Physical(Code::KeyY),
+                IcedCode::KeyZ => Self::Physical(Code::KeyZ),
+                IcedCode::Minus => Self::Physical(Code::Minus),
+                IcedCode::Period => Self::Physical(Code::Period),
+                IcedCode::Quote => Self::Physical(Code::Quote),
+                IcedCode::Semicolon => Self::Physical(Code::Semicolon),
+                IcedCode::Slash => Self::Physical(Code::Slash),
+                IcedCode::AltLeft => Self::Physical(Code::AltLeft),
+                IcedCode::AltRight => Self::Physical(Code::AltRight),
+                IcedCode::Backspace => Self::Physical(Code::Backspace),
+                IcedCode::CapsLock => Self::Physical(Code::CapsLock),
+                IcedCode::ContextMenu => Self::Physical(Code::ContextMenu),
+                IcedCode::ControlLeft => Self::Physical(Code::ControlLeft),
+                IcedCode::ControlRight => Self::Physical(Code::ControlRight),
+                IcedCode::Enter => Self::Physical(Code::Enter),
+                IcedCode::SuperLeft => Self::Physical(Code::MetaLeft),
+                IcedCode::SuperRight => Self::Physical(Code::MetaRight),
+                IcedCode::ShiftLeft => Self::Physical(Code::ShiftLeft),
+                IcedCode::ShiftRight => Self::Physical(Code::ShiftRight),
+                IcedCode::Space => Self::Physical(Code::Space),
+                IcedCode::Tab => Self::Physical(Code::Tab),
+                IcedCode::Convert => Self::Physical(Code::Convert),
+                IcedCode::KanaMode => Self::Physical(Code::KanaMode),
+                IcedCode::Lang1 => Self::Physical(Code::Lang1),
+                IcedCode::Lang2 => Self::Physical(Code::Lang2),
+                IcedCode::NonConvert => Self::Physical(Code::NonConvert),
+                IcedCode::Delete => Self::Physical(Code::Delete),
+                IcedCode::End => Self::Physical(Code::End),
+                IcedCode::Help => Self::Physical(Code::Help),
+                IcedCode::Home => Self::Physical(Code::Home),
+                IcedCode::Insert => Self::Physical(Code::Insert),
+                IcedCode::PageDown => Self::Physical(Code::PageDown),
+                IcedCode::PageUp => Self::Physical(Code::PageUp),
+                IcedCode::ArrowDown => Self::Physical(Code::ArrowDown),
+                IcedCode::ArrowLeft => Self::Physical(Code::ArrowLeft),
+                IcedCode::ArrowRight => Self::Physical(Code::ArrowRight),
+                IcedCode::ArrowUp => Self::Physical(Code::ArrowUp),
+                IcedCode::NumLock => Self::Physical(Code::NumLock),
+                IcedCode::Numpad0 => Self::Physical(Code::Numpad0),
+                IcedCode::Numpad1 => Self::Physical(Code::Numpad1),
+                IcedCode::Numpad2 => Self::Physical(Code::Numpad2),
+                IcedCode::Numpad3 => Self::Physical(Code::Numpad3),
+                IcedCode::Numpad4 => Self::Physical(Code::Numpad4),
+                IcedCode::Numpad5 => Self::Physical(Code::Numpad5),
+                IcedCode::Numpad6 => Self::Physical(Code::Numpad6),
+                IcedCode::Numpad7 => Self::Physical(Code::Numpad7),
+                IcedCode::Numpad8 => Self::Physical(Code::Numpad8),
+                IcedCode::Numpad9 => Self::Physical(Code::Numpad9),
+                IcedCode::NumpadAdd => Self::Physical(Code::NumpadAdd),
+                IcedCode::NumpadComma => Self::Physical(Code::NumpadComma),
+                IcedCode::NumpadDecimal => Self::Physical(Code::NumpadDecimal),
+                IcedCode::NumpadDivide => Self::Physical(Code::NumpadDivide),
+                IcedCode::NumpadEnter => Self::Physical(Code::NumpadEnter),
+                IcedCode::NumpadEqual => Self::Physical(Code::NumpadEqual),
+                IcedCode::NumpadMultiply => Self::Physical(Code::NumpadMultiply),
+                IcedCode::NumpadSubtract => Self::Physical(Code::NumpadSubtract),
+                IcedCode::Escape => Self::Physical(Code::Escape),
+                IcedCode::F1 => Self::Physical(Code::F1),
+                IcedCode::F2 => Self::Physical(Code::F2),
+                IcedCode::F3 => Self::Physical(Code::F3),
+                IcedCode::F4 => Self::Physical(Code::F4),
+                IcedCode::F5 => Self::Physical(Code::F5),
+                IcedCode::F6 => Self::Physical(Code::F6),
+                IcedCode::F7 => Self::Physical(Code::F7),
+                IcedCode::F8 => Self::Physical(Code::F8),
+                IcedCode::F9 => Self::Physical(Code::F9),
+                IcedCode::F10 => Self::Physical(Code::F10),
+                IcedCode::F11 => Self::Physical(Code::F11),
+                IcedCode::F12 => Self::Physical(Code::F12),
+                IcedCode::F13 => Self::Physical(Code::F13),
+                IcedCode::F14 => Self::Physical(Code::F14),
+                IcedCode::F15 => Self::Physical(Code::F15),
+                IcedCode::F16 => Self::Physical(Code::F16),
+                IcedCode::F17 => Self::Physical(Code::F17),
+                IcedCode::F18 => Self::Physical(Code::F18),
+                IcedCode::F19 => Self::Physical(Code::F19),
+                IcedCode::F20 => Self::Physical(Code::F20),
+                IcedCode::F21 => Self::Physical(Code::F21),
+                IcedCode::F22 => Self::Physical(Code::F22),
+                IcedCode::F23 => Self::Physical(Code::F23),
+                IcedCode::F24 => Self::Physical(Code::F24),
+                IcedCode::PrintScreen => Self::Physical(Code::PrintScreen),
+                IcedCode::ScrollLock => Self::Physical(Code::ScrollLock),
+                IcedCode::Pause => Self::Physical(Code::Pause),
+                _ => Self::Unidentified,
+            },
+            Physical::Unidentified(_) => Self::Unidentified,
+        }
+    }
+}
+
+impl From<Physical> for Key {
+    fn from(key: Physical) -> Self {
+        Self::from(&key)
+    }
+}
+
 impl From<&Modifiers> for Mods {
     /// Convert iced's [`Modifiers`] into keybinds' [`Mods`].
     ///
@@ -221,8 +436,12 @@ impl From<&KeyEvent> for KeyInput {
     /// no modifiers. Note that <kbd>Shift</kbd> modifier is removed when the pressed key is unnamed following the
     /// [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
     ///
+    /// The physical key of the event is also converted so that physical key bindings like `Ctrl+Shift+[KeyX]` can
+    /// match to the input. See [`KeyInput::with_code`] for more details. Keys on the numeric keypad are converted
+    /// with [`Mods::NUMPAD`].
+    ///
     /// ```
-    /// use keybinds::{KeyInput, Mods};
+    /// use keybinds::{KeyInput, KeySeq, Match, Mods, Code};
     /// use iced::keyboard::{Event, Modifiers, Key};
     ///
     /// // Key event for Ctrl+Shift+X
@@ -230,13 +449,31 @@ impl From<&KeyEvent> for KeyInput {
     ///     key: Key::Character("x".into()),
     ///     modified_key: Key::Character("X".into()),
     ///     modifiers: Modifiers::CTRL | Modifiers::SHIFT,
+    ///     physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::KeyX),
     ///     // ...
     /// #   location: iced::keyboard::Location::Standard,
     /// #   text: None,
-    /// #   physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::KeyX),
     /// };
     /// // `Mods::SHIFT` is removed because 'X' is already modified by Shift key
-    /// assert_eq!(KeyInput::from(event), KeyInput::new('X', Mods::CTRL));
+    /// let input = KeyInput::from(event);
+    /// assert_eq!(input, KeyInput::new('X', Mods::CTRL));
+    ///
+    /// // The physical key binding also matches to the input
+    /// assert_eq!(input.code(), Some(Code::KeyX));
+    /// let seq: KeySeq = "Ctrl+Shift+[KeyX]".parse().unwrap();
+    /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+    ///
+    /// // Non-Latin characters typed with modifiers fall back to Latin characters. See `convert_with_fallback`
+    /// let event = Event::KeyPressed {
+    ///     key: Key::Character("с".into()), // Cyrillic 'с'
+    ///     modified_key: Key::Character("с".into()),
+    ///     modifiers: Modifiers::CTRL,
+    ///     physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::KeyC),
+    ///     // ...
+    /// #   location: iced::keyboard::Location::Standard,
+    /// #   text: None,
+    /// };
+    /// assert_eq!(KeyInput::from(event), KeyInput::with_code('c', Mods::CTRL, Code::KeyC));
     ///
     /// // Events other than key presses are ignored
     /// let event = Event::KeyReleased {
@@ -248,29 +485,152 @@ impl From<&KeyEvent> for KeyInput {
     /// assert_eq!(KeyInput::from(event), KeyInput::from(keybinds::Key::Ignored));
     /// ```
     fn from(event: &KeyEvent) -> Self {
+        convert_with_fallback(event, LatinFallback::default())
+    }
+}
+
+impl From<KeyEvent> for KeyInput {
+    fn from(event: KeyEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
+/// Convert iced's key event to [`KeyInput`] with the policy to fall back from non-Latin logical keys to the Latin keys
+/// at the same physical key positions. `KeyInput::from` uses the default policy [`LatinFallback::WithModifiers`]. See
+/// [`LatinFallback`] for more details.
+///
+/// ```
+/// use keybinds::{KeyInput, LatinFallback, Mods, Code};
+/// use keybinds::iced::convert_with_fallback;
+/// use iced::keyboard::{Event, Modifiers, Key};
+///
+/// // Key event for Ctrl+С on Russian layout
+/// let event = Event::KeyPressed {
+///     key: Key::Character("с".into()),
+///     modified_key: Key::Character("с".into()),
+///     modifiers: Modifiers::CTRL,
+///     physical_key: iced::keyboard::key::Physical::Code(iced::keyboard::key::Code::KeyC),
+///     // ...
+/// #   location: iced::keyboard::Location::Standard,
+/// #   text: None,
+/// };
+///
+/// let input = convert_with_fallback(&event, LatinFallback::Never);
+/// assert_eq!(input, KeyInput::with_code('с', Mods::CTRL, Code::KeyC));
+///
+/// let input = convert_with_fallback(&event, LatinFallback::WithModifiers);
+/// assert_eq!(input, KeyInput::with_code('c', Mods::CTRL, Code::KeyC));
+/// ```
+pub fn convert_with_fallback(event: &KeyEvent, fallback: LatinFallback) -> KeyInput {
+    match event {
+        KeyEvent::KeyPressed {
+            modified_key,
+            physical_key,
+            location,
+            modifiers,
+            ..
+        } => {
+            let mut mods = Mods::from(modifiers);
+            if *location == Location::Numpad {
+                mods |= Mods::NUMPAD;
+            }
+            match Key::from(physical_key) {
+                Key::Physical(code) => {
+                    fallback.apply(KeyInput::with_code(modified_key, mods, code))
+                }
+                _ => KeyInput::new(modified_key, mods),
+            }
+        }
+        _ => Key::Ignored.into(),
+    }
+}
+
+impl From<Button> for Key {
+    /// Convert iced's mouse button into [`Key`]. [`Button::Other`] is converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use iced::mouse::Button;
+    ///
+    /// assert_eq!(Key::from(Button::Left), Key::MouseLeft);
+    /// assert_eq!(Key::from(Button::Other(8)), Key::Unidentified);
+    /// ```
+    fn from(button: Button) -> Self {
+        match button {
+            Button::Left => Self::MouseLeft,
+            Button::Right => Self::MouseRight,
+            Button::Middle => Self::MouseMiddle,
+            Button::Back => Self::MouseBack,
+            Button::Forward => Self::MouseForward,
+            Button::Other(_) => Self::Unidentified,
+        }
+    }
+}
+
+impl From<&ScrollDelta> for Key {
+    /// Convert iced's scroll amount into one of [`Key::WheelUp`], [`Key::WheelDown`], [`Key::WheelLeft`] and
+    /// [`Key::WheelRight`]. The direction of the larger amount is chosen. No scroll is converted into [`Key::Ignored`].
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use iced::mouse::ScrollDelta;
+    ///
+    /// assert_eq!(Key::from(ScrollDelta::Lines { x: 0.0, y: -1.0 }), Key::WheelDown);
+    /// assert_eq!(Key::from(ScrollDelta::Pixels { x: 12.0, y: 3.0 }), Key::WheelLeft);
+    /// assert_eq!(Key::from(ScrollDelta::Lines { x: 0.0, y: 0.0 }), Key::Ignored);
+    /// ```
+    fn from(delta: &ScrollDelta) -> Self {
+        match *delta {
+            ScrollDelta::Lines { x, y } | ScrollDelta::Pixels { x, y } => {
+                Self::from_wheel_delta(x.into(), y.into())
+            }
+        }
+    }
+}
+
+impl From<ScrollDelta> for Key {
+    fn from(delta: ScrollDelta) -> Self {
+        Self::from(&delta)
+    }
+}
+
+impl From<&MouseEvent> for KeyInput {
+    /// Convert iced's mouse events into [`KeyInput`]. Pressing mouse buttons and scrolling the wheel are converted into
+    /// the mouse keys such as [`Key::MouseLeft`] and [`Key::WheelUp`]. Other mouse events are converted into
+    /// `Key::Ignored`.
+    ///
+    /// Since iced's mouse events do not report the modifiers state, the converted inputs have no modifiers. Use
+    /// [`IcedEventConverter::convert_event`] to convert mouse events with modifiers.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput};
+    /// use iced::mouse::{Button, Event};
+    ///
+    /// assert_eq!(KeyInput::from(Event::ButtonPressed(Button::Left)), KeyInput::from(Key::MouseLeft));
+    /// assert_eq!(KeyInput::from(Event::ButtonReleased(Button::Left)), KeyInput::from(Key::Ignored));
+    /// ```
+    fn from(event: &MouseEvent) -> Self {
         match event {
-            KeyEvent::KeyPressed {
-                modified_key,
-                modifiers,
-                ..
-            } => Self::new(modified_key, modifiers),
+            MouseEvent::ButtonPressed(button) => Key::from(*button).into(),
+            MouseEvent::WheelScrolled { delta } => Key::from(delta).into(),
             _ => Key::Ignored.into(),
         }
     }
 }
 
-impl From<KeyEvent> for KeyInput {
-    fn from(event: KeyEvent) -> Self {
+impl From<MouseEvent> for KeyInput {
+    fn from(event: MouseEvent) -> Self {
         Self::from(&event)
     }
 }
 
 impl From<&Event> for KeyInput {
-    /// Convert iced's events to [`KeyInput`]. Events unrelated to key presses are converted into `Key::Ignored` with
-    /// no modifiers.
+    /// Convert iced's events to [`KeyInput`]. Key events and mouse events are converted in the same way as converting
+    /// [`KeyEvent`] and [`MouseEvent`]. Other events are converted into `Key::Ignored` with no modifiers.
     fn from(event: &Event) -> Self {
         match event {
             Event::Keyboard(event) => event.into(),
+            Event::Mouse(event) => event.into(),
             _ => Key::Ignored.into(),
         }
     }
@@ -282,23 +642,887 @@ impl From<Event> for KeyInput {
     }
 }
 
+// Convert the key into iced's logical key. This is the reverse of `From<&IcedKey> for Key`.
+fn iced_key(key: Key) -> Option<IcedKey> {
+    let named = match key {
+        Key::Char(' ') => Named::Space,
+        Key::Char(c) => return Some(IcedKey::Character(c.encode_utf8(&mut [0; 4]).into())),
+        Key::Str(s) => return Some(IcedKey::Character(s.as_str().into())),
+        Key::Up => Named::ArrowUp,
+        Key::Right => Named::ArrowRight,
+        Key::Down => Named::ArrowDown,
+        Key::Left => Named::ArrowLeft,
+        Key::Enter => Named::Enter,
+        Key::Backspace => Named::Backspace,
+        Key::Delete => Named::Delete,
+        Key::Home => Named::Home,
+        Key::End => Named::End,
+        Key::PageUp => Named::PageUp,
+        Key::PageDown => Named::PageDown,
+        Key::Esc => Named::Escape,
+        Key::Tab => Named::Tab,
+        Key::Insert => Named::Insert,
+        Key::Copy => Named::Copy,
+        Key::Cut => Named::Cut,
+        Key::Paste => Named::Paste,
+        Key::Clear => Named::Clear,
+        Key::Undo => Named::Undo,
+        Key::Redo => Named::Redo,
+        Key::Help => Named::Help,
+        Key::Accept => Named::Accept,
+        Key::Again => Named::Again,
+        Key::Attn => Named::Attn,
+        Key::Cancel => Named::Cancel,
+        Key::Execute => Named::Execute,
+        Key::Find => Named::Find,
+        Key::Props => Named::Props,
+        Key::Select => Named::Select,
+        Key::CrSel => Named::CrSel,
+        Key::EraseEof => Named::EraseEof,
+        Key::ExSel => Named::ExSel,
+        Key::BrightnessDown => Named::BrightnessDown,
+        Key::BrightnessUp => Named::BrightnessUp,
+        Key::Eject => Named::Eject,
+        Key::LogOff => Named::LogOff,
+        Key::Power => Named::Power,
+        Key::PowerOff => Named::PowerOff,
+        Key::Hibernate => Named::Hibernate,
+        Key::Standby => Named::Standby,
+        Key::WakeUp => Named::WakeUp,
+        Key::FastForward => Named::MediaFastForward,
+        Key::Record => Named::MediaRecord,
+        Key::BrowserBack => Named::BrowserBack,
+        Key::BrowserFavorites => Named::BrowserFavorites,
+        Key::BrowserForward => Named::BrowserForward,
+        Key::BrowserHome => Named::BrowserHome,
+        Key::BrowserRefresh => Named::BrowserRefresh,
+        Key::BrowserSearch => Named::BrowserSearch,
+        Key::BrowserStop => Named::BrowserStop,
+        Key::LaunchApplication1 => Named::LaunchApplication1,
+        Key::LaunchApplication2 => Named::LaunchApplication2,
+        Key::LaunchCalendar => Named::LaunchCalendar,
+        Key::LaunchContacts => Named::LaunchContacts,
+        Key::LaunchMail => Named::LaunchMail,
+        Key::LaunchMediaPlayer => Named::LaunchMediaPlayer,
+        Key::LaunchMusicPlayer => Named::LaunchMusicPlayer,
+        Key::LaunchPhone => Named::LaunchPhone,
+        Key::LaunchScreenSaver => Named::LaunchScreenSaver,
+        Key::LaunchSpreadsheet => Named::LaunchSpreadsheet,
+        Key::LaunchWebBrowser => Named::LaunchWebBrowser,
+        Key::LaunchWebCam => Named::LaunchWebCam,
+        Key::LaunchWordProcessor => Named::LaunchWordProcessor,
+        Key::Close => Named::Close,
+        Key::New => Named::New,
+        Key::Open => Named::Open,
+        Key::Print => Named::Print,
+        Key::Save => Named::Save,
+        Key::SpellCheck => Named::SpellCheck,
+        Key::MailForward => Named::MailForward,
+        Key::MailReply => Named::MailReply,
+        Key::MailSend => Named::MailSend,
+        Key::ZoomIn => Named::ZoomIn,
+        Key::ZoomOut => Named::ZoomOut,
+        Key::ZoomToggle => Named::ZoomToggle,
+        Key::ScrollLock => Named::ScrollLock,
+        Key::NumLock => Named::NumLock,
+        Key::PrintScreen => Named::PrintScreen,
+        Key::Menu => Named::ContextMenu,
+        Key::Play => Named::MediaPlay,
+        Key::Pause => Named::MediaPause,
+        Key::PlayPause => Named::MediaPlayPause,
+        Key::Stop => Named::MediaStop,
+        Key::Rewind => Named::MediaRewind,
+        Key::NextTrack => Named::MediaTrackNext,
+        Key::PrevTrack => Named::MediaTrackPrevious,
+        Key::VolumeUp => Named::AudioVolumeUp,
+        Key::VolumeDown => Named::AudioVolumeDown,
+        Key::Mute => Named::AudioVolumeMute,
+        Key::F1 => Named::F1,
+        Key::F2 => Named::F2,
+        Key::F3 => Named::F3,
+        Key::F4 => Named::F4,
+        Key::F5 => Named::F5,
+        Key::F6 => Named::F6,
+        Key::F7 => Named::F7,
+        Key::F8 => Named::F8,
+        Key::F9 => Named::F9,
+        Key::F10 => Named::F10,
+        Key::F11 => Named::F11,
+        Key::F12 => Named::F12,
+        Key::F13 => Named::F13,
+        Key::F14 => Named::F14,
+        Key::F15 => Named::F15,
+        Key::F16 => Named::F16,
+        Key::F17 => Named::F17,
+        Key::F18 => Named::F18,
+        Key::F19 => Named::F19,
+        Key::F20 => Named::F20,
+        Key::F21 => Named::F21,
+        Key::F22 => Named::F22,
+        Key::F23 => Named::F23,
+        Key::F24 => Named::F24,
+        Key::F25 => Named::F25,
+        Key::F26 => Named::F26,
+        Key::F27 => Named::F27,
+        Key::F28 => Named::F28,
+        Key::F29 => Named::F29,
+        Key::F30 => Named::F30,
+        Key::F31 => Named::F31,
+        Key::F32 => Named::F32,
+        Key::F33 => Named::F33,
+        Key::F34 => Named::F34,
+        Key::F35 => Named::F35,
+        _ => return None,
+    };
+    Some(IcedKey::Named(named))
+}
+
+// Convert the physical key into iced's key code. This is the reverse of `From<&Physical> for Key`.
+fn iced_code(code: Code) -> IcedCode {
+    match code {
+        Code::Backquote => IcedCode::Backquote,
+        Code::Backslash => IcedCode::Backslash,
+        Code::BracketLeft => IcedCode::BracketLeft,
+        Code::BracketRight => IcedCode::BracketRight,
+        Code::Comma => IcedCode::Comma,
+        Code::Digit0 => IcedCode::Digit0,
+        Code::Digit1 => IcedCode::Digit1,
+        Code::Digit2 => IcedCode::Digit2,
+        Code::Digit3 => IcedCode::Digit3,
+        Code::Digit4 => IcedCode::Digit4,
+        Code::Digit5 => IcedCode::Digit5,
+        Code::Digit6 => IcedCode::Digit6,
+        Code::Digit7 => IcedCode::Digit7,
+        Code::Digit8 => IcedCode::Digit8,
+        Code::Digit9 => IcedCode::Digit9,
+        Code::Equal => IcedCode::Equal,
+        Code::IntlBackslash => IcedCode::IntlBackslash,
+        Code::IntlRo => IcedCode::IntlRo,
+        Code::IntlYen => IcedCode::IntlYen,
+        Code::KeyA => IcedCode::KeyA,
+        Code::KeyB => IcedCode::KeyB,
+        Code::KeyC => IcedCode::KeyC,
+        Code::KeyD => IcedCode::KeyD,
+        Code::KeyE => IcedCode::KeyE,
+        Code::KeyF => IcedCode::KeyF,
+        Code::KeyG => IcedCode::KeyG,
+        Code::KeyH => IcedCode::KeyH,
+        Code::KeyI => IcedCode::KeyI,
+        Code::KeyJ => IcedCode::KeyJ,
+        Code::KeyK => IcedCode::KeyK,
+        Code::KeyL => IcedCode::KeyL,
+        Code::KeyM => IcedCode::KeyM,
+        Code::KeyN => IcedCode::KeyN,
+        Code::KeyO => IcedCode::KeyO,
+        Code::KeyP => IcedCode::KeyP,
+        Code::KeyQ => IcedCode::KeyQ,
+        Code::KeyR => IcedCode::KeyR,
+        Code::KeyS => IcedCode::KeyS,
+        Code::KeyT => IcedCode::KeyT,
+        Code::KeyU => IcedCode::KeyU,
+        Code::KeyV => IcedCode::KeyV,
+        Code::KeyW => IcedCode::KeyW,
+        Code::KeyX => IcedCode::KeyX,
+        Code::KeyY => IcedCode::KeyY,
+        Code::KeyZ => IcedCode::KeyZ,
+        Code::Minus => IcedCode::Minus,
+        Code::Period => IcedCode::Period,
+        Code::Quote => IcedCode::Quote,
+        Code::Semicolon => IcedCode::Semicolon,
+        Code::Slash => IcedCode::Slash,
+        Code::AltLeft => IcedCode::AltLeft,
+        Code::AltRight => IcedCode::AltRight,
+        Code::Backspace => IcedCode::Backspace,
+        Code::CapsLock => IcedCode::CapsLock,
+        Code::ContextMenu => IcedCode::ContextMenu,
+        Code::ControlLeft => IcedCode::ControlLeft,
+        Code::ControlRight => IcedCode::ControlRight,
+        Code::Enter => IcedCode::Enter,
+        Code::MetaLeft => IcedCode::SuperLeft,
+        Code::MetaRight => IcedCode::SuperRight,
+        Code::ShiftLeft => IcedCode::ShiftLeft,
+        Code::ShiftRight => IcedCode::ShiftRight,
+        Code::Space => IcedCode::Space,
+        Code::Tab => IcedCode::Tab,
+        Code::Convert => IcedCode::Convert,
+        Code::KanaMode => IcedCode::KanaMode,
+        Code::Lang1 => IcedCode::Lang1,
+        Code::Lang2 => IcedCode::Lang2,
+        Code::NonConvert => IcedCode::NonConvert,
+        Code::Delete => IcedCode::Delete,
+        Code::End => IcedCode::End,
+        Code::Help => IcedCode::Help,
+        Code::Home => IcedCode::Home,
+        Code::Insert => IcedCode::Insert,
+        Code::PageDown => IcedCode::PageDown,
+        Code::PageUp => IcedCode::PageUp,
+        Code::ArrowDown => IcedCode::ArrowDown,
+        Code::ArrowLeft => IcedCode::ArrowLeft,
+        Code::ArrowRight => IcedCode::ArrowRight,
+        Code::ArrowUp => IcedCode::ArrowUp,
+        Code::NumLock => IcedCode::NumLock,
+        Code::Numpad0 => IcedCode::Numpad0,
+        Code::Numpad1 => IcedCode::Numpad1,
+        Code::Numpad2 => IcedCode::Numpad2,
+        Code::Numpad3 => IcedCode::Numpad3,
+        Code::Numpad4 => IcedCode::Numpad4,
+        Code::Numpad5 => IcedCode::Numpad5,
+        Code::Numpad6 => IcedCode::Numpad6,
+        Code::Numpad7 => IcedCode::Numpad7,
+        Code::Numpad8 => IcedCode::Numpad8,
+        Code::Numpad9 => IcedCode::Numpad9,
+        Code::NumpadAdd => IcedCode::NumpadAdd,
+        Code::NumpadComma => IcedCode::NumpadComma,
+        Code::NumpadDecimal => IcedCode::NumpadDecimal,
+        Code::NumpadDivide => IcedCode::NumpadDivide,
+        Code::NumpadEnter => IcedCode::NumpadEnter,
+        Code::NumpadEqual => IcedCode::NumpadEqual,
+        Code::NumpadMultiply => IcedCode::NumpadMultiply,
+        Code::NumpadSubtract => IcedCode::NumpadSubtract,
+        Code::Escape => IcedCode::Escape,
+        Code::F1 => IcedCode::F1,
+        Code::F2 => IcedCode::F2,
+        Code::F3 => IcedCode::F3,
+        Code::F4 => IcedCode::F4,
+        Code::F5 => IcedCode::F5,
+        Code::F6 => IcedCode::F6,
+        Code::F7 => IcedCode::F7,
+        Code::F8 => IcedCode::F8,
+        Code::F9 => IcedCode::F9,
+        Code::F10 => IcedCode::F10,
+        Code::F11 => IcedCode::F11,
+        Code::F12 => IcedCode::F12,
+        Code::F13 => IcedCode::F13,
+        Code::F14 => IcedCode::F14,
+        Code::F15 => IcedCode::F15,
+        Code::F16 => IcedCode::F16,
+        Code::F17 => IcedCode::F17,
+        Code::F18 => IcedCode::F18,
+        Code::F19 => IcedCode::F19,
+        Code::F20 => IcedCode::F20,
+        Code::F21 => IcedCode::F21,
+        Code::F22 => IcedCode::F22,
+        Code::F23 => IcedCode::F23,
+        Code::F24 => IcedCode::F24,
+        Code::PrintScreen => IcedCode::PrintScreen,
+        Code::ScrollLock => IcedCode::ScrollLock,
+        Code::Pause => IcedCode::Pause,
+    }
+}
+
+impl TryFrom<KeyInput> for KeyEvent {
+    type Error = Error;
+
+    /// Convert [`KeyInput`] into iced's key press event. The physical key of the input is converted into the physical
+    /// key of the event. Keys with [`Mods::NUMPAD`] are located at [`Location::Numpad`]. See
+    /// [`Error::UnconvertibleInput`] for the common behavior of the conversions.
+    ///
+    /// Since the key input does not know the key before applying modifiers, both `key` and `modified_key` of the event
+    /// are set to the key of the input. `text` is set to the character of the key only when no modifier other than
+    /// <kbd>Shift</kbd> is pressed.
+    ///
+    /// [`Mods::META`], [`Mods::HYPER`] and [`Mods::ALTGR`] cannot be converted.
+    ///
+    /// ```
+    /// use keybinds::{Code, Key, KeyInput, Mods};
+    /// use iced::keyboard::{Event, Key as IcedKey, Modifiers};
+    /// use iced::keyboard::key::{Code as IcedCode, Physical};
+    ///
+    /// let input = KeyInput::with_code('x', Mods::CTRL, Code::KeyX);
+    /// let event = Event::try_from(input).unwrap();
+    /// let Event::KeyPressed { modified_key, modifiers, physical_key, .. } = &event else {
+    ///     panic!("not a key press event: {event:?}");
+    /// };
+    /// assert_eq!(modified_key, &IcedKey::Character("x".into()));
+    /// assert_eq!(modifiers, &Modifiers::CTRL);
+    /// assert_eq!(physical_key, &Physical::Code(IcedCode::KeyX));
+    ///
+    /// // The event is converted back into the same key input
+    /// assert_eq!(KeyInput::from(event), input);
+    ///
+    /// // Mouse buttons are not key events
+    /// assert!(Event::try_from(KeyInput::new(Key::MouseLeft, Mods::NONE)).is_err());
+    /// ```
+    fn try_from(input: KeyInput) -> Result<Self> {
+        to_key_event(input, Platform::current())
+    }
+}
+
+/// Convert [`KeyInput`] into iced's key press event on the given platform. See [`Platform`] for which to pass.
+///
+/// ```
+/// use iced::keyboard::{Event, Modifiers};
+/// use keybinds::iced::to_key_event;
+/// use keybinds::{KeyInput, Mods, Platform};
+///
+/// let input = KeyInput::new('s', Mods::MOD);
+/// let Event::KeyPressed { modifiers, .. } = to_key_event(input, Platform::MacOS).unwrap() else {
+///     unreachable!();
+/// };
+/// assert_eq!(modifiers, Modifiers::LOGO);
+///
+/// let Event::KeyPressed { modifiers, .. } = to_key_event(input, Platform::Linux).unwrap() else {
+///     unreachable!();
+/// };
+/// assert_eq!(modifiers, Modifiers::CTRL);
+/// ```
+pub fn to_key_event(input: KeyInput, platform: Platform) -> Result<KeyEvent> {
+    let unconvertible = || Error::UnconvertibleInput(input);
+    let mods = input.physical_mods().resolve(platform);
+    if mods.intersects(Mods::META | Mods::HYPER | Mods::ALTGR) {
+        return Err(unconvertible());
+    }
+
+    let mut modifiers = Modifiers::empty();
+    for (from, to) in [
+        (Mods::CTRL, Modifiers::CTRL),
+        (Mods::ALT, Modifiers::ALT),
+        (Mods::CMD, Modifiers::LOGO),
+        (Mods::WIN, Modifiers::LOGO),
+        (Mods::SHIFT, Modifiers::SHIFT),
+    ] {
+        if mods.contains(from) {
+            modifiers |= to;
+        }
+    }
+
+    let key = iced_key(input.key()).ok_or_else(unconvertible)?;
+    let physical_key = match input.code() {
+        Some(code) => Physical::Code(iced_code(code)),
+        None => Physical::Unidentified(NativeCode::Unidentified),
+    };
+    let location = if mods.contains(Mods::NUMPAD) {
+        Location::Numpad
+    } else {
+        Location::Standard
+    };
+    let text = match &key {
+        _ if modifiers.intersects(Modifiers::CTRL | Modifiers::ALT | Modifiers::LOGO) => None,
+        IcedKey::Character(s) => Some(s.clone()),
+        IcedKey::Named(Named::Space) => Some(" ".into()),
+        _ => None,
+    };
+
+    Ok(KeyEvent::KeyPressed {
+        key: key.clone(),
+        modified_key: key,
+        physical_key,
+        location,
+        modifiers,
+        text,
+    })
+}
+
+const SIDED_MODS: [Mods; 6] = [
+    Mods::LCTRL,
+    Mods::RCTRL,
+    Mods::LALT,
+    Mods::RALT,
+    Mods::LSHIFT,
+    Mods::RSHIFT,
+];
+
+/// Event converter to convert iced's key events into [`KeyInput`] with tracking the left and right modifier keys.
+///
+/// iced's [`Modifiers`] does not distinguish the left and right modifier keys. This converter remembers the
+/// [`Location`] of the modifier keys being pressed and fills the side-aware modifiers such as [`Mods::RALT`]. When
+/// side-aware modifiers are not necessary, `KeyInput::from` is sufficient.
+///
+/// ```
+/// use keybinds::{KeyInput, Mods, Code};
+/// use keybinds::iced::IcedEventConverter;
+/// use iced::keyboard::{Event, Key, Location, Modifiers};
+/// use iced::keyboard::key::{Named, Physical, Code as IcedCode};
+///
+/// let mut converter = IcedEventConverter::default();
+///
+/// // Right Alt key is pressed
+/// let event = Event::KeyPressed {
+///     key: Key::Named(Named::Alt),
+///     modified_key: Key::Named(Named::Alt),
+///     location: Location::Right,
+///     modifiers: Modifiers::ALT,
+///     // ...
+/// #   physical_key: Physical::Code(IcedCode::AltRight),
+/// #   text: None,
+/// };
+/// assert_eq!(converter.convert(&event).key(), keybinds::Key::Ignored);
+/// assert_eq!(converter.mods(), Mods::RALT);
+///
+/// // X key is pressed while the right Alt key is being pressed
+/// let event = Event::KeyPressed {
+///     key: Key::Character("x".into()),
+///     modified_key: Key::Character("x".into()),
+///     location: Location::Standard,
+///     modifiers: Modifiers::ALT,
+///     physical_key: Physical::Code(IcedCode::KeyX),
+///     // ...
+/// #   text: None,
+/// };
+/// assert_eq!(converter.convert(&event), KeyInput::with_code('x', Mods::RALT, Code::KeyX));
+/// ```
+///
+/// This converter also tracks <kbd>AltGr</kbd> key. While it is pressed, the modifiers are converted into
+/// [`Mods::ALTGR`] instead of <kbd>Ctrl</kbd> + <kbd>Alt</kbd> which some platforms like Windows report for
+/// <kbd>AltGr</kbd>.
+///
+/// [`IcedEventConverter::convert_event`] converts mouse events as well. Unlike `KeyInput::from`, the converted mouse
+/// inputs have the modifiers reported by the last key event so that key bindings like `Ctrl+MouseLeft` work.
+#[derive(Default)]
+pub struct IcedEventConverter {
+    pressed: Mods,
+    modifiers: Mods,
+    altgr: bool,
+    fallback: LatinFallback,
+}
+
+impl IcedEventConverter {
+    /// Returns the side-aware modifiers being pressed. [`Mods::ALTGR`] is included while <kbd>AltGr</kbd> key is
+    /// pressed. See [`IcedEventConverter`] for an example.
+    pub fn mods(&self) -> Mods {
+        if self.altgr {
+            self.pressed.with_altgr(Platform::current())
+        } else {
+            self.pressed
+        }
+    }
+
+    /// Set the policy to fall back from non-Latin logical keys to the Latin keys at the same physical key positions.
+    /// The default policy is [`LatinFallback::WithModifiers`]. See [`convert_with_fallback`] for more details.
+    ///
+    /// ```
+    /// use keybinds::LatinFallback;
+    /// use keybinds::iced::IcedEventConverter;
+    ///
+    /// let mut converter = IcedEventConverter::default();
+    /// assert_eq!(converter.latin_fallback(), LatinFallback::WithModifiers);
+    ///
+    /// converter.set_latin_fallback(LatinFallback::Never);
+    /// assert_eq!(converter.latin_fallback(), LatinFallback::Never);
+    /// ```
+    pub fn set_latin_fallback(&mut self, fallback: LatinFallback) {
+        self.fallback = fallback;
+    }
+
+    /// Returns the policy to fall back from non-Latin logical keys to the Latin keys. See
+    /// [`IcedEventConverter::set_latin_fallback`] for more details.
+    pub fn latin_fallback(&self) -> LatinFallback {
+        self.fallback
+    }
+
+    /// Convert iced's key events into [`KeyInput`] instances with tracking the left and right modifier keys. See the
+    /// document for [`IcedEventConverter`] for an example.
+    pub fn convert(&mut self, event: &KeyEvent) -> KeyInput {
+        match event {
+            KeyEvent::KeyPressed {
+                key,
+                location,
+                modifiers,
+                ..
+            } => {
+                self.modifiers = modifiers.into();
+                self.pressed |= Self::side(key, *location);
+                if *key == IcedKey::Named(Named::AltGraph) {
+                    self.altgr = true;
+                }
+            }
+            KeyEvent::KeyReleased {
+                key,
+                location,
+                modifiers,
+            } => {
+                self.modifiers = modifiers.into();
+                if *key == IcedKey::Named(Named::AltGraph) {
+                    self.altgr = false;
+                }
+                let released = Self::side(key, *location);
+                self.pressed = SIDED_MODS
+                    .into_iter()
+                    .filter(|&m| m != released && self.pressed.contains(m))
+                    .collect();
+            }
+            KeyEvent::ModifiersChanged(modifiers) => {
+                // Forget the modifier keys released while the window was not focused
+                let mods = Mods::from(modifiers);
+                self.modifiers = mods;
+                self.pressed = SIDED_MODS
+                    .into_iter()
+                    .filter(|&m| self.pressed.contains(m) && mods.intersects(m))
+                    .collect();
+            }
+        }
+
+        let input = convert_with_fallback(event, LatinFallback::Never);
+        let mods = self.with_sides(input.physical_mods());
+        let input = match input.code() {
+            Some(code) => KeyInput::with_code(input.key(), mods, code),
+            None => KeyInput::new(input.key(), mods),
+        };
+        self.fallback.apply(input)
+    }
+
+    /// Convert iced's events into [`KeyInput`] instances. Key events are converted by [`IcedEventConverter::convert`].
+    /// Mouse events are converted with the current modifiers state. Other events are converted into `Key::Ignored`.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Mods};
+    /// use keybinds::iced::IcedEventConverter;
+    /// use iced::{keyboard, mouse, Event};
+    ///
+    /// let mut converter = IcedEventConverter::default();
+    ///
+    /// // Ctrl key is pressed
+    /// let event = Event::Keyboard(keyboard::Event::ModifiersChanged(keyboard::Modifiers::CTRL));
+    /// assert_eq!(converter.convert_event(&event), KeyInput::from(Key::Ignored));
+    ///
+    /// // Left mouse button is clicked while Ctrl key is being pressed
+    /// let event = Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+    /// assert_eq!(converter.convert_event(&event), KeyInput::new(Key::MouseLeft, Mods::CTRL));
+    /// ```
+    pub fn convert_event(&mut self, event: &Event) -> KeyInput {
+        match event {
+            Event::Keyboard(event) => self.convert(event),
+            Event::Mouse(event) => match KeyInput::from(event).key() {
+                Key::Ignored => Key::Ignored.into(),
+                key => KeyInput::new(key, self.with_sides(self.modifiers)),
+            },
+            _ => Key::Ignored.into(),
+        }
+    }
+
+    fn with_sides(&self, mods: Mods) -> Mods {
+        let mut mods = mods
+            | SIDED_MODS
+                .into_iter()
+                .filter(|&m| self.pressed.contains(m) && mods.intersects(m))
+                .collect();
+        if self.altgr {
+            mods = mods.with_altgr(Platform::current());
+        }
+        mods
+    }
+
+    fn side(key: &IcedKey, location: Location) -> Mods {
+        match (key, location) {
+            (IcedKey::Named(Named::Control), Location::Left) => Mods::LCTRL,
+            (IcedKey::Named(Named::Control), Location::Right) => Mods::RCTRL,
+            (IcedKey::Named(Named::Alt), Location::Left) => Mods::LALT,
+            (IcedKey::Named(Named::Alt), Location::Right) => Mods::RALT,
+            (IcedKey::Named(Named::Shift), Location::Left) => Mods::LSHIFT,
+            (IcedKey::Named(Named::Shift), Location::Right) => Mods::RSHIFT,
+            _ => Mods::NONE,
+        }
+    }
+}
+
+/// Event produced by the subscription created with [`subscription`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum KeybindsEvent<A> {
+    /// The action dispatched by the key bindings.
+    Action(A),
+    /// The key inputs of the ongoing key sequence which matched the key bindings so far. This event is produced when
+    /// the ongoing key sequence is changed. The vector is empty when the key sequence is completed, cancelled, or
+    /// expired by the timeout of the key bindings.
+    Pending(Vec<KeyInput>),
+}
+
+// State to dispatch actions inside the subscription
+struct Dispatcher<A> {
+    keybinds: Keybinds<A>,
+    converter: IcedEventConverter,
+    deadline: Option<Instant>,
+}
+
+impl<A: Clone> Dispatcher<A> {
+    fn new(keybinds: Keybinds<A>) -> Self {
+        Self {
+            keybinds,
+            converter: IcedEventConverter::default(),
+            deadline: None,
+        }
+    }
+
+    fn handle(&mut self, event: &Event) -> Vec<KeybindsEvent<A>> {
+        // The timer may not fire before the next event on some platforms
+        let mut events = self.expire(Instant::now());
+        let input = self.converter.convert_event(event);
+        if input.key() == Key::Ignored {
+            return events;
+        }
+
+        let was_ongoing = !self.keybinds.ongoing_inputs().is_empty();
+        if let Some(action) = self.keybinds.dispatch(input) {
+            events.push(KeybindsEvent::Action(action.clone()));
+        }
+        let ongoing = self.keybinds.ongoing_inputs();
+        if was_ongoing || !ongoing.is_empty() {
+            events.push(KeybindsEvent::Pending(ongoing.to_vec()));
+        }
+        self.deadline = (!ongoing.is_empty()).then(|| Instant::now() + self.keybinds.timeout());
+        events
+    }
+
+    // Cancel the ongoing key sequence when its timeout has passed at `now`.
+    fn expire(&mut self, now: Instant) -> Vec<KeybindsEvent<A>> {
+        match self.deadline {
+            Some(deadline) if deadline <= now => {
+                self.deadline = None;
+                self.keybinds.reset();
+                vec![KeybindsEvent::Pending(vec![])]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+// Future which is resolved after the deadline. A thread is used since the subscription cannot depend on the async
+// runtime chosen by the application. Threads are not available on WebAssembly so the timer never fires there and the
+// timeout is detected on the next event instead.
+struct Timer {
+    deadline: Instant,
+    #[cfg(not(target_arch = "wasm32"))]
+    fired: oneshot::Receiver<()>,
+}
+
+impl Timer {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn new(deadline: Instant) -> Self {
+        let (tx, fired) = oneshot::channel();
+        std::thread::spawn(move || {
+            std::thread::sleep(deadline.saturating_duration_since(Instant::now()));
+            let _ = tx.send(());
+        });
+        Self { deadline, fired }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn new(deadline: Instant) -> Self {
+        Self { deadline }
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.fired.poll_unpin(cx).map(|_| ())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        Poll::Pending
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type BoxStream<T> = stream::BoxStream<'static, T>;
+#[cfg(target_arch = "wasm32")]
+type BoxStream<T> = stream::LocalBoxStream<'static, T>;
+
+struct KeybindsRecipe<I, A, M> {
+    id: I,
+    keybinds: Arc<Keybinds<A>>,
+    f: fn(KeybindsEvent<A>) -> M,
+}
+
+impl<I, A, M> Recipe for KeybindsRecipe<I, A, M>
+where
+    I: Hash + 'static,
+    A: Clone + Send + 'static,
+    M: Send + 'static,
+{
+    type Output = M;
+
+    fn hash(&self, state: &mut Hasher) {
+        TypeId::of::<Self>().hash(state);
+        self.id.hash(state);
+        self.f.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<M> {
+        let Self { keybinds, f, .. } = *self;
+        let state = (Dispatcher::new(Arc::unwrap_or_clone(keybinds)), input, None);
+        let events = stream::unfold(state, |(mut dispatcher, mut input, timer)| async move {
+            let (event, timer) = match dispatcher.deadline {
+                Some(deadline) => {
+                    // Reuse the timer until the deadline is changed not to spawn a thread on every event
+                    let timer = match timer {
+                        Some(timer @ Timer { deadline: d, .. }) if d == deadline => timer,
+                        _ => Timer::new(deadline),
+                    };
+                    match future::select(input.next(), timer).await {
+                        Either::Left((event, timer)) => (event, Some(timer)),
+                        Either::Right(((), _)) => {
+                            let events = dispatcher.expire(Instant::now());
+                            return Some((events, (dispatcher, input, None)));
+                        }
+                    }
+                }
+                None => (input.next().await, None),
+            };
+            let events = match event? {
+                // Events captured by widgets such as text inputs are not dispatched like `keyboard::on_key_press`
+                iced_subscription::Event::Interaction {
+                    event,
+                    status: Status::Ignored,
+                    ..
+                } => dispatcher.handle(&event),
+                _ => vec![],
+            };
+            Some((events, (dispatcher, input, timer)))
+        });
+        Box::pin(events.flat_map(move |events| stream::iter(events.into_iter().map(f))))
+    }
+}
+
+/// Create [`iced::Subscription`] to dispatch actions by the key bindings. The subscription converts key and mouse
+/// events with [`IcedEventConverter`], dispatches actions with the key bindings, and produces the message converted
+/// from [`KeybindsEvent`] by `f`. The changes of the ongoing key sequence are also reported as
+/// [`KeybindsEvent::Pending`] so that the application can display them. Events captured by widgets such as typing in
+/// a focused text input are not dispatched as well as [`iced::keyboard::on_key_press`].
+///
+/// The key bindings are shared via [`Arc`] and the subscription is identified by `id` like
+/// [`Subscription::run_with_id`]. Though iced calls `subscription` of the application after every update, only the
+/// [`Arc`] is cloned each time. The key bindings are cloned once when the subscription starts so mutations of the key
+/// bindings in the application state are not reflected in the running subscription. For example,
+/// the ongoing key sequence is not shared and [`Keybinds::reset`] on the application state does nothing to the
+/// subscription. To apply the modified key bindings or to reset the ongoing key sequence, change `id` so that the
+/// subscription is restarted.
+///
+/// When the ongoing key sequence expires by the timeout of the key bindings, `KeybindsEvent::Pending(vec![])` is
+/// produced. A thread is spawned to wait for the timeout on each key input of the sequence since the subscription does
+/// not depend on any async runtime. On WebAssembly, the expiration is reported on the next event instead.
+///
+/// ```no_run
+/// use keybinds::Keybinds;
+/// use keybinds::iced::{subscription, KeybindsEvent};
+/// use iced::{Element, Subscription, Task};
+/// use std::sync::Arc;
+///
+/// // Actions dispatched by the key bindings
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     SayHello,
+///     Exit,
+/// }
+///
+/// #[derive(Debug)]
+/// enum Message {
+///     Keybinds(KeybindsEvent<Action>),
+/// }
+///
+/// struct App {
+///     keybinds: Arc<Keybinds<Action>>,
+///     // Incremented when the key bindings are modified to restart the subscription
+///     keybinds_version: usize,
+///     pending: String,
+/// }
+///
+/// impl Default for App {
+///     fn default() -> Self {
+///         let mut keybinds = Keybinds::default();
+///         keybinds.bind("H e l l o", Action::SayHello).unwrap();
+///         keybinds.bind("Mod+x Mod+c", Action::Exit).unwrap();
+///         Self { keybinds: Arc::new(keybinds), keybinds_version: 0, pending: String::new() }
+///     }
+/// }
+///
+/// impl App {
+///     fn update(&mut self, message: Message) -> Task<Message> {
+///         match message {
+///             Message::Keybinds(KeybindsEvent::Action(action)) => match action {
+///                 Action::SayHello => println!("Hello!"),
+///                 Action::Exit => return iced::exit(),
+///             },
+///             Message::Keybinds(KeybindsEvent::Pending(inputs)) => {
+///                 // Remember the ongoing key sequence like "Ctrl+x" to display it
+///                 self.pending = inputs.iter().map(|i| i.to_string()).collect::<Vec<_>>().join(" ");
+///             }
+///             // Handle other messages of your application...
+///         }
+///         Task::none()
+///     }
+///
+///     fn view(&self) -> Element<Message> {
+///         todo!("TODO: Build UI of your application")
+///     }
+///
+///     fn subscription(&self) -> Subscription<Message> {
+///         // Receive the dispatched actions as messages
+///         subscription(self.keybinds_version, self.keybinds.clone(), Message::Keybinds)
+///     }
+/// }
+///
+/// iced::application("My App", App::update, App::view)
+///     .subscription(App::subscription)
+///     .run()
+///     .unwrap();
+/// ```
+pub fn subscription<I, A, M>(
+    id: I,
+    keybinds: Arc<Keybinds<A>>,
+    f: fn(KeybindsEvent<A>) -> M,
+) -> Subscription<M>
+where
+    I: Hash + 'static,
+    A: Clone + Send + 'static,
+    M: Send + 'static,
+{
+    iced_subscription::from_recipe(KeybindsRecipe { id, keybinds, f })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use iced::keyboard::key::{Code, Physical};
-    use iced::keyboard::Location;
+    use crate::KeyStr;
     use iced::window::Event as WindowEvent;
+    use std::thread::sleep;
+    use std::time::Duration;
 
     #[test]
     fn key_to_key() {
         assert_eq!(Key::from(IcedKey::Character("+".into())), Key::Char('+'));
+        // Text not used in key bindings is not interned
+        assert_eq!(
+            Key::from(IcedKey::Character("日本語で".into())),
+            Key::Unidentified,
+        );
+        let s = KeyStr::new("日本語").unwrap();
+        assert_eq!(Key::from(IcedKey::Character("日本語".into())), Key::Str(s));
         assert_eq!(Key::from(IcedKey::Named(Named::Space)), Key::Char(' '));
         assert_eq!(Key::from(IcedKey::Named(Named::ArrowUp)), Key::Up);
+        assert_eq!(
+            Key::from(IcedKey::Named(Named::LaunchMail)),
+            Key::LaunchMail
+        );
+        assert_eq!(Key::from(IcedKey::Named(Named::MediaRecord)), Key::Record);
+        assert_eq!(
+            Key::from(IcedKey::Named(Named::BrightnessUp)),
+            Key::BrightnessUp
+        );
         assert_eq!(Key::from(IcedKey::Named(Named::Control)), Key::Ignored);
         assert_eq!(Key::from(IcedKey::Unidentified), Key::Unidentified);
         assert_eq!(Key::from(IcedKey::Named(Named::Compose)), Key::Unidentified);
     }
 
+    #[test]
+    fn physical_to_key() {
+        assert_eq!(
+            Key::from(Physical::Code(IcedCode::KeyW)),
+            Key::Physical(Code::KeyW),
+        );
+        assert_eq!(
+            Key::from(Physical::Code(IcedCode::SuperLeft)),
+            Key::Physical(Code::MetaLeft),
+        );
+        assert_eq!(
+            Key::from(Physical::Code(IcedCode::Numpad1)),
+            Key::Physical(Code::Numpad1),
+        );
+        assert_eq!(Key::from(Physical::Code(IcedCode::Fn)), Key::Unidentified);
+    }
+
     #[test]
     fn modifiers_to_mods() {
         assert_eq!(Mods::from(Modifiers::empty()), Mods::NONE);
@@ -317,7 +1541,7 @@ mod tests {
             KeyInput::from(KeyEvent::KeyPressed {
                 key: IcedKey::Character("x".into()),
                 modified_key: IcedKey::Character("X".into()),
-                physical_key: Physical::Code(Code::KeyX),
+                physical_key: Physical::Code(IcedCode::KeyX),
                 location: Location::Standard,
                 modifiers: Modifiers::SHIFT,
                 text: Some("X".into()),
@@ -334,22 +1558,601 @@ mod tests {
         );
     }
 
+    #[test]
+    fn key_event_latin_fallback() {
+        let event = KeyEvent::KeyPressed {
+            key: IcedKey::Character("ψ".into()),
+            modified_key: IcedKey::Character("ψ".into()),
+            physical_key: Physical::Code(IcedCode::KeyC),
+            location: Location::Standard,
+            modifiers: Modifiers::ALT,
+            text: None,
+        };
+        assert_eq!(
+            KeyInput::from(&event),
+            KeyInput::with_code('c', Mods::ALT, Code::KeyC),
+        );
+        for (fallback, expected) in [
+            (LatinFallback::Never, 'ψ'),
+            (LatinFallback::WithModifiers, 'c'),
+            (LatinFallback::Always, 'c'),
+        ] {
+            assert_eq!(
+                convert_with_fallback(&event, fallback),
+                KeyInput::with_code(expected, Mods::ALT, Code::KeyC),
+                "fallback={fallback:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn input_to_key_event() {
+        let event = KeyEvent::try_from(KeyInput::new('A', Mods::NONE)).unwrap();
+        assert_eq!(
+            event,
+            KeyEvent::KeyPressed {
+                key: IcedKey::Character("A".into()),
+                modified_key: IcedKey::Character("A".into()),
+                physical_key: Physical::Unidentified(NativeCode::Unidentified),
+                location: Location::Standard,
+                modifiers: Modifiers::empty(),
+                text: Some("A".into()),
+            },
+        );
+
+        let event = KeyEvent::try_from(KeyInput::with_code('A', Mods::SHIFT, Code::KeyA)).unwrap();
+        assert_eq!(
+            event,
+            KeyEvent::KeyPressed {
+                key: IcedKey::Character("A".into()),
+                modified_key: IcedKey::Character("A".into()),
+                physical_key: Physical::Code(IcedCode::KeyA),
+                location: Location::Standard,
+                modifiers: Modifiers::SHIFT,
+                text: Some("A".into()),
+            },
+        );
+
+        let event =
+            KeyEvent::try_from(KeyInput::with_code('a', Mods::CTRL | Mods::ALT, Code::KeyA))
+                .unwrap();
+        assert_eq!(
+            event,
+            KeyEvent::KeyPressed {
+                key: IcedKey::Character("a".into()),
+                modified_key: IcedKey::Character("a".into()),
+                physical_key: Physical::Code(IcedCode::KeyA),
+                location: Location::Standard,
+                modifiers: Modifiers::CTRL | Modifiers::ALT,
+                text: None,
+            },
+        );
+
+        let event = KeyEvent::try_from(KeyInput::new(' ', Mods::NONE)).unwrap();
+        let KeyEvent::KeyPressed { key, text, .. } = event else {
+            panic!("{event:?}");
+        };
+        assert_eq!(key, IcedKey::Named(Named::Space));
+        assert_eq!(text, Some(" ".into()));
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Enter, Mods::NUMPAD)).unwrap();
+        let KeyEvent::KeyPressed {
+            key,
+            location,
+            text,
+            ..
+        } = event
+        else {
+            panic!("{event:?}");
+        };
+        assert_eq!(key, IcedKey::Named(Named::Enter));
+        assert_eq!(location, Location::Numpad);
+        assert_eq!(text, None);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Up, Mods::SUPER)).unwrap();
+        let KeyEvent::KeyPressed { modifiers, .. } = event else {
+            panic!("{event:?}");
+        };
+        assert_eq!(modifiers, Modifiers::LOGO);
+    }
+
+    #[test]
+    fn input_to_key_event_on_platform() {
+        for (platform, expected) in [
+            (Platform::MacOS, Modifiers::LOGO),
+            (Platform::Linux, Modifiers::CTRL),
+            (Platform::Windows, Modifiers::CTRL),
+        ] {
+            let event = to_key_event(KeyInput::new('a', Mods::MOD), platform).unwrap();
+            let KeyEvent::KeyPressed {
+                modifiers, text, ..
+            } = event
+            else {
+                panic!("not a key press event: {event:?}");
+            };
+            assert_eq!(modifiers, expected, "{platform:?}");
+            assert_eq!(text, None, "{platform:?}");
+        }
+        let input = KeyInput::new('a', Mods::MOD);
+        assert_eq!(
+            KeyEvent::try_from(input),
+            to_key_event(input, Platform::current()),
+        );
+    }
+
+    #[test]
+    fn unconvertible_input_to_key_event() {
+        for input in [
+            KeyInput::new(Key::MouseLeft, Mods::NONE),
+            KeyInput::new(Key::WheelUp, Mods::CTRL),
+            KeyInput::new(Key::Physical(Code::KeyA), Mods::NONE),
+            KeyInput::new(Key::Ignored, Mods::NONE),
+            KeyInput::new(Key::Unidentified, Mods::NONE),
+            KeyInput::new('x', Mods::META),
+            KeyInput::new('x', Mods::HYPER),
+            KeyInput::new('@', Mods::ALTGR),
+        ] {
+            assert_eq!(
+                KeyEvent::try_from(input),
+                Err(Error::UnconvertibleInput(input)),
+                "{input:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn input_key_event_round_trip() {
+        for input in [
+            KeyInput::new('a', Mods::NONE),
+            KeyInput::new('A', Mods::CTRL),
+            KeyInput::new(' ', Mods::CTRL | Mods::ALT | Mods::SUPER),
+            KeyInput::new(Key::Str(KeyStr::new("日本語").unwrap()), Mods::NONE),
+            KeyInput::new('あ', Mods::NONE),
+            KeyInput::new('1', Mods::NUMPAD),
+            KeyInput::new(Key::Enter, Mods::NONE),
+            KeyInput::new(Key::Tab, Mods::SHIFT),
+            KeyInput::new(Key::F1, Mods::NONE),
+            KeyInput::new(Key::F35, Mods::CTRL),
+            KeyInput::new(Key::Menu, Mods::NONE),
+            KeyInput::new(Key::LaunchMail, Mods::NONE),
+            KeyInput::with_code('x', Mods::CTRL, Code::KeyX),
+            KeyInput::with_code('X', Mods::CTRL | Mods::SHIFT, Code::KeyX),
+            KeyInput::with_code(Key::Left, Mods::NONE, Code::ArrowLeft),
+            KeyInput::with_code(Key::Enter, Mods::NUMPAD, Code::NumpadEnter),
+        ] {
+            let event = KeyEvent::try_from(input).unwrap();
+            assert_eq!(KeyInput::from(event), input, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn converter_side_aware_mods() {
+        fn pressed(key: IcedKey, location: Location, modifiers: Modifiers) -> KeyEvent {
+            KeyEvent::KeyPressed {
+                key: key.clone(),
+                modified_key: key,
+                physical_key: Physical::Unidentified(iced::keyboard::key::NativeCode::Unidentified),
+                location,
+                modifiers,
+                text: None,
+            }
+        }
+        fn released(key: IcedKey, location: Location, modifiers: Modifiers) -> KeyEvent {
+            KeyEvent::KeyReleased {
+                key,
+                location,
+                modifiers,
+            }
+        }
+        let ctrl = IcedKey::Named(Named::Control);
+        let shift = IcedKey::Named(Named::Shift);
+        let x = IcedKey::Character("x".into());
+
+        let mut conv = IcedEventConverter::default();
+        assert_eq!(conv.mods(), Mods::NONE);
+
+        conv.convert(&pressed(ctrl.clone(), Location::Left, Modifiers::CTRL));
+        assert_eq!(conv.mods(), Mods::LCTRL);
+        assert_eq!(
+            conv.convert(&pressed(x.clone(), Location::Standard, Modifiers::CTRL)),
+            KeyInput::new('x', Mods::LCTRL),
+        );
+
+        conv.convert(&pressed(ctrl.clone(), Location::Right, Modifiers::CTRL));
+        assert_eq!(conv.mods(), Mods::LCTRL | Mods::RCTRL);
+        conv.convert(&released(ctrl.clone(), Location::Left, Modifiers::CTRL));
+        assert_eq!(conv.mods(), Mods::RCTRL);
+
+        conv.convert(&pressed(
+            shift.clone(),
+            Location::Right,
+            Modifiers::CTRL | Modifiers::SHIFT,
+        ));
+        assert_eq!(
+            conv.convert(&pressed(
+                IcedKey::Named(Named::Enter),
+                Location::Standard,
+                Modifiers::CTRL | Modifiers::SHIFT
+            )),
+            KeyInput::new(Key::Enter, Mods::RCTRL | Mods::RSHIFT),
+        );
+        // Shift is removed from the unnamed key
+        assert_eq!(
+            conv.convert(&pressed(
+                x.clone(),
+                Location::Standard,
+                Modifiers::CTRL | Modifiers::SHIFT
+            )),
+            KeyInput::new('x', Mods::RCTRL),
+        );
+
+        conv.convert(&KeyEvent::ModifiersChanged(Modifiers::SHIFT));
+        assert_eq!(conv.mods(), Mods::RSHIFT);
+        conv.convert(&released(shift, Location::Right, Modifiers::empty()));
+        assert_eq!(conv.mods(), Mods::NONE);
+        assert_eq!(
+            conv.convert(&pressed(x, Location::Standard, Modifiers::empty())),
+            KeyInput::new('x', Mods::NONE),
+        );
+    }
+
+    #[test]
+    fn converter_altgr() {
+        let altgr = IcedKey::Named(Named::AltGraph);
+        let q = IcedKey::Character("@".into());
+        let mut conv = IcedEventConverter::default();
+
+        // Windows reports AltGr as Ctrl+Alt and other platforms report it as Alt
+        let modifiers = if cfg!(windows) {
+            Modifiers::CTRL | Modifiers::ALT
+        } else {
+            Modifiers::ALT
+        };
+        let event = KeyEvent::KeyPressed {
+            key: altgr.clone(),
+            modified_key: altgr.clone(),
+            physical_key: Physical::Code(IcedCode::AltRight),
+            location: Location::Right,
+            modifiers,
+            text: None,
+        };
+        assert_eq!(conv.convert(&event).key(), Key::Ignored);
+        assert_eq!(conv.mods(), Mods::ALTGR);
+
+        let event = KeyEvent::KeyPressed {
+            key: IcedKey::Character("q".into()),
+            modified_key: q,
+            physical_key: Physical::Code(IcedCode::KeyQ),
+            location: Location::Standard,
+            modifiers,
+            text: Some("@".into()),
+        };
+        assert_eq!(
+            conv.convert(&event),
+            KeyInput::with_code('@', Mods::ALTGR, Code::KeyQ),
+        );
+
+        conv.convert(&KeyEvent::KeyReleased {
+            key: altgr,
+            location: Location::Right,
+            modifiers: Modifiers::empty(),
+        });
+        assert_eq!(conv.mods(), Mods::NONE);
+        assert_eq!(
+            conv.convert(&event),
+            KeyInput::with_code('@', Mods::from(modifiers), Code::KeyQ),
+        );
+    }
+
+    #[test]
+    fn numpad_key_event() {
+        let event = KeyEvent::KeyPressed {
+            key: IcedKey::Named(Named::Enter),
+            modified_key: IcedKey::Named(Named::Enter),
+            physical_key: Physical::Code(IcedCode::NumpadEnter),
+            location: Location::Numpad,
+            modifiers: Modifiers::CTRL,
+            text: None,
+        };
+        assert_eq!(
+            KeyInput::from(&event),
+            KeyInput::with_code(Key::Enter, Mods::CTRL | Mods::NUMPAD, Code::NumpadEnter),
+        );
+
+        let event = KeyEvent::KeyPressed {
+            key: IcedKey::Character("1".into()),
+            modified_key: IcedKey::Character("1".into()),
+            physical_key: Physical::Code(IcedCode::Digit1),
+            location: Location::Standard,
+            modifiers: Modifiers::empty(),
+            text: Some("1".into()),
+        };
+        assert_eq!(
+            KeyInput::from(&event),
+            KeyInput::with_code('1', Mods::NONE, Code::Digit1),
+        );
+    }
+
     #[test]
     fn event_to_input() {
         assert_eq!(
             KeyInput::from(Event::Keyboard(KeyEvent::KeyPressed {
                 key: IcedKey::Character("x".into()),
                 modified_key: IcedKey::Character("X".into()),
-                physical_key: Physical::Code(Code::KeyX),
+                physical_key: Physical::Code(IcedCode::KeyX),
                 location: Location::Standard,
                 modifiers: Modifiers::SHIFT,
                 text: Some("X".into()),
             })),
             KeyInput::from(Key::Char('X')),
         );
+        assert_eq!(
+            KeyInput::from(Event::Mouse(MouseEvent::ButtonPressed(Button::Right))),
+            KeyInput::from(Key::MouseRight),
+        );
         assert_eq!(
             KeyInput::from(Event::Window(WindowEvent::Closed)),
             KeyInput::from(Key::Ignored),
         );
     }
+
+    #[test]
+    fn mouse_event_to_input() {
+        for (button, key) in [
+            (Button::Left, Key::MouseLeft),
+            (Button::Right, Key::MouseRight),
+            (Button::Middle, Key::MouseMiddle),
+            (Button::Back, Key::MouseBack),
+            (Button::Forward, Key::MouseForward),
+            (Button::Other(9), Key::Unidentified),
+        ] {
+            assert_eq!(
+                KeyInput::from(MouseEvent::ButtonPressed(button)),
+                KeyInput::from(key),
+                "button={button:?}",
+            );
+        }
+        for (delta, key) in [
+            (ScrollDelta::Lines { x: 0.0, y: 1.0 }, Key::WheelUp),
+            (ScrollDelta::Lines { x: 0.0, y: -1.0 }, Key::WheelDown),
+            (ScrollDelta::Lines { x: 1.0, y: 0.0 }, Key::WheelLeft),
+            (ScrollDelta::Lines { x: -1.0, y: 0.0 }, Key::WheelRight),
+            (ScrollDelta::Pixels { x: 3.0, y: -8.0 }, Key::WheelDown),
+            (ScrollDelta::Pixels { x: 0.0, y: 0.0 }, Key::Ignored),
+        ] {
+            assert_eq!(
+                KeyInput::from(MouseEvent::WheelScrolled { delta }),
+                KeyInput::from(key),
+                "delta={delta:?}",
+            );
+        }
+        for event in [
+            MouseEvent::ButtonReleased(Button::Left),
+            MouseEvent::CursorMoved {
+                position: iced::Point::ORIGIN,
+            },
+            MouseEvent::CursorLeft,
+        ] {
+            assert_eq!(KeyInput::from(&event), KeyInput::from(Key::Ignored));
+        }
+    }
+
+    #[test]
+    fn converter_mouse() {
+        let mut conv = IcedEventConverter::default();
+        let click = Event::Mouse(MouseEvent::ButtonPressed(Button::Left));
+        assert_eq!(conv.convert_event(&click), KeyInput::from(Key::MouseLeft));
+
+        // Right Ctrl key is pressed
+        let event = Event::Keyboard(KeyEvent::KeyPressed {
+            key: IcedKey::Named(Named::Control),
+            modified_key: IcedKey::Named(Named::Control),
+            physical_key: Physical::Code(IcedCode::ControlRight),
+            location: Location::Right,
+            modifiers: Modifiers::CTRL,
+            text: None,
+        });
+        assert_eq!(conv.convert_event(&event).key(), Key::Ignored);
+        assert_eq!(
+            conv.convert_event(&click),
+            KeyInput::new(Key::MouseLeft, Mods::RCTRL),
+        );
+
+        let wheel = Event::Mouse(MouseEvent::WheelScrolled {
+            delta: ScrollDelta::Lines { x: 0.0, y: 1.0 },
+        });
+        assert_eq!(
+            conv.convert_event(&wheel),
+            KeyInput::new(Key::WheelUp, Mods::RCTRL),
+        );
+
+        conv.convert_event(&Event::Keyboard(KeyEvent::ModifiersChanged(
+            Modifiers::empty(),
+        )));
+        assert_eq!(conv.convert_event(&wheel), KeyInput::from(Key::WheelUp));
+        assert_eq!(
+            conv.convert_event(&Event::Mouse(MouseEvent::ButtonReleased(Button::Left))),
+            KeyInput::from(Key::Ignored),
+        );
+    }
+
+    #[test]
+    fn dispatch_in_subscription() {
+        #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+        enum A {
+            Foo,
+            Bar,
+        }
+
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+x Ctrl+c", A::Foo).unwrap();
+        keybinds.bind("a", A::Bar).unwrap();
+        let mut dispatcher = Dispatcher::new(keybinds);
+
+        let press = |c: &str, modifiers| {
+            Event::Keyboard(KeyEvent::KeyPressed {
+                key: IcedKey::Character(c.into()),
+                modified_key: IcedKey::Character(c.into()),
+                physical_key: Physical::Unidentified(NativeCode::Unidentified),
+                location: Location::Standard,
+                modifiers,
+                text: None,
+            })
+        };
+        let ctrl_x = KeyInput::new('x', Mods::CTRL);
+
+        assert_eq!(
+            dispatcher.handle(&press("a", Modifiers::empty())),
+            [KeybindsEvent::Action(A::Bar)]
+        );
+        assert_eq!(
+            dispatcher.handle(&press("x", Modifiers::CTRL)),
+            [KeybindsEvent::Pending(vec![ctrl_x])],
+        );
+        assert_eq!(
+            dispatcher.handle(&press("c", Modifiers::CTRL)),
+            [
+                KeybindsEvent::Action(A::Foo),
+                KeybindsEvent::Pending(vec![])
+            ],
+        );
+
+        // Cancel the ongoing key sequence
+        assert_eq!(
+            dispatcher.handle(&press("x", Modifiers::CTRL)),
+            [KeybindsEvent::Pending(vec![ctrl_x])],
+        );
+        assert_eq!(
+            dispatcher.handle(&press("b", Modifiers::empty())),
+            [KeybindsEvent::Pending(vec![])],
+        );
+
+        // Unmatched inputs and ignored events produce nothing
+        assert_eq!(dispatcher.handle(&press("b", Modifiers::empty())), []);
+        let modifier = Event::Keyboard(KeyEvent::KeyPressed {
+            key: IcedKey::Named(Named::Control),
+            modified_key: IcedKey::Named(Named::Control),
+            physical_key: Physical::Code(IcedCode::ControlLeft),
+            location: Location::Left,
+            modifiers: Modifiers::CTRL,
+            text: None,
+        });
+        assert_eq!(dispatcher.handle(&modifier), []);
+        assert_eq!(dispatcher.handle(&Event::Window(WindowEvent::Focused)), []);
+
+        // The ongoing key sequence expires by the timeout
+        dispatcher.keybinds.set_timeout(Duration::from_millis(10));
+        let lctrl_x = KeyInput::new('x', Mods::CTRL | Mods::LCTRL); // Left Ctrl key is being pressed
+        assert_eq!(
+            dispatcher.handle(&press("x", Modifiers::CTRL)),
+            [KeybindsEvent::Pending(vec![lctrl_x])],
+        );
+        assert_eq!(dispatcher.expire(Instant::now()), []);
+        sleep(Duration::from_millis(50));
+        assert_eq!(
+            dispatcher.handle(&Event::Window(WindowEvent::Focused)),
+            [KeybindsEvent::Pending(vec![])],
+        );
+        assert!(!dispatcher.keybinds.is_ongoing());
+        assert_eq!(dispatcher.expire(Instant::now()), []);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn expire_ongoing_sequence_in_subscription() {
+        use iced::futures::executor::block_on;
+
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+x Ctrl+c", 0).unwrap();
+        keybinds.set_timeout(Duration::from_millis(10));
+        let recipe = iced_subscription::into_recipes(subscription(0, Arc::new(keybinds), |e| e))
+            .pop()
+            .unwrap();
+
+        let event = iced_subscription::Event::Interaction {
+            window: iced::window::Id::unique(),
+            event: Event::Keyboard(KeyEvent::KeyPressed {
+                key: IcedKey::Character("x".into()),
+                modified_key: IcedKey::Character("x".into()),
+                physical_key: Physical::Code(IcedCode::KeyX),
+                location: Location::Standard,
+                modifiers: Modifiers::CTRL,
+                text: None,
+            }),
+            status: Status::Ignored,
+        };
+        // Only events unrelated to the key bindings follow the first key input. The timer keeps running across them
+        let focused = || iced_subscription::Event::Interaction {
+            window: iced::window::Id::unique(),
+            event: Event::Window(WindowEvent::Focused),
+            status: Status::Ignored,
+        };
+        let input = stream::iter([event, focused(), focused(), focused()])
+            .chain(stream::pending())
+            .boxed();
+        let events: Vec<_> = block_on(recipe.stream(input).take(2).collect());
+        let ctrl_x = KeyInput::with_code('x', Mods::CTRL, Code::KeyX);
+        assert_eq!(
+            events,
+            [
+                KeybindsEvent::Pending(vec![ctrl_x]),
+                KeybindsEvent::Pending(vec![]),
+            ],
+        );
+    }
+
+    #[test]
+    fn ignore_captured_events_in_subscription() {
+        use iced::futures::executor::block_on;
+
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("a", 0).unwrap();
+        let recipe = iced_subscription::into_recipes(subscription(0, Arc::new(keybinds), |e| e))
+            .pop()
+            .unwrap();
+
+        let window = iced::window::Id::unique();
+        let event = |status| iced_subscription::Event::Interaction {
+            window,
+            event: Event::Keyboard(KeyEvent::KeyPressed {
+                key: IcedKey::Character("a".into()),
+                modified_key: IcedKey::Character("a".into()),
+                physical_key: Physical::Code(IcedCode::KeyA),
+                location: Location::Standard,
+                modifiers: Modifiers::empty(),
+                text: None,
+            }),
+            status,
+        };
+        let input = stream::iter([event(Status::Captured), event(Status::Ignored)]).boxed();
+        let events: Vec<_> = block_on(recipe.stream(input).collect());
+        assert_eq!(events, [KeybindsEvent::Action(0)]);
+    }
+
+    #[test]
+    fn subscription_identity() {
+        fn id<I: Hash + 'static>(id: I, keybinds: &Arc<Keybinds<u32>>) -> u64 {
+            use std::hash::Hasher as _;
+            let recipes =
+                iced_subscription::into_recipes(subscription(id, keybinds.clone(), |e| e));
+            let mut hasher = Hasher::default();
+            for recipe in recipes {
+                recipe.hash(&mut hasher);
+            }
+            hasher.finish()
+        }
+
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+x Ctrl+c", 0).unwrap();
+        let mut keybinds = Arc::new(keybinds);
+        let before = id(0, &keybinds);
+
+        // The key bindings are not compared
+        Arc::make_mut(&mut keybinds).bind("a", 1).unwrap();
+        assert_eq!(id(0, &keybinds), before);
+        assert_eq!(id(0, &Arc::default()), before);
+
+        assert_ne!(id(1, &keybinds), before);
+        assert_ne!(id("0", &keybinds), before);
+    }
 }
@@ -0,0 +1,184 @@
+use crate::Error;
+use std::fmt;
+use std::hash::Hash;
+
+/// Input type handled by [`GenericKeySeq`][crate::GenericKeySeq] and [`GenericKeybinds`][crate::GenericKeybinds].
+///
+/// [`KeySeq`][crate::KeySeq] and [`Keybinds`][crate::Keybinds] handle keyboard inputs represented by
+/// [`KeyInput`][crate::KeyInput]. Implementing this trait allows to reuse the sequence matching, the timeout handling,
+/// and the serde support for other input sources such as MIDI pads, gamepads or foot pedals.
+///
+/// An input is parsed from a whitespace-separated element of a key sequence string such as `"Left Right"` and
+/// formatted into the string with [`fmt::Display`].
+///
+/// ```
+/// use keybinds::{Error, GenericKeybinds, Input};
+/// use std::fmt;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// enum Pedal {
+///     Left,
+///     Right,
+/// }
+///
+/// impl fmt::Display for Pedal {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Self::Left => f.write_str("Left"),
+///             Self::Right => f.write_str("Right"),
+///         }
+///     }
+/// }
+///
+/// impl Input for Pedal {
+///     type Context = ();
+///
+///     fn parse(s: &str) -> Result<Self, Error> {
+///         match s {
+///             "Left" => Ok(Self::Left),
+///             "Right" => Ok(Self::Right),
+///             _ => Err(Error::UnknownKey(s.into())),
+///         }
+///     }
+/// }
+///
+/// #[derive(PartialEq, Eq, Debug)]
+/// enum Action {
+///     NextPage,
+///     PrevPage,
+/// }
+///
+/// let mut keybinds = GenericKeybinds::<Action, Pedal>::new(vec![]);
+/// keybinds.bind("Right", Action::NextPage).unwrap();
+/// keybinds.bind("Left Left", Action::PrevPage).unwrap();
+///
+/// assert_eq!(keybinds.dispatch(Pedal::Right), Some(&Action::NextPage));
+/// assert_eq!(keybinds.dispatch(Pedal::Left), None);
+/// assert_eq!(keybinds.dispatch(Pedal::Left), Some(&Action::PrevPage));
+/// ```
+pub trait Input: Clone + Eq + Hash + fmt::Debug + fmt::Display {
+    /// Context shared by all the inputs in a dispatcher. The dispatcher passes it to [`Input::parse_in`] and
+    /// [`Input::matches_in`]. For example, [`KeyInput`][crate::KeyInput] uses the platform and the keyboard layout set
+    /// to [`Keybinds`][crate::Keybinds]. Inputs which need no context can use `()`.
+    type Context: Default + Clone + PartialEq + Eq + fmt::Debug;
+
+    /// Parse an input from a string. The string is one of the whitespace-separated elements in a key sequence so it
+    /// contains no whitespace.
+    fn parse(s: &str) -> Result<Self, Error>;
+
+    /// Parse an input from a string with the context of the dispatcher. The default implementation ignores the context
+    /// and calls [`Input::parse`].
+    fn parse_in(s: &str, context: &Self::Context) -> Result<Self, Error> {
+        let _ = context;
+        Self::parse(s)
+    }
+
+    /// Check if the input in a key sequence matches to the actual input. The default implementation compares the
+    /// inputs with `==`.
+    fn matches(&self, actual: &Self) -> bool {
+        self == actual
+    }
+
+    /// Check if the input in a key sequence matches to the actual input with the context of the dispatcher. The
+    /// default implementation ignores the context and calls [`Input::matches`].
+    fn matches_in(&self, actual: &Self, context: &Self::Context) -> bool {
+        let _ = context;
+        self.matches(actual)
+    }
+
+    /// Return `true` when the dispatcher should ignore the actual input. Ignored inputs neither dispatch actions nor
+    /// break the ongoing key sequence matching. The default implementation ignores nothing.
+    fn is_ignored(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GenericKeySeq, GenericKeybind, GenericKeybinds, Match};
+    use std::time::Duration;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+    enum Pad {
+        Hit(u8),
+        Idle,
+    }
+
+    impl fmt::Display for Pad {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Hit(n) => write!(f, "Pad{n}"),
+                Self::Idle => f.write_str("Idle"),
+            }
+        }
+    }
+
+    impl Input for Pad {
+        type Context = ();
+
+        fn parse(s: &str) -> Result<Self, Error> {
+            match s.strip_prefix("Pad").and_then(|n| n.parse().ok()) {
+                Some(n) => Ok(Self::Hit(n)),
+                None => Err(Error::UnknownKey(s.into())),
+            }
+        }
+
+        fn is_ignored(&self) -> bool {
+            *self == Self::Idle
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    enum A {
+        Action1,
+        Action2,
+    }
+
+    #[test]
+    fn custom_input_seq() {
+        let seq: GenericKeySeq<Pad> = "Pad1  Pad2".parse().unwrap();
+        assert_eq!(seq.as_slice(), &[Pad::Hit(1), Pad::Hit(2)]);
+        assert_eq!(seq.to_string(), "Pad1 Pad2");
+        assert_eq!(seq.match_to(&[Pad::Hit(1)]), Match::Prefix);
+        assert_eq!(seq.match_to(&[Pad::Hit(1), Pad::Hit(2)]), Match::Matched);
+        assert_eq!(seq.match_to(&[Pad::Hit(2)]), Match::Unmatch);
+
+        assert_eq!(
+            "Pad1 Foo".parse::<GenericKeySeq<Pad>>(),
+            Err(Error::UnknownKey("Foo".into())),
+        );
+        assert_eq!(
+            " ".parse::<GenericKeySeq<Pad>>(),
+            Err(Error::EmptyKeySequence),
+        );
+
+        let seq: GenericKeySeq<Pad> = [Pad::Hit(3), Pad::Hit(4)].into_iter().collect();
+        assert_eq!(seq.to_string(), "Pad3 Pad4");
+    }
+
+    #[test]
+    fn custom_input_dispatch() {
+        let seq: GenericKeySeq<Pad> = [Pad::Hit(1), Pad::Hit(2)].into_iter().collect();
+        let mut keybinds = GenericKeybinds::new(vec![GenericKeybind {
+            seq,
+            action: A::Action1,
+        }]);
+        keybinds.bind("Pad3", A::Action2).unwrap();
+        keybinds.bind("Pad", A::Action2).unwrap_err();
+
+        assert_eq!(keybinds.dispatch(Pad::Hit(1)), None);
+        assert!(keybinds.is_ongoing());
+        assert_eq!(keybinds.dispatch(Pad::Idle), None);
+        assert_eq!(keybinds.ongoing_inputs(), &[Pad::Hit(1)]);
+        assert_eq!(keybinds.dispatch(Pad::Hit(2)), Some(&A::Action1));
+        assert_eq!(keybinds.dispatch(Pad::Hit(3)), Some(&A::Action2));
+        assert_eq!(keybinds.dispatch(Pad::Hit(4)), None);
+        assert!(!keybinds.is_ongoing());
+
+        keybinds.set_timeout(Duration::from_millis(10));
+        keybinds.dispatch(Pad::Hit(1));
+        std::thread::sleep(Duration::from_millis(50));
+        assert_eq!(keybinds.dispatch(Pad::Hit(2)), None);
+    }
+}
@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+// Interned strings are never freed so the number of them and their lengths are bounded. It prevents key bindings loaded
+// from an untrusted source from consuming the memory without limit. The indices must fit in the 21 bits payload of
+// `PackedKey`.
+const MAX_KEY_STRS: usize = 1024;
+const MAX_KEY_STR_LEN: usize = 64;
+
+static INTERNER: Interner<MAX_KEY_STRS> = Interner::new();
+
+struct Interner<const N: usize> {
+    // Each slot is written only once and never modified after that so reading the interned strings needs no lock
+    strs: [OnceLock<Box<str>>; N],
+    // Map from the interned strings to their indices in `strs`. The lock is only taken on interning and looking up
+    ids: OnceLock<RwLock<HashMap<&'static str, u32>>>,
+}
+
+impl<const N: usize> Interner<N> {
+    const fn new() -> Self {
+        Self {
+            strs: [const { OnceLock::new() }; N],
+            ids: OnceLock::new(),
+        }
+    }
+
+    fn ids(&self) -> &RwLock<HashMap<&'static str, u32>> {
+        self.ids.get_or_init(RwLock::default)
+    }
+
+    fn intern(&'static self, s: &str) -> Option<u32> {
+        let mut ids = self.ids().write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&id) = ids.get(s) {
+            return Some(id);
+        }
+        let id = ids.len();
+        let slot = self.strs.get(id)?; // The table is full
+        let s: &'static str = slot.get_or_init(|| s.into());
+        ids.insert(s, id as u32);
+        Some(id as u32)
+    }
+
+    fn lookup(&self, s: &str) -> Option<u32> {
+        let ids = self.ids().read().unwrap_or_else(PoisonError::into_inner);
+        ids.get(s).copied()
+    }
+
+    fn get(&self, id: u32) -> &str {
+        self.strs[id as usize].get().unwrap() // IDs are only created after interning the strings
+    }
+}
+
+/// Normalize the string into NFC when `unicode-normalization` feature is enabled.
+#[cfg(feature = "unicode-normalization")]
+pub(crate) fn normalize(s: &str) -> Cow<'_, str> {
+    use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+    match is_nfc_quick(s.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(s),
+        _ => Cow::Owned(s.nfc().collect()),
+    }
+}
+
+#[cfg(not(feature = "unicode-normalization"))]
+pub(crate) fn normalize(s: &str) -> Cow<'_, str> {
+    Cow::Borrowed(s)
+}
+
+/// Interned string of a multi-character key value such as a grapheme cluster.
+///
+/// Some platforms report a key value which consists of several code points, such as a character composed with a dead
+/// key, a text committed by IME, or an emoji with ZWJ sequence. Such key values are represented by
+/// [`Key::Str`][crate::Key::Str] variant with this type. The string is interned in a global table so that this type is
+/// small and `Copy`, and comparing two instances is as cheap as comparing integers.
+///
+/// When `unicode-normalization` feature is enabled, the string is normalized into NFC on interning. It means that
+/// canonically equivalent strings result in the same instance.
+///
+/// ```
+/// use keybinds::{Key, KeyStr};
+///
+/// let Ok(Key::Str(family)) = "👨‍👩‍👧".parse() else { unreachable!() };
+/// assert_eq!(family.as_str(), "👨‍👩‍👧");
+/// assert_eq!(KeyStr::lookup("👨‍👩‍👧"), Some(family));
+/// assert_eq!(Key::from_text("👨‍👩‍👧"), Key::Str(family));
+/// ```
+///
+/// Note that interned strings are never freed. Only the key values in key bindings are interned on parsing them and
+/// the texts reported by platforms are only looked up by [`KeyStr::lookup`]. In addition, at most 1024 strings up to
+/// 64 bytes can be interned. Parsing a key binding fails when the limit is exceeded. Reading the interned string by
+/// [`KeyStr::as_str`] needs no lock.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyStr(u32);
+
+impl KeyStr {
+    // Intern the string and create a new instance. Interning the same string returns the same instance. `None` is
+    // returned when the string cannot be represented in the syntax; the string has less than two characters, only
+    // consists of ASCII characters, or contains whitespaces or `+`. It is also returned when the string is too long or
+    // too many strings have been interned.
+    pub(crate) fn new(s: &str) -> Option<Self> {
+        let s = normalize(s);
+        let mut chars = s.chars();
+        let valid = chars.next().is_some()
+            && chars.next().is_some()
+            && !s.is_ascii()
+            && s.len() <= MAX_KEY_STR_LEN
+            && !s.chars().any(|c| c == '+' || c.is_whitespace());
+        if !valid {
+            return None;
+        }
+        INTERNER.intern(&s).map(Self)
+    }
+
+    /// Look up the interned string. Unlike parsing a key binding, this function never interns a new string. `None` is
+    /// returned when the string has not been interned yet.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyStr};
+    ///
+    /// let key: Key = "👍🏽".parse().unwrap();
+    /// assert_eq!(KeyStr::lookup("👍🏽").map(Key::Str), Some(key));
+    /// assert_eq!(KeyStr::lookup("🙇‍♂️"), None);
+    /// ```
+    pub fn lookup(s: &str) -> Option<Self> {
+        INTERNER.lookup(&normalize(s)).map(Self)
+    }
+
+    /// Return the interned string.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyStr};
+    ///
+    /// let Ok(Key::Str(s)) = "👍🏽".parse() else { unreachable!() };
+    /// assert_eq!(s.as_str(), "👍🏽");
+    /// ```
+    pub fn as_str(self) -> &'static str {
+        INTERNER.get(self.0)
+    }
+
+    // The index in the interner to pack `Key::Str` into `PackedKey`
+    pub(crate) fn index(self) -> u32 {
+        self.0
+    }
+
+    pub(crate) fn from_index(index: u32) -> Self {
+        Self(index)
+    }
+}
+
+impl fmt::Display for KeyStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for KeyStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("KeyStr").field(&self.as_str()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_key_str() {
+        let a = KeyStr::new("👨‍👩‍👧").unwrap();
+        let b = KeyStr::new("日本語").unwrap();
+        assert_ne!(a, b);
+        assert_eq!(Some(a), KeyStr::new("👨‍👩‍👧"));
+        assert_eq!(a.as_str(), "👨‍👩‍👧");
+        assert_eq!(b.as_str(), "日本語");
+        assert_eq!(format!("{b}"), "日本語");
+        assert_eq!(format!("{b:?}"), r#"KeyStr("日本語")"#);
+    }
+
+    #[test]
+    fn reject_key_str() {
+        for s in [
+            "",
+            "a",
+            "あ",
+            "foo",
+            "Enter",
+            "日本 語",
+            "あ+い",
+            "\u{3000}あ",
+        ] {
+            assert_eq!(KeyStr::new(s), None, "s={s:?}");
+        }
+
+        let long = "あ".repeat(MAX_KEY_STR_LEN / 3 + 1);
+        assert_eq!(KeyStr::new(&long), None);
+        assert_eq!(KeyStr::lookup(&long), None);
+    }
+
+    #[test]
+    fn bounded_interner() {
+        let interner: &'static Interner<2> = Box::leak(Box::new(Interner::new()));
+        assert_eq!(interner.intern("日本語"), Some(0));
+        assert_eq!(interner.intern("👍🏽"), Some(1));
+        assert_eq!(interner.intern("日本語"), Some(0));
+
+        // The table is full but the interned strings can still be used
+        assert_eq!(interner.intern("🙇‍♂️"), None);
+        assert_eq!(interner.lookup("🙇‍♂️"), None);
+        assert_eq!(interner.lookup("👍🏽"), Some(1));
+        assert_eq!(interner.get(0), "日本語");
+        assert_eq!(interner.get(1), "👍🏽");
+    }
+
+    #[test]
+    fn lookup_key_str() {
+        let s = "🧑\u{200D}🚀";
+        assert_eq!(KeyStr::lookup(s), None);
+        let interned = KeyStr::new(s).unwrap();
+        assert_eq!(KeyStr::lookup(s), Some(interned));
+        assert_eq!(KeyStr::lookup("foo"), None);
+
+        // Looking up never interns a new string
+        let t = "🧑\u{200D}🚒";
+        assert_eq!(KeyStr::lookup(t), None);
+        assert_eq!(KeyStr::lookup(t), None);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn normalize_key_str() {
+        // "が" is composed of "か" and the combining voiced sound mark in NFD
+        assert_eq!(normalize("か\u{3099}"), "が");
+        assert_eq!(normalize("が"), "が");
+        assert_eq!(KeyStr::new("か\u{3099}ー"), KeyStr::new("がー"));
+        assert_eq!(KeyStr::lookup("か\u{3099}ー"), KeyStr::new("がー"));
+    }
+}
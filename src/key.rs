@@ -1,15 +1,18 @@
-use crate::Error;
+use crate::intern::normalize;
+use crate::{Code, Error, Input, KeyStr, Layout, Platform};
 use bitflags::bitflags;
 use smallvec::{smallvec, SmallVec};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 
 #[cfg(feature = "arbitrary")]
 use arbitrary::Arbitrary;
 
 // Note: We use `Key::F1`...`Key::F35` variants instead of `Key::F(u8)` variant because
-//  * it reduces the size of `Key` from 8 bytes to 4 bytes because `u8` value requires a padding. Thanks to this
-//    reduction, `KeyInput` fits to 1 word and can implement `Copy`.
+//  * it keeps named keys free of payloads so that they can be packed into `PackedKey` by their indices. Thanks to this
+//    packing, `KeyInput` fits to 1 word and can implement `Copy` cheaply even though `Key` itself is 8 bytes due to the
+//    payloads of `Key::Physical` and `Key::Str`.
 //  * it can avoid invalid keys like `Key::F(0)` or `Key::F(999)`.
 
 /// Single logical key on keyboard.
@@ -17,12 +20,19 @@ use arbitrary::Arbitrary;
 /// The 'logical key' is the key after applying modifier keys. For example, `Key::Char('A')` usually means the result
 /// of pressing <kbd>Shift</kbd> + <kbd>A</kbd> physical keys.
 ///
+/// As an exception, [`Key::Physical`] represents a physical key (the position of the key on keyboard) instead of a
+/// logical key. It is written as `[KeyW]` in the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
+///
 /// This enum is non-exhaustive because more keys may be added in the future.
 #[non_exhaustive]
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
 pub enum Key {
     Char(char),
+    /// Physical key regardless of the keyboard layout. See [`Code`] for more details.
+    Physical(Code),
+    /// Key value which consists of multiple characters such as a grapheme cluster. See [`KeyStr`] for more details.
+    Str(KeyStr),
     Up,
     Right,
     Down,
@@ -62,6 +72,75 @@ pub enum Key {
     VolumeDown,
     Mute,
     Help,
+    Accept,
+    Again,
+    Attn,
+    Cancel,
+    Execute,
+    Find,
+    Props,
+    Select,
+    CrSel,
+    EraseEof,
+    ExSel,
+    BrightnessDown,
+    BrightnessUp,
+    Eject,
+    LogOff,
+    Power,
+    PowerOff,
+    Hibernate,
+    Standby,
+    WakeUp,
+    FastForward,
+    Record,
+    BrowserBack,
+    BrowserFavorites,
+    BrowserForward,
+    BrowserHome,
+    BrowserRefresh,
+    BrowserSearch,
+    BrowserStop,
+    LaunchApplication1,
+    LaunchApplication2,
+    LaunchCalendar,
+    LaunchContacts,
+    LaunchMail,
+    LaunchMediaPlayer,
+    LaunchMusicPlayer,
+    LaunchPhone,
+    LaunchScreenSaver,
+    LaunchSpreadsheet,
+    LaunchWebBrowser,
+    LaunchWebCam,
+    LaunchWordProcessor,
+    Close,
+    New,
+    Open,
+    Print,
+    Save,
+    SpellCheck,
+    MailForward,
+    MailReply,
+    MailSend,
+    /// Primary (left) mouse button.
+    MouseLeft,
+    /// Secondary (right) mouse button.
+    MouseRight,
+    /// Auxiliary (middle) mouse button. Clicking a mouse wheel usually means this button.
+    MouseMiddle,
+    /// "Back" side button of mouse.
+    MouseBack,
+    /// "Forward" side button of mouse.
+    MouseForward,
+    /// Scrolling mouse wheel up.
+    WheelUp,
+    /// Scrolling mouse wheel down.
+    WheelDown,
+    /// Scrolling mouse wheel (or tilting it) left.
+    WheelLeft,
+    /// Scrolling mouse wheel (or tilting it) right.
+    WheelRight,
     F1,
     F2,
     F3,
@@ -120,15 +199,69 @@ impl Key {
     /// assert!(Key::Char(' ').is_named());
     /// assert!(Key::Char('+').is_named());
     /// assert!(!Key::Char('x').is_named());
+    /// assert!(!Key::from_text("👍🏽").is_named());
     /// assert!(!Key::Unidentified.is_named());
     /// ```
     pub fn is_named(self) -> bool {
         match self {
             Self::Char(' ' | '+') => true,
-            Self::Char(_) | Self::Ignored | Self::Unidentified => false,
+            Self::Char(_) | Self::Str(_) | Self::Ignored | Self::Unidentified => false,
             _ => true,
         }
     }
+
+    /// Create a key from the text typed by the key. A single character text is converted into [`Key::Char`] and an
+    /// empty text is converted into [`Key::Unidentified`].
+    ///
+    /// A multi-character text such as a grapheme cluster is converted into [`Key::Str`] only when the same key value
+    /// is used in some key bindings. Otherwise it is converted into [`Key::Unidentified`] because no key binding can
+    /// match to it. This is because the multi-character key values are interned and never freed. See [`KeyStr`] for
+    /// more details.
+    ///
+    /// When `unicode-normalization` feature is enabled, the text is normalized into NFC before the conversion.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeySeq};
+    ///
+    /// assert_eq!(Key::from_text("a"), Key::Char('a'));
+    /// assert_eq!(Key::from_text(""), Key::Unidentified);
+    ///
+    /// // The key value is not used in any key binding
+    /// assert_eq!(Key::from_text("👨‍👩‍👧"), Key::Unidentified);
+    ///
+    /// let seq: KeySeq = "Ctrl+👨‍👩‍👧".parse().unwrap();
+    /// let key = Key::from_text("👨‍👩‍👧");
+    /// assert_eq!(key, seq.as_slice()[0].key());
+    /// assert_eq!(key.to_string(), "👨‍👩‍👧");
+    /// ```
+    pub fn from_text(text: &str) -> Self {
+        let text = normalize(text);
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::Char(c),
+            (Some(_), Some(_)) => KeyStr::lookup(&text).map_or(Self::Unidentified, Self::Str),
+            (None, _) => Self::Unidentified,
+        }
+    }
+
+    /// Create a wheel key from the scroll amounts. Positive values mean scrolling up and left, which is the convention
+    /// of winit and iced. The direction of the larger amount is chosen. Zero amounts result in [`Key::Ignored`].
+    #[cfg(any(feature = "winit", feature = "iced"))]
+    pub(crate) fn from_wheel_delta(x: f64, y: f64) -> Self {
+        if y != 0.0 && y.abs() >= x.abs() {
+            if y > 0.0 {
+                Self::WheelUp
+            } else {
+                Self::WheelDown
+            }
+        } else if x > 0.0 {
+            Self::WheelLeft
+        } else if x < 0.0 {
+            Self::WheelRight
+        } else {
+            Self::Ignored
+        }
+    }
 }
 
 impl From<char> for Key {
@@ -151,7 +284,7 @@ impl FromStr for Key {
     /// Parse the key from [`str`] following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
     ///
     /// ```
-    /// use keybinds::Key;
+    /// use keybinds::{Key, Code};
     ///
     /// assert_eq!("x".parse(), Ok(Key::Char('x')));
     /// assert_eq!("Up".parse(), Ok(Key::Up));
@@ -159,9 +292,16 @@ impl FromStr for Key {
     /// assert_eq!("Space".parse(), Ok(Key::Char(' ')));
     /// assert_eq!("Plus".parse(), Ok(Key::Char('+')));
     /// assert_eq!("F1".parse(), Ok(Key::F1));
+    /// assert_eq!("BrowserBack".parse(), Ok(Key::BrowserBack));
+    /// assert_eq!("launchmail".parse(), Ok(Key::LaunchMail));
+    /// assert_eq!("[KeyW]".parse(), Ok(Key::Physical(Code::KeyW)));
+    ///
+    /// // Multi-character keys containing non-ASCII characters
+    /// assert_eq!("👍🏽".parse(), Ok(Key::from_text("👍🏽")));
     ///
     /// assert!("Unknown".parse::<Key>().is_err());
     /// assert!("".parse::<Key>().is_err());
+    /// assert!("[Foo]".parse::<Key>().is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let s = s.trim_ascii();
@@ -172,6 +312,22 @@ impl FromStr for Key {
             }
         }
 
+        if let Some(code) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            return Ok(Self::Physical(code.parse()?));
+        }
+
+        // All named keys consist of ASCII characters
+        if !s.is_ascii() {
+            let text = normalize(s);
+            let mut chars = text.chars();
+            if let (Some(c), None) = (chars.next(), chars.next()) {
+                return Ok(Self::Char(c));
+            }
+            return KeyStr::new(&text)
+                .map(Self::Str)
+                .ok_or_else(|| Error::UnknownKey(s.into()));
+        }
+
         match s {
             "space" | "Space" | "SPACE" => Ok(Self::Char(' ')),
             "plus" | "Plus" | "PLUS" => Ok(Self::Char('+')),
@@ -214,6 +370,84 @@ impl FromStr for Key {
             "volumedown" | "VolumeDown" | "VOLUMEDOWN" => Ok(Self::VolumeDown),
             "mute" | "Mute" | "MUTE" => Ok(Self::Mute),
             "help" | "Help" | "HELP" => Ok(Self::Help),
+            "accept" | "Accept" | "ACCEPT" => Ok(Self::Accept),
+            "again" | "Again" | "AGAIN" => Ok(Self::Again),
+            "attn" | "Attn" | "ATTN" => Ok(Self::Attn),
+            "cancel" | "Cancel" | "CANCEL" => Ok(Self::Cancel),
+            "execute" | "Execute" | "EXECUTE" => Ok(Self::Execute),
+            "find" | "Find" | "FIND" => Ok(Self::Find),
+            "props" | "Props" | "PROPS" => Ok(Self::Props),
+            "select" | "Select" | "SELECT" => Ok(Self::Select),
+            "crsel" | "CrSel" | "CRSEL" => Ok(Self::CrSel),
+            "eraseeof" | "EraseEof" | "ERASEEOF" => Ok(Self::EraseEof),
+            "exsel" | "ExSel" | "EXSEL" => Ok(Self::ExSel),
+            "brightnessdown" | "BrightnessDown" | "BRIGHTNESSDOWN" => Ok(Self::BrightnessDown),
+            "brightnessup" | "BrightnessUp" | "BRIGHTNESSUP" => Ok(Self::BrightnessUp),
+            "eject" | "Eject" | "EJECT" => Ok(Self::Eject),
+            "logoff" | "LogOff" | "LOGOFF" => Ok(Self::LogOff),
+            "power" | "Power" | "POWER" => Ok(Self::Power),
+            "poweroff" | "PowerOff" | "POWEROFF" => Ok(Self::PowerOff),
+            "hibernate" | "Hibernate" | "HIBERNATE" => Ok(Self::Hibernate),
+            "standby" | "Standby" | "STANDBY" => Ok(Self::Standby),
+            "wakeup" | "WakeUp" | "WAKEUP" => Ok(Self::WakeUp),
+            "fastforward" | "FastForward" | "FASTFORWARD" => Ok(Self::FastForward),
+            "record" | "Record" | "RECORD" => Ok(Self::Record),
+            "browserback" | "BrowserBack" | "BROWSERBACK" => Ok(Self::BrowserBack),
+            "browserfavorites" | "BrowserFavorites" | "BROWSERFAVORITES" => {
+                Ok(Self::BrowserFavorites)
+            }
+            "browserforward" | "BrowserForward" | "BROWSERFORWARD" => Ok(Self::BrowserForward),
+            "browserhome" | "BrowserHome" | "BROWSERHOME" => Ok(Self::BrowserHome),
+            "browserrefresh" | "BrowserRefresh" | "BROWSERREFRESH" => Ok(Self::BrowserRefresh),
+            "browsersearch" | "BrowserSearch" | "BROWSERSEARCH" => Ok(Self::BrowserSearch),
+            "browserstop" | "BrowserStop" | "BROWSERSTOP" => Ok(Self::BrowserStop),
+            "launchapplication1" | "LaunchApplication1" | "LAUNCHAPPLICATION1" => {
+                Ok(Self::LaunchApplication1)
+            }
+            "launchapplication2" | "LaunchApplication2" | "LAUNCHAPPLICATION2" => {
+                Ok(Self::LaunchApplication2)
+            }
+            "launchcalendar" | "LaunchCalendar" | "LAUNCHCALENDAR" => Ok(Self::LaunchCalendar),
+            "launchcontacts" | "LaunchContacts" | "LAUNCHCONTACTS" => Ok(Self::LaunchContacts),
+            "launchmail" | "LaunchMail" | "LAUNCHMAIL" => Ok(Self::LaunchMail),
+            "launchmediaplayer" | "LaunchMediaPlayer" | "LAUNCHMEDIAPLAYER" => {
+                Ok(Self::LaunchMediaPlayer)
+            }
+            "launchmusicplayer" | "LaunchMusicPlayer" | "LAUNCHMUSICPLAYER" => {
+                Ok(Self::LaunchMusicPlayer)
+            }
+            "launchphone" | "LaunchPhone" | "LAUNCHPHONE" => Ok(Self::LaunchPhone),
+            "launchscreensaver" | "LaunchScreenSaver" | "LAUNCHSCREENSAVER" => {
+                Ok(Self::LaunchScreenSaver)
+            }
+            "launchspreadsheet" | "LaunchSpreadsheet" | "LAUNCHSPREADSHEET" => {
+                Ok(Self::LaunchSpreadsheet)
+            }
+            "launchwebbrowser" | "LaunchWebBrowser" | "LAUNCHWEBBROWSER" => {
+                Ok(Self::LaunchWebBrowser)
+            }
+            "launchwebcam" | "LaunchWebCam" | "LAUNCHWEBCAM" => Ok(Self::LaunchWebCam),
+            "launchwordprocessor" | "LaunchWordProcessor" | "LAUNCHWORDPROCESSOR" => {
+                Ok(Self::LaunchWordProcessor)
+            }
+            "close" | "Close" | "CLOSE" => Ok(Self::Close),
+            "new" | "New" | "NEW" => Ok(Self::New),
+            "open" | "Open" | "OPEN" => Ok(Self::Open),
+            "print" | "Print" | "PRINT" => Ok(Self::Print),
+            "save" | "Save" | "SAVE" => Ok(Self::Save),
+            "spellcheck" | "SpellCheck" | "SPELLCHECK" => Ok(Self::SpellCheck),
+            "mailforward" | "MailForward" | "MAILFORWARD" => Ok(Self::MailForward),
+            "mailreply" | "MailReply" | "MAILREPLY" => Ok(Self::MailReply),
+            "mailsend" | "MailSend" | "MAILSEND" => Ok(Self::MailSend),
+            "mouseleft" | "MouseLeft" | "MOUSELEFT" => Ok(Self::MouseLeft),
+            "mouseright" | "MouseRight" | "MOUSERIGHT" => Ok(Self::MouseRight),
+            "mousemiddle" | "MouseMiddle" | "MOUSEMIDDLE" => Ok(Self::MouseMiddle),
+            "mouseback" | "MouseBack" | "MOUSEBACK" => Ok(Self::MouseBack),
+            "mouseforward" | "MouseForward" | "MOUSEFORWARD" => Ok(Self::MouseForward),
+            "wheelup" | "WheelUp" | "WHEELUP" => Ok(Self::WheelUp),
+            "wheeldown" | "WheelDown" | "WHEELDOWN" => Ok(Self::WheelDown),
+            "wheelleft" | "WheelLeft" | "WHEELLEFT" => Ok(Self::WheelLeft),
+            "wheelright" | "WheelRight" | "WHEELRIGHT" => Ok(Self::WheelRight),
             "f1" | "F1" => Ok(Self::F1),
             "f2" | "F2" => Ok(Self::F2),
             "f3" | "F3" => Ok(Self::F3),
@@ -258,6 +492,9 @@ impl FromStr for Key {
 impl fmt::Display for Key {
     /// Generate a string representation of the key following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
     ///
+    /// The names are always in English so that they can be parsed again. To display localized names, use
+    /// [`KeySeqFormatter`][crate::KeySeqFormatter] with [`Locale`][crate::Locale].
+    ///
     /// ```
     /// use keybinds::Key;
     ///
@@ -265,14 +502,18 @@ impl fmt::Display for Key {
     /// assert_eq!(format!("{}", Key::Down), "Down");
     /// assert_eq!(format!("{}", Key::Insert), "Insert");
     /// assert_eq!(format!("{}", Key::F5), "F5");
+    /// assert_eq!(format!("{}", Key::BrightnessUp), "BrightnessUp");
     /// assert_eq!(format!("{}", Key::Char(' ')), "Space");
     /// assert_eq!(format!("{}", Key::Char('+')), "Plus");
+    /// assert_eq!(format!("{}", Key::Physical(keybinds::Code::KeyW)), "[KeyW]");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Char(' ') => f.write_str("Space"),
             Self::Char('+') => f.write_str("Plus"),
             Self::Char(c) => write!(f, "{c}"),
+            Self::Physical(code) => write!(f, "[{code}]"),
+            Self::Str(s) => f.write_str(s.as_str()),
             Self::Up => f.write_str("Up"),
             Self::Right => f.write_str("Right"),
             Self::Down => f.write_str("Down"),
@@ -312,6 +553,66 @@ impl fmt::Display for Key {
             Self::VolumeDown => f.write_str("VolumeDown"),
             Self::Mute => f.write_str("Mute"),
             Self::Help => f.write_str("Help"),
+            Self::Accept => f.write_str("Accept"),
+            Self::Again => f.write_str("Again"),
+            Self::Attn => f.write_str("Attn"),
+            Self::Cancel => f.write_str("Cancel"),
+            Self::Execute => f.write_str("Execute"),
+            Self::Find => f.write_str("Find"),
+            Self::Props => f.write_str("Props"),
+            Self::Select => f.write_str("Select"),
+            Self::CrSel => f.write_str("CrSel"),
+            Self::EraseEof => f.write_str("EraseEof"),
+            Self::ExSel => f.write_str("ExSel"),
+            Self::BrightnessDown => f.write_str("BrightnessDown"),
+            Self::BrightnessUp => f.write_str("BrightnessUp"),
+            Self::Eject => f.write_str("Eject"),
+            Self::LogOff => f.write_str("LogOff"),
+            Self::Power => f.write_str("Power"),
+            Self::PowerOff => f.write_str("PowerOff"),
+            Self::Hibernate => f.write_str("Hibernate"),
+            Self::Standby => f.write_str("Standby"),
+            Self::WakeUp => f.write_str("WakeUp"),
+            Self::FastForward => f.write_str("FastForward"),
+            Self::Record => f.write_str("Record"),
+            Self::BrowserBack => f.write_str("BrowserBack"),
+            Self::BrowserFavorites => f.write_str("BrowserFavorites"),
+            Self::BrowserForward => f.write_str("BrowserForward"),
+            Self::BrowserHome => f.write_str("BrowserHome"),
+            Self::BrowserRefresh => f.write_str("BrowserRefresh"),
+            Self::BrowserSearch => f.write_str("BrowserSearch"),
+            Self::BrowserStop => f.write_str("BrowserStop"),
+            Self::LaunchApplication1 => f.write_str("LaunchApplication1"),
+            Self::LaunchApplication2 => f.write_str("LaunchApplication2"),
+            Self::LaunchCalendar => f.write_str("LaunchCalendar"),
+            Self::LaunchContacts => f.write_str("LaunchContacts"),
+            Self::LaunchMail => f.write_str("LaunchMail"),
+            Self::LaunchMediaPlayer => f.write_str("LaunchMediaPlayer"),
+            Self::LaunchMusicPlayer => f.write_str("LaunchMusicPlayer"),
+            Self::LaunchPhone => f.write_str("LaunchPhone"),
+            Self::LaunchScreenSaver => f.write_str("LaunchScreenSaver"),
+            Self::LaunchSpreadsheet => f.write_str("LaunchSpreadsheet"),
+            Self::LaunchWebBrowser => f.write_str("LaunchWebBrowser"),
+            Self::LaunchWebCam => f.write_str("LaunchWebCam"),
+            Self::LaunchWordProcessor => f.write_str("LaunchWordProcessor"),
+            Self::Close => f.write_str("Close"),
+            Self::New => f.write_str("New"),
+            Self::Open => f.write_str("Open"),
+            Self::Print => f.write_str("Print"),
+            Self::Save => f.write_str("Save"),
+            Self::SpellCheck => f.write_str("SpellCheck"),
+            Self::MailForward => f.write_str("MailForward"),
+            Self::MailReply => f.write_str("MailReply"),
+            Self::MailSend => f.write_str("MailSend"),
+            Self::MouseLeft => f.write_str("MouseLeft"),
+            Self::MouseRight => f.write_str("MouseRight"),
+            Self::MouseMiddle => f.write_str("MouseMiddle"),
+            Self::MouseBack => f.write_str("MouseBack"),
+            Self::MouseForward => f.write_str("MouseForward"),
+            Self::WheelUp => f.write_str("WheelUp"),
+            Self::WheelDown => f.write_str("WheelDown"),
+            Self::WheelLeft => f.write_str("WheelLeft"),
+            Self::WheelRight => f.write_str("WheelRight"),
             Self::F1 => f.write_str("F1"),
             Self::F2 => f.write_str("F2"),
             Self::F3 => f.write_str("F3"),
@@ -369,67 +670,204 @@ bitflags! {
     ///
     /// assert_ne!(none, ctrl_alt);
     /// ```
+    ///
+    /// `MOD` and `SUPER` are virtual modifiers whose actual modifier keys depend on the platform. They are resolved
+    /// at runtime with [`Mods::resolve`]. See the document of each constant for more details.
+    ///
+    /// `LCTRL`, `RCTRL`, `LALT`, `RALT`, `LSHIFT` and `RSHIFT` are side-aware modifiers which distinguish the left and
+    /// right modifier keys. Each of them contains the side-agnostic modifier. For example, `LCTRL` contains `CTRL`.
+    /// When a key binding does not specify the side of a modifier, it matches to both sides.
+    ///
+    /// `CAPS_LOCK` and `NUM_LOCK` represent the lock states instead of pressed keys. They are ignored on matching
+    /// unless a key binding specifies them. `ALTGR` is the <kbd>AltGr</kbd> key on European keyboards which is
+    /// distinguished from <kbd>Ctrl</kbd> + <kbd>Alt</kbd>. It is ignored on matching character keys unless a key
+    /// binding specifies it because the character is typed with it.
+    ///
+    /// `NUMPAD` is not a modifier but represents that the key is on the numeric keypad. Similar to the lock states, it
+    /// is ignored on matching unless a key binding specifies it.
+    ///
+    /// ```
+    /// use keybinds::{KeySeq, KeyInput, Mods, Match};
+    ///
+    /// assert!(Mods::RALT.contains(Mods::ALT));
+    ///
+    /// let input = KeyInput::new('x', Mods::RALT);
+    ///
+    /// let seq: KeySeq = "RAlt+x".parse().unwrap();
+    /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+    /// let seq: KeySeq = "Alt+x".parse().unwrap();
+    /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+    /// let seq: KeySeq = "LAlt+x".parse().unwrap();
+    /// assert_eq!(seq.match_to(&[input]), Match::Unmatch);
+    /// ```
     #[repr(transparent)]
     #[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
-    pub struct Mods: u8 {
+    pub struct Mods: u32 {
         const NONE  = 0b00000000;
         const CTRL  = 0b00000001;
         const CMD   = 0b00000010;
         const ALT   = 0b00000100;
         const WIN   = 0b00001000;
         const SHIFT = 0b00010000;
+        /// The "Mod" modifier key. It is equivalent to "Cmd" on macOS and "Ctrl" on other platforms.
+        ///
+        /// ```
+        /// use keybinds::{Mods, Platform};
+        ///
+        /// assert_eq!(Mods::MOD.resolve(Platform::MacOS), Mods::CMD);
+        /// assert_eq!(Mods::MOD.resolve(Platform::Linux), Mods::CTRL);
+        /// ```
+        const MOD   = 0b00100000;
+        /// The "Super" modifier key. It is equivalent to "Cmd" on macOS and "Win" on other platforms.
+        ///
+        /// ```
+        /// use keybinds::{Mods, Platform};
+        ///
+        /// assert_eq!(Mods::SUPER.resolve(Platform::MacOS), Mods::CMD);
+        /// assert_eq!(Mods::SUPER.resolve(Platform::Windows), Mods::WIN);
+        /// ```
+        const SUPER = 0b01000000;
+        /// The left "Ctrl" modifier key. It contains `CTRL`.
+        const LCTRL  = 0b00000001_00000001;
+        /// The right "Ctrl" modifier key. It contains `CTRL`.
+        const RCTRL  = 0b00000010_00000001;
+        /// The left "Alt" modifier key. It contains `ALT`.
+        const LALT   = 0b00000100_00000100;
+        /// The right "Alt" modifier key. It contains `ALT`.
+        const RALT   = 0b00001000_00000100;
+        /// The left "Shift" modifier key. It contains `SHIFT`.
+        const LSHIFT = 0b00010000_00010000;
+        /// The right "Shift" modifier key. It contains `SHIFT`.
+        const RSHIFT = 0b00100000_00010000;
+        /// The "Meta" modifier key. It is distinct from "Alt" when the platform or the terminal reports it.
+        const META      = 0b00000001_00000000_00000000;
+        /// The "Hyper" modifier key.
+        const HYPER     = 0b00000010_00000000_00000000;
+        /// The "AltGr" (Alt Graph) modifier key on European keyboards.
+        ///
+        /// ```
+        /// use keybinds::{KeySeq, KeyInput, Mods, Match};
+        ///
+        /// // '@' typed with AltGr+Q on German keyboard
+        /// let input = KeyInput::new('@', Mods::ALTGR);
+        ///
+        /// let seq: KeySeq = "@".parse().unwrap();
+        /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+        /// let seq: KeySeq = "Ctrl+Alt+@".parse().unwrap();
+        /// assert_eq!(seq.match_to(&[input]), Match::Unmatch);
+        /// ```
+        const ALTGR     = 0b00000100_00000000_00000000;
+        /// The CapsLock state. It is ignored on matching unless a key binding specifies it.
+        ///
+        /// ```
+        /// use keybinds::{KeySeq, KeyInput, Mods, Match};
+        ///
+        /// let input = KeyInput::new('x', Mods::CTRL | Mods::CAPS_LOCK);
+        ///
+        /// let seq: KeySeq = "Ctrl+x".parse().unwrap();
+        /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+        /// let seq: KeySeq = "CapsLock+Ctrl+x".parse().unwrap();
+        /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+        /// assert_eq!(seq.match_to(&[KeyInput::new('x', Mods::CTRL)]), Match::Unmatch);
+        /// ```
+        const CAPS_LOCK = 0b00001000_00000000_00000000;
+        /// The NumLock state. It is ignored on matching unless a key binding specifies it.
+        const NUM_LOCK  = 0b00010000_00000000_00000000;
+        /// The key is on the numeric keypad. It is ignored on matching unless a key binding specifies it.
+        ///
+        /// ```
+        /// use keybinds::{KeySeq, KeyInput, Key, Mods, Match};
+        ///
+        /// let input = KeyInput::new(Key::Enter, Mods::NUMPAD);
+        ///
+        /// let seq: KeySeq = "Enter".parse().unwrap();
+        /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+        /// let seq: KeySeq = "Numpad+Enter".parse().unwrap();
+        /// assert_eq!(seq.match_to(&[input]), Match::Matched);
+        /// assert_eq!(seq.match_to(&[KeyInput::new(Key::Enter, Mods::NONE)]), Match::Unmatch);
+        /// ```
+        const NUMPAD    = 0b00100000_00000000_00000000;
     }
 }
 
 impl Mods {
-    /// The "Mod" modifier key. It is equivalent to "Cmd" on macOS and "Ctrl" on other platforms.
-    ///
-    /// ```
-    /// use keybinds::Mods;
-    ///
-    /// #[cfg(target_os = "macos")]
-    /// assert_eq!(Mods::MOD, Mods::CMD);
-    /// #[cfg(not(target_os = "macos"))]
-    /// assert_eq!(Mods::MOD, Mods::CTRL);
-    /// ```
-    #[cfg(not(target_os = "macos"))]
-    pub const MOD: Self = Self::CTRL;
-    /// The "Mod" modifier key. It is equivalent to "Cmd" on macOS and "Ctrl" on other platforms.
-    ///
-    /// ```
-    /// use keybinds::Mods;
-    ///
-    /// #[cfg(target_os = "macos")]
-    /// assert_eq!(Mods::MOD, Mods::CMD);
-    /// #[cfg(not(target_os = "macos"))]
-    /// assert_eq!(Mods::MOD, Mods::CTRL);
-    /// ```
-    #[cfg(target_os = "macos")]
-    pub const MOD: Self = Self::CMD;
-    /// The "Super" modifier key. It is equivalent to "Cmd" on macOS and "Win" on other platforms.
-    ///
-    /// ```
-    /// use keybinds::Mods;
+    // Pairs of the left and right modifiers
+    const SIDES: [(Self, Self); 3] = [
+        (Self::LCTRL, Self::RCTRL),
+        (Self::LALT, Self::RALT),
+        (Self::LSHIFT, Self::RSHIFT),
+    ];
+
+    // Shift modifier including both sides
+    pub(crate) const ANY_SHIFT: Self = Self::LSHIFT.union(Self::RSHIFT);
+
+    // States which are not actual modifier keys
+    pub(crate) const STATES: Self = Self::CAPS_LOCK.union(Self::NUM_LOCK).union(Self::NUMPAD);
+
+    // Check if the modifiers in a key binding match to the actual modifiers. The sides of the actual modifiers and
+    // the states like CapsLock are ignored when the key binding does not specify them.
+    pub(crate) fn matches(self, mut actual: Self) -> bool {
+        for (left, right) in Self::SIDES {
+            let sides = left.union(right).difference(left.intersection(right));
+            if !self.intersects(sides) {
+                actual.remove(sides);
+            }
+        }
+        for state in Self::STATES.iter() {
+            if !self.contains(state) {
+                actual.remove(state);
+            }
+        }
+        self == actual
+    }
+
+    // Replace the modifiers synthesized for AltGr with AltGr. AltGr is reported as RAlt, and Windows reports it as
+    // LCtrl+RAlt, so the converters call this while AltGr key is pressed. Other Ctrl and Alt keys pressed with AltGr
+    // are kept. Note that LCtrl actually pressed with AltGr is not distinguishable on Windows.
+    #[cfg(any(feature = "winit", feature = "iced"))]
+    pub(crate) fn with_altgr(self, platform: Platform) -> Self {
+        let mut mods = self.without_side(Self::RALT, Self::LALT);
+        if platform == Platform::Windows {
+            mods = mods.without_side(Self::LCTRL, Self::RCTRL);
+        }
+        mods | Self::ALTGR
+    }
+
+    // Remove the side of the modifier. The side-agnostic modifier is kept only while the other side is pressed.
+    #[cfg(any(feature = "winit", feature = "iced"))]
+    fn without_side(self, side: Self, other: Self) -> Self {
+        let agnostic = side.intersection(other);
+        let mut mods = self.difference(side.difference(agnostic));
+        if !mods.contains(other) {
+            mods.remove(agnostic);
+        }
+        mods
+    }
+
+    /// Resolve the virtual modifiers `MOD` and `SUPER` into the actual modifiers on the platform. Other modifiers are
+    /// kept as-is.
     ///
-    /// #[cfg(target_os = "macos")]
-    /// assert_eq!(Mods::SUPER, Mods::CMD);
-    /// #[cfg(not(target_os = "macos"))]
-    /// assert_eq!(Mods::SUPER, Mods::WIN);
     /// ```
-    #[cfg(not(target_os = "macos"))]
-    pub const SUPER: Self = Self::WIN;
-    /// The "Super" modifier key. It is equivalent to "Cmd" on macOS and "Win" on other platforms.
+    /// use keybinds::{Mods, Platform};
     ///
-    /// ```
-    /// use keybinds::Mods;
+    /// let mods = Mods::MOD | Mods::SUPER | Mods::SHIFT;
     ///
-    /// #[cfg(target_os = "macos")]
-    /// assert_eq!(Mods::SUPER, Mods::CMD);
-    /// #[cfg(not(target_os = "macos"))]
-    /// assert_eq!(Mods::SUPER, Mods::WIN);
+    /// assert_eq!(mods.resolve(Platform::MacOS), Mods::CMD | Mods::SHIFT);
+    /// assert_eq!(mods.resolve(Platform::Linux), Mods::CTRL | Mods::WIN | Mods::SHIFT);
     /// ```
-    #[cfg(target_os = "macos")]
-    pub const SUPER: Self = Self::CMD;
+    pub fn resolve(self, platform: Platform) -> Self {
+        let is_mac = platform == Platform::MacOS;
+        let mut mods = self;
+        if mods.contains(Self::MOD) {
+            mods.remove(Self::MOD);
+            mods |= if is_mac { Self::CMD } else { Self::CTRL };
+        }
+        if mods.contains(Self::SUPER) {
+            mods.remove(Self::SUPER);
+            mods |= if is_mac { Self::CMD } else { Self::WIN };
+        }
+        mods
+    }
 }
 
 impl FromStr for Mods {
@@ -445,11 +883,24 @@ impl FromStr for Mods {
     /// assert_eq!("Alt".parse(), Ok(Mods::ALT));
     /// assert_eq!("Mod".parse(), Ok(Mods::MOD));
     /// assert_eq!("Super".parse(), Ok(Mods::SUPER));
+    /// assert_eq!("LCtrl".parse(), Ok(Mods::LCTRL));
+    /// assert_eq!("RAlt".parse(), Ok(Mods::RALT));
+    /// assert_eq!("Meta".parse(), Ok(Mods::META));
+    /// assert_eq!("Hyper".parse(), Ok(Mods::HYPER));
+    /// assert_eq!("AltGr".parse(), Ok(Mods::ALTGR));
+    /// assert_eq!("CapsLock".parse(), Ok(Mods::CAPS_LOCK));
+    /// assert_eq!("NumLock".parse(), Ok(Mods::NUM_LOCK));
+    /// assert_eq!("Numpad".parse(), Ok(Mods::NUMPAD));
     ///
     /// // Aliases
     /// assert_eq!("Control".parse(), Ok(Mods::CTRL));
     /// assert_eq!("Command".parse(), Ok(Mods::CMD));
     /// assert_eq!("Option".parse(), Ok(Mods::ALT));
+    /// assert_eq!("LeftShift".parse(), Ok(Mods::LSHIFT));
+    /// assert_eq!("RightCtrl".parse(), Ok(Mods::RCTRL));
+    /// assert_eq!("AltGraph".parse(), Ok(Mods::ALTGR));
+    /// assert_eq!("Num".parse(), Ok(Mods::NUMPAD));
+    /// assert_eq!("Kp".parse(), Ok(Mods::NUMPAD));
     ///
     /// // Error cases
     /// assert!("Fooo".parse::<Mods>().is_err());
@@ -463,6 +914,26 @@ impl FromStr for Mods {
             "Alt" | "alt" | "ALT" | "Option" | "option" | "OPTION" => Ok(Self::ALT),
             "Super" | "super" | "SUPER" => Ok(Self::SUPER),
             "Shift" | "shift" | "SHIFT" => Ok(Self::SHIFT),
+            "LCtrl" | "lctrl" | "LCTRL" | "LeftCtrl" | "leftctrl" | "LEFTCTRL" => Ok(Self::LCTRL),
+            "RCtrl" | "rctrl" | "RCTRL" | "RightCtrl" | "rightctrl" | "RIGHTCTRL" => {
+                Ok(Self::RCTRL)
+            }
+            "LAlt" | "lalt" | "LALT" | "LeftAlt" | "leftalt" | "LEFTALT" => Ok(Self::LALT),
+            "RAlt" | "ralt" | "RALT" | "RightAlt" | "rightalt" | "RIGHTALT" => Ok(Self::RALT),
+            "LShift" | "lshift" | "LSHIFT" | "LeftShift" | "leftshift" | "LEFTSHIFT" => {
+                Ok(Self::LSHIFT)
+            }
+            "RShift" | "rshift" | "RSHIFT" | "RightShift" | "rightshift" | "RIGHTSHIFT" => {
+                Ok(Self::RSHIFT)
+            }
+            "Meta" | "meta" | "META" => Ok(Self::META),
+            "Hyper" | "hyper" | "HYPER" => Ok(Self::HYPER),
+            "AltGr" | "altgr" | "ALTGR" | "AltGraph" | "altgraph" | "ALTGRAPH" => Ok(Self::ALTGR),
+            "CapsLock" | "capslock" | "CAPSLOCK" => Ok(Self::CAPS_LOCK),
+            "NumLock" | "numlock" | "NUMLOCK" => Ok(Self::NUM_LOCK),
+            "Numpad" | "numpad" | "NUMPAD" | "Num" | "num" | "NUM" | "Kp" | "kp" | "KP" => {
+                Ok(Self::NUMPAD)
+            }
             "" => Err(Error::EmptyModifier),
             _ => Err(Error::UnknownModifier(s.into())),
         }
@@ -473,24 +944,46 @@ impl fmt::Display for Mods {
     /// Generate a string representation of the modifier key following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
     ///
     /// When multiple modifiers are pressed at once they are joined with "+". When no modifier key is pressed, it generates an empty string.
+    /// The virtual modifiers `MOD` and `SUPER` are not resolved so that the generated string is portable across
+    /// platforms.
     ///
     /// ```
     /// use keybinds::Mods;
     ///
     /// assert_eq!(format!("{}", Mods::CTRL), "Ctrl");
     /// assert_eq!(format!("{}", Mods::CTRL | Mods::CMD | Mods::ALT), "Ctrl+Cmd+Alt");
+    /// assert_eq!(format!("{}", Mods::MOD | Mods::SHIFT), "Mod+Shift");
+    /// assert_eq!(format!("{}", Mods::RALT | Mods::LSHIFT), "RAlt+LShift");
+    /// assert_eq!(format!("{}", Mods::ALTGR | Mods::CAPS_LOCK), "AltGr+CapsLock");
     /// assert_eq!(format!("{}", Mods::NONE), "");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut first = true;
         for (value, name) in [
+            (Mods::LCTRL, "LCtrl"),
+            (Mods::RCTRL, "RCtrl"),
             (Mods::CTRL, "Ctrl"),
             (Mods::CMD, "Cmd"),
+            (Mods::MOD, "Mod"),
+            (Mods::LALT, "LAlt"),
+            (Mods::RALT, "RAlt"),
             (Mods::ALT, "Alt"),
+            (Mods::ALTGR, "AltGr"),
+            (Mods::META, "Meta"),
+            (Mods::HYPER, "Hyper"),
             (Mods::WIN, "Win"),
+            (Mods::SUPER, "Super"),
+            (Mods::LSHIFT, "LShift"),
+            (Mods::RSHIFT, "RShift"),
             (Mods::SHIFT, "Shift"),
+            (Mods::CAPS_LOCK, "CapsLock"),
+            (Mods::NUM_LOCK, "NumLock"),
+            (Mods::NUMPAD, "Numpad"),
         ] {
-            if self.contains(value) {
+            let sided = Mods::SIDES.iter().any(|&(l, r)| {
+                value == l.intersection(r) && self.intersects(l.symmetric_difference(r))
+            });
+            if self.contains(value) && !sided {
                 if first {
                     first = false;
                 } else {
@@ -503,14 +996,211 @@ impl fmt::Display for Mods {
     }
 }
 
+// Compact representation of `Key` in 3 bytes. The upper 3 bits are the kind of the key and the lower 21 bits are the
+// payload; a character, the index of an interned string, or the index of a named key in `NAMED_KEYS`. The `Code` of
+// `Key::Physical` is stored in `KeyInput::code` instead. With this representation, `KeyInput` consists of 3 bytes key,
+// 1 byte `Option<Code>`, and 4 bytes `Mods` so it fits to 1 word.
+#[derive(Clone, Copy)]
+struct PackedKey([u8; 3]);
+
+const PACKED_PAYLOAD_BITS: u32 = 21;
+const PACKED_PAYLOAD_MASK: u32 = (1 << PACKED_PAYLOAD_BITS) - 1;
+const PACKED_CHAR: u32 = 0;
+const PACKED_NAMED: u32 = 1;
+const PACKED_PHYSICAL: u32 = 2;
+const PACKED_STR: u32 = 3;
+
+macro_rules! packed_key {
+    ($($name:ident,)+) => {
+        // Indices of the named keys in `NAMED_KEYS`
+        enum NamedKeyIndex {
+            $($name,)+
+        }
+
+        const NAMED_KEYS: &[Key] = &[$(Key::$name,)+];
+
+        impl PackedKey {
+            fn new(key: Key) -> Self {
+                let (kind, payload) = match key {
+                    Key::Char(c) => (PACKED_CHAR, c as u32),
+                    Key::Physical(_) => (PACKED_PHYSICAL, 0),
+                    Key::Str(s) => (PACKED_STR, s.index()),
+                    $(Key::$name => (PACKED_NAMED, NamedKeyIndex::$name as u32),)+
+                };
+                let [a, b, c, _] = (kind << PACKED_PAYLOAD_BITS | payload).to_le_bytes();
+                Self([a, b, c])
+            }
+        }
+    };
+}
+
+// All named keys must be listed here. Otherwise `PackedKey::new` fails to compile.
+packed_key! {
+    Up,
+    Right,
+    Down,
+    Left,
+    Enter,
+    Backspace,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Esc,
+    Tab,
+    Insert,
+    Copy,
+    Cut,
+    Paste,
+    Clear,
+    Undo,
+    Redo,
+    ZoomIn,
+    ZoomOut,
+    ZoomToggle,
+    ScrollLock,
+    NumLock,
+    FnLock,
+    PrintScreen,
+    Menu,
+    Play,
+    Pause,
+    PlayPause,
+    Stop,
+    Rewind,
+    NextTrack,
+    PrevTrack,
+    VolumeUp,
+    VolumeDown,
+    Mute,
+    Help,
+    Accept,
+    Again,
+    Attn,
+    Cancel,
+    Execute,
+    Find,
+    Props,
+    Select,
+    CrSel,
+    EraseEof,
+    ExSel,
+    BrightnessDown,
+    BrightnessUp,
+    Eject,
+    LogOff,
+    Power,
+    PowerOff,
+    Hibernate,
+    Standby,
+    WakeUp,
+    FastForward,
+    Record,
+    BrowserBack,
+    BrowserFavorites,
+    BrowserForward,
+    BrowserHome,
+    BrowserRefresh,
+    BrowserSearch,
+    BrowserStop,
+    LaunchApplication1,
+    LaunchApplication2,
+    LaunchCalendar,
+    LaunchContacts,
+    LaunchMail,
+    LaunchMediaPlayer,
+    LaunchMusicPlayer,
+    LaunchPhone,
+    LaunchScreenSaver,
+    LaunchSpreadsheet,
+    LaunchWebBrowser,
+    LaunchWebCam,
+    LaunchWordProcessor,
+    Close,
+    New,
+    Open,
+    Print,
+    Save,
+    SpellCheck,
+    MailForward,
+    MailReply,
+    MailSend,
+    MouseLeft,
+    MouseRight,
+    MouseMiddle,
+    MouseBack,
+    MouseForward,
+    WheelUp,
+    WheelDown,
+    WheelLeft,
+    WheelRight,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
+    F25,
+    F26,
+    F27,
+    F28,
+    F29,
+    F30,
+    F31,
+    F32,
+    F33,
+    F34,
+    F35,
+    Unidentified,
+    Ignored,
+}
+
+impl PackedKey {
+    fn unpack(self, code: Option<Code>) -> Key {
+        let [a, b, c] = self.0;
+        let bits = u32::from_le_bytes([a, b, c, 0]);
+        let payload = bits & PACKED_PAYLOAD_MASK;
+        match bits >> PACKED_PAYLOAD_BITS {
+            PACKED_CHAR => Key::Char(char::from_u32(payload).unwrap()), // Packed from a valid character
+            PACKED_PHYSICAL => code.map_or(Key::Unidentified, Key::Physical),
+            PACKED_STR => Key::Str(KeyStr::from_index(payload)),
+            _ => NAMED_KEYS[payload as usize],
+        }
+    }
+}
+
 /// Single key input by pressing a key and modifiers.
 ///
 /// This struct is equivalent to a key combination in the [syntax document](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md)
 /// such as "Ctrl+x".
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
+///
+/// The physical key and the <kbd>Shift</kbd> modifier kept for it (see [`KeyInput::with_code`]) are supplementary
+/// information to match physical key bindings. They are not considered by the [`PartialEq`] and [`Hash`]
+/// implementations so that key inputs converted from events of GUI frameworks are equal to the ones created by
+/// [`KeyInput::new`].
+#[derive(Clone, Copy)]
 pub struct KeyInput {
-    key: Key,
+    key: PackedKey,
+    code: Option<Code>,
     mods: Mods,
 }
 
@@ -547,39 +1237,155 @@ impl KeyInput {
         let key = key.into();
         let mut mods = mods.into();
         if !key.is_named() {
-            mods.remove(Mods::SHIFT); // Ensure the invariant
+            mods.remove(Mods::ANY_SHIFT); // Ensure the invariant
+        }
+        Self::from_parts(key, mods, None)
+    }
+
+    /// Create a new [`KeyInput`] instance with both the logical key and the physical key. This is useful to convert
+    /// key events of GUI frameworks which report both keys. A key binding matches to the input when either of the
+    /// logical key or the physical key matches.
+    ///
+    /// Unlike [`KeyInput::new`], the <kbd>Shift</kbd> modifier is kept because it is necessary to match the physical
+    /// key such as `Shift+[KeyW]`. It can be obtained by [`KeyInput::physical_mods`]. When the logical key is not a
+    /// named key, it is removed from [`KeyInput::mods`] and ignored on matching the logical key and comparing the
+    /// inputs as described in [`KeyInput::new`].
+    ///
+    /// ```
+    /// use keybinds::{KeyInput, KeySeq, Key, Code, Mods, Match};
+    ///
+    /// // Shift+W on QWERTY layout
+    /// let input = KeyInput::with_code('W', Mods::SHIFT, Code::KeyW);
+    /// assert_eq!(input.key(), Key::Char('W'));
+    /// assert_eq!(input.mods(), Mods::NONE);
+    /// assert_eq!(input.physical_mods(), Mods::SHIFT);
+    /// assert_eq!(input.code(), Some(Code::KeyW));
+    ///
+    /// // The physical key is supplementary information
+    /// assert_eq!(input, KeyInput::new('W', Mods::NONE));
+    ///
+    /// // Both the logical and physical key bindings match
+    /// for seq in ["W", "Shift+[KeyW]"] {
+    ///     let seq: KeySeq = seq.parse().unwrap();
+    ///     assert_eq!(seq.match_to(&[input]), Match::Matched);
+    /// }
+    /// ```
+    pub fn with_code<K, M>(key: K, mods: M, code: Code) -> Self
+    where
+        K: Into<Key>,
+        M: Into<Mods>,
+    {
+        Self::from_parts(key.into(), mods.into(), Some(code))
+    }
+
+    // The physical key of `Key::Physical` is always the key itself
+    fn from_parts(key: Key, mods: Mods, code: Option<Code>) -> Self {
+        let code = match key {
+            Key::Physical(code) => Some(code),
+            _ => code,
+        };
+        Self {
+            key: PackedKey::new(key),
+            code,
+            mods,
         }
-        KeyInput { key, mods }
     }
 
     /// Return the [`Key`] of the input.
     pub fn key(&self) -> Key {
-        self.key
+        self.key.unpack(self.code)
     }
 
     /// Return the [`Mods`] of the input.
     pub fn mods(&self) -> Mods {
+        self.logical_mods()
+    }
+
+    /// Return the [`Mods`] to match the physical key of the input. Unlike [`KeyInput::mods`], <kbd>Shift</kbd>
+    /// modifier is not removed when the input was created by [`KeyInput::with_code`].
+    ///
+    /// ```
+    /// use keybinds::{KeyInput, Code, Mods};
+    ///
+    /// let input = KeyInput::with_code('A', Mods::CTRL | Mods::SHIFT, Code::KeyA);
+    /// assert_eq!(input.mods(), Mods::CTRL);
+    /// assert_eq!(input.physical_mods(), Mods::CTRL | Mods::SHIFT);
+    ///
+    /// let input = KeyInput::new('A', Mods::CTRL | Mods::SHIFT);
+    /// assert_eq!(input.physical_mods(), Mods::CTRL);
+    /// ```
+    pub fn physical_mods(&self) -> Mods {
         self.mods
     }
-}
 
-impl FromStr for KeyInput {
-    type Err = Error;
+    /// Return the physical key of the input if it is known. See [`KeyInput::with_code`] for more details. The physical
+    /// key of [`Key::Physical`] is the key itself.
+    ///
+    /// ```
+    /// use keybinds::{KeyInput, Key, Code, Mods};
+    ///
+    /// assert_eq!(KeyInput::new('a', Mods::NONE).code(), None);
+    /// assert_eq!(KeyInput::with_code('a', Mods::NONE, Code::KeyA).code(), Some(Code::KeyA));
+    /// assert_eq!(KeyInput::new(Key::Physical(Code::KeyA), Mods::NONE).code(), Some(Code::KeyA));
+    /// ```
+    pub fn code(&self) -> Option<Code> {
+        self.code
+    }
 
-    /// Parse the key input from [`str`] following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
+    // Modifiers to match to the logical key. Shift modifier for unnamed keys may remain when the input has a physical
+    // key. See `KeyInput::with_code`.
+    fn logical_mods(&self) -> Mods {
+        if self.key().is_named() {
+            self.mods
+        } else {
+            self.mods - Mods::ANY_SHIFT
+        }
+    }
+
+    /// Resolve the virtual modifiers `MOD` and `SUPER` of the input into the actual modifiers on the platform. See
+    /// [`Mods::resolve`] for more details.
     ///
     /// ```
-    /// use keybinds::{Key, Mods, KeyInput};
+    /// use keybinds::{KeyInput, Mods, Platform};
     ///
-    /// assert_eq!("a".parse(), Ok(KeyInput::new('a', Mods::NONE)));
-    /// assert_eq!("Ctrl+x".parse(), Ok(KeyInput::new('x', Mods::CTRL)));
-    /// assert_eq!("Alt+Shift+Enter".parse(), Ok(KeyInput::new(Key::Enter, Mods::ALT | Mods::SHIFT)));
+    /// let input = KeyInput::new('x', Mods::MOD);
     ///
-    /// assert!("".parse::<KeyInput>().is_err());
-    /// assert!("Foooo".parse::<KeyInput>().is_err());
-    /// assert!("Shift+x".parse::<KeyInput>().is_err()); // Violates Shift modifier invariant
+    /// assert_eq!(input.resolve(Platform::MacOS), KeyInput::new('x', Mods::CMD));
+    /// assert_eq!(input.resolve(Platform::Windows), KeyInput::new('x', Mods::CTRL));
     /// ```
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    pub fn resolve(self, platform: Platform) -> Self {
+        Self {
+            mods: self.mods.resolve(platform),
+            ..self
+        }
+    }
+
+    /// Parse the key input from [`str`] like [`KeyInput::from_str`] but <kbd>Shift</kbd> modifier with a character
+    /// key is normalized into the shifted character on the keyboard layout. For example, `Shift+a` is parsed as `A`
+    /// and `Shift+1` is parsed as `!` on the US layout. <kbd>Shift</kbd> modifier with an already shifted character
+    /// such as `Shift+A` is redundant and it is removed. See [`Layout`] for more details.
+    ///
+    /// ```
+    /// use keybinds::{KeyInput, Key, Layout, Mods};
+    ///
+    /// assert_eq!(KeyInput::parse_with_layout("Shift+a", Layout::Us), Ok(KeyInput::new('A', Mods::NONE)));
+    /// assert_eq!(KeyInput::parse_with_layout("Ctrl+Shift+1", Layout::Us), Ok(KeyInput::new('!', Mods::CTRL)));
+    /// assert_eq!(KeyInput::parse_with_layout("Ctrl+Shift+2", Layout::German), Ok(KeyInput::new('"', Mods::CTRL)));
+    ///
+    /// // Written with and without Shift are the same
+    /// assert_eq!(KeyInput::parse_with_layout("Ctrl+Shift+P", Layout::Us), Ok(KeyInput::new('P', Mods::CTRL)));
+    ///
+    /// // Named keys keep Shift modifier
+    /// assert_eq!(KeyInput::parse_with_layout("Shift+Up", Layout::Us), Ok(KeyInput::new(Key::Up, Mods::SHIFT)));
+    ///
+    /// // The character is not typed on the layout
+    /// assert!(KeyInput::parse_with_layout("Shift+€", Layout::Us).is_err());
+    /// ```
+    pub fn parse_with_layout(s: &str, layout: Layout) -> Result<Self, Error> {
+        Self::parse_on(s, Some(layout))
+    }
+
+    fn parse_on(s: &str, layout: Option<Layout>) -> Result<Self, Error> {
         let mut s = s.trim_ascii().split('+');
         let mut cur = s.next().unwrap(); // Iterator by `.split()` is never empty
         let mut mods = Mods::NONE;
@@ -588,16 +1394,140 @@ impl FromStr for KeyInput {
                 mods |= cur.parse()?;
                 cur = next;
             } else {
-                let key: Key = cur.parse()?;
+                let mut key: Key = cur.parse()?;
                 if mods.contains(Mods::SHIFT) && !key.is_named() {
-                    return Err(Error::ShiftUnavailable(key));
+                    let shifted = match (key, layout) {
+                        // Shift with an already shifted character like `Shift+A` is redundant
+                        (Key::Char(c), Some(layout)) if layout.is_shifted(c) => Some(c),
+                        (Key::Char(c), Some(layout)) => layout.shift(c),
+                        _ => None,
+                    };
+                    let Some(c) = shifted else {
+                        return Err(Error::ShiftUnavailable(key));
+                    };
+                    key = Key::Char(c);
+                    mods.remove(Mods::ANY_SHIFT);
+                }
+                return Ok(Self::from_parts(key, mods, None));
+            }
+        }
+    }
+
+    // Check if the key input in a key sequence matches to the actual key input
+    fn matches_on(&self, input: &Self, platform: Platform) -> bool {
+        let mods = self.mods.resolve(platform);
+        match self.key() {
+            Key::Physical(code) if input.code == Some(code) => {
+                mods.matches(input.mods.resolve(platform))
+            }
+            key => {
+                let mut actual = input.logical_mods();
+                if !key.is_named() && !mods.contains(Mods::ALTGR) {
+                    actual.remove(Mods::ALTGR);
                 }
-                return Ok(Self { key, mods });
+                key == input.key() && mods.matches(actual.resolve(platform))
             }
         }
     }
 }
 
+/// Context to parse and match [`KeyInput`] values in [`Keybinds`][crate::Keybinds]. It consists of the platform set
+/// by [`Keybinds::set_platform`][crate::Keybinds::set_platform], the keyboard layout set by
+/// [`Keybinds::set_layout`][crate::Keybinds::set_layout], and the normalization for legacy terminals set by
+/// [`Keybinds::set_terminal_normalization`][crate::Keybinds::set_terminal_normalization].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KeyContext {
+    pub(crate) platform: Platform,
+    pub(crate) layout: Option<Layout>,
+    pub(crate) terminal: bool,
+}
+
+impl Default for KeyContext {
+    /// Create the context with the current platform, no keyboard layout, and no normalization for terminals.
+    fn default() -> Self {
+        Self {
+            platform: Platform::current(),
+            layout: None,
+            terminal: false,
+        }
+    }
+}
+
+impl Input for KeyInput {
+    type Context = KeyContext;
+
+    fn parse(s: &str) -> Result<Self, Error> {
+        Self::parse_on(s, None)
+    }
+
+    fn parse_in(s: &str, context: &KeyContext) -> Result<Self, Error> {
+        Self::parse_on(s, context.layout)
+    }
+
+    fn matches(&self, actual: &Self) -> bool {
+        self.matches_on(actual, Platform::current())
+    }
+
+    fn matches_in(&self, actual: &Self, context: &KeyContext) -> bool {
+        if context.terminal {
+            let expected = self.normalize_for_terminal();
+            expected.matches_on(&actual.normalize_for_terminal(), context.platform)
+        } else {
+            self.matches_on(actual, context.platform)
+        }
+    }
+
+    fn is_ignored(&self) -> bool {
+        self.key() == Key::Ignored
+    }
+}
+
+impl PartialEq for KeyInput {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key() && self.logical_mods() == other.logical_mods()
+    }
+}
+
+impl Eq for KeyInput {}
+
+impl Hash for KeyInput {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+        self.logical_mods().hash(state);
+    }
+}
+
+impl fmt::Debug for KeyInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyInput")
+            .field("key", &self.key())
+            .field("mods", &self.mods)
+            .field("code", &self.code)
+            .finish()
+    }
+}
+
+impl FromStr for KeyInput {
+    type Err = Error;
+
+    /// Parse the key input from [`str`] following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
+    ///
+    /// ```
+    /// use keybinds::{Key, Mods, KeyInput};
+    ///
+    /// assert_eq!("a".parse(), Ok(KeyInput::new('a', Mods::NONE)));
+    /// assert_eq!("Ctrl+x".parse(), Ok(KeyInput::new('x', Mods::CTRL)));
+    /// assert_eq!("Alt+Shift+Enter".parse(), Ok(KeyInput::new(Key::Enter, Mods::ALT | Mods::SHIFT)));
+    ///
+    /// assert!("".parse::<KeyInput>().is_err());
+    /// assert!("Foooo".parse::<KeyInput>().is_err());
+    /// assert!("Shift+x".parse::<KeyInput>().is_err()); // Violates Shift modifier invariant
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_on(s, None)
+    }
+}
+
 impl<K: Into<Key>> From<K> for KeyInput {
     /// Convert a single key with no modifiers into [`KeyInput`].
     ///
@@ -625,10 +1555,11 @@ impl fmt::Display for KeyInput {
     /// );
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.mods != Mods::NONE {
-            write!(f, "{}+", self.mods)?;
+        let mods = self.logical_mods();
+        if mods != Mods::NONE {
+            write!(f, "{mods}+")?;
         }
-        write!(f, "{}", self.key)
+        write!(f, "{}", self.key())
     }
 }
 
@@ -683,11 +1614,14 @@ pub enum Match {
 ///
 /// assert_eq!(seq.as_slice(), &[KeyInput::from('a'), KeyInput::from('c')]);
 /// ```
-///
-#[derive(Clone, PartialEq, Eq, Default, Hash, Debug)]
-pub struct KeySeq(SmallVec<[KeyInput; 2]>);
+pub type KeySeq = GenericKeySeq<KeyInput>;
 
-impl KeySeq {
+/// Sequence of inputs. The type parameter `I` is the type of inputs. See [`Input`] for input sources other than
+/// keyboards and [`KeySeq`] for key sequences of keyboard inputs.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GenericKeySeq<I>(SmallVec<[I; 2]>);
+
+impl<I: Input> GenericKeySeq<I> {
     /// Match the given inputs to the key sequence. The result [`Match`] is one of following cases:
     ///
     /// - the key sequence completely matched the input
@@ -711,12 +1645,19 @@ impl KeySeq {
     /// assert_eq!(seq.match_to(&unmatch_1), Match::Unmatch);
     /// assert_eq!(seq.match_to(&unmatch_2), Match::Unmatch);
     /// ```
-    pub fn match_to(&self, inputs: &[KeyInput]) -> Match {
+    ///
+    /// The virtual modifiers `MOD` and `SUPER` are resolved for the current platform. To match them on another
+    /// platform, use [`KeySeq::match_to_on`].
+    pub fn match_to(&self, inputs: &[I]) -> Match {
+        self.match_to_in(inputs, &I::Context::default())
+    }
+
+    pub(crate) fn match_to_in(&self, inputs: &[I], context: &I::Context) -> Match {
         let mut ls = self.0.iter();
         let mut rs = inputs.iter();
         loop {
             match (ls.next(), rs.next()) {
-                (Some(l), Some(r)) if l != r => return Match::Unmatch,
+                (Some(l), Some(r)) if !l.matches_in(r, context) => return Match::Unmatch,
                 (Some(_), Some(_)) => continue,
                 (Some(_), None) => return Match::Prefix,
                 (None, Some(_)) => return Match::Unmatch,
@@ -734,7 +1675,7 @@ impl KeySeq {
     ///
     /// assert_eq!(seq.as_slice(), &[KeyInput::from('a'), KeyInput::from('b')]);
     /// ```
-    pub fn as_slice(&self) -> &[KeyInput] {
+    pub fn as_slice(&self) -> &[I] {
         self.0.as_slice()
     }
 
@@ -749,7 +1690,7 @@ impl KeySeq {
     ///
     /// assert_eq!(seq.as_slice(), &[KeyInput::from('a'), KeyInput::from('x')]);
     /// ```
-    pub fn as_mut_slice(&mut self) -> &mut [KeyInput] {
+    pub fn as_mut_slice(&mut self) -> &mut [I] {
         self.0.as_mut_slice()
     }
 
@@ -779,7 +1720,7 @@ impl KeySeq {
     /// let len = seq.as_slice().len();
     /// assert!(len == 2 || len == 3);
     /// ```
-    pub fn push(&mut self, input: KeyInput) {
+    pub fn push(&mut self, input: I) {
         self.0.push(input);
     }
 
@@ -796,12 +1737,86 @@ impl KeySeq {
     ///
     /// assert_eq!(seq.as_slice(), &[prefix, 'a'.into(), 'b'.into()]);
     /// ```
-    pub fn insert(&mut self, idx: usize, input: KeyInput) {
+    pub fn insert(&mut self, idx: usize, input: I) {
         self.0.insert(idx, input);
     }
+
+    pub(crate) fn parse_in(s: &str, context: &I::Context) -> Result<Self, Error> {
+        let vec: SmallVec<_> = s
+            .split_ascii_whitespace()
+            .map(|input| I::parse_in(input, context))
+            .collect::<Result<_, _>>()?;
+        if vec.is_empty() {
+            return Err(Error::EmptyKeySequence);
+        }
+        Ok(Self(vec))
+    }
 }
 
-impl FromStr for KeySeq {
+impl KeySeq {
+    /// Parse a key sequence from [`str`] like [`KeySeq::from_str`] but <kbd>Shift</kbd> modifier with a character key
+    /// is normalized into the shifted character on the keyboard layout. See [`KeyInput::parse_with_layout`] for more
+    /// details.
+    ///
+    /// ```
+    /// use keybinds::{KeySeq, KeyInput, Layout, Mods};
+    ///
+    /// // Key bindings written with and without Shift are the same
+    /// assert_eq!(
+    ///     KeySeq::parse_with_layout("Ctrl+Shift+p Shift+1", Layout::Us),
+    ///     "Ctrl+P !".parse(),
+    /// );
+    ///
+    /// assert!(KeySeq::parse_with_layout("", Layout::Us).is_err());
+    /// ```
+    pub fn parse_with_layout(s: &str, layout: Layout) -> Result<Self, Error> {
+        let context = KeyContext {
+            layout: Some(layout),
+            ..KeyContext::default()
+        };
+        Self::parse_in(s, &context)
+    }
+
+    /// Match the given inputs to the key sequence on the platform. The virtual modifiers `MOD` and `SUPER` in both the
+    /// key sequence and the inputs are resolved for the platform before matching. See [`KeySeq::match_to`] for the
+    /// result.
+    ///
+    /// ```
+    /// use keybinds::{KeySeq, KeyInput, Match, Mods, Platform};
+    ///
+    /// let seq: KeySeq = "Mod+x".parse().unwrap();
+    ///
+    /// let ctrl_x = [KeyInput::new('x', Mods::CTRL)];
+    /// let cmd_x = [KeyInput::new('x', Mods::CMD)];
+    ///
+    /// assert_eq!(seq.match_to_on(&ctrl_x, Platform::Linux), Match::Matched);
+    /// assert_eq!(seq.match_to_on(&cmd_x, Platform::Linux), Match::Unmatch);
+    /// assert_eq!(seq.match_to_on(&ctrl_x, Platform::MacOS), Match::Unmatch);
+    /// assert_eq!(seq.match_to_on(&cmd_x, Platform::MacOS), Match::Matched);
+    /// ```
+    pub fn match_to_on(&self, inputs: &[KeyInput], platform: Platform) -> Match {
+        let context = KeyContext {
+            platform,
+            ..KeyContext::default()
+        };
+        self.match_to_in(inputs, &context)
+    }
+}
+
+impl Default for KeySeq {
+    /// Create an empty key sequence.
+    ///
+    /// ```
+    /// use keybinds::KeySeq;
+    ///
+    /// assert!(KeySeq::default().as_slice().is_empty());
+    /// ```
+    fn default() -> Self {
+        Self(SmallVec::new())
+    }
+}
+
+impl<I: Input> FromStr for GenericKeySeq<I> {
     type Err = Error;
 
     /// Parse a key sequence from [`str`] following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
@@ -830,14 +1845,7 @@ impl FromStr for KeySeq {
     /// assert!("x Fooo".parse::<KeySeq>().is_err()); // Unknown named key
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let vec: SmallVec<_> = s
-            .split_ascii_whitespace()
-            .map(|key| key.parse())
-            .collect::<Result<_, _>>()?;
-        if vec.is_empty() {
-            return Err(Error::EmptyKeySequence);
-        }
-        Ok(Self(vec))
+        Self::parse_in(s, &I::Context::default())
     }
 }
 
@@ -871,7 +1879,7 @@ impl<const N: usize, I: Into<KeyInput>> From<[I; N]> for KeySeq {
     }
 }
 
-impl<I: Into<KeyInput>> FromIterator<I> for KeySeq {
+impl<I: Input, T: Into<I>> FromIterator<T> for GenericKeySeq<I> {
     /// Collect a key sequence from an iterator of key inputs.
     ///
     /// ```
@@ -882,15 +1890,15 @@ impl<I: Into<KeyInput>> FromIterator<I> for KeySeq {
     ///
     /// assert_eq!(format!("{seq}"), "Ctrl+a Ctrl+a Ctrl+a");
     /// ```
-    fn from_iter<T>(iter: T) -> Self
+    fn from_iter<U>(iter: U) -> Self
     where
-        T: IntoIterator<Item = I>,
+        U: IntoIterator<Item = T>,
     {
         Self(iter.into_iter().map(Into::into).collect())
     }
 }
 
-impl fmt::Display for KeySeq {
+impl<I: Input> fmt::Display for GenericKeySeq<I> {
     /// Generate a string representation of the key sequence following the
     /// [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
     ///
@@ -917,11 +1925,11 @@ impl fmt::Display for KeySeq {
     }
 }
 
-impl<I: Into<KeyInput>> Extend<I> for KeySeq {
+impl<I: Input, T: Into<I>> Extend<T> for GenericKeySeq<I> {
     /// Extend the key sequence with the iterator of key inputs. See [`KeySeq::push`] for an example.
-    fn extend<T>(&mut self, iter: T)
+    fn extend<U>(&mut self, iter: U)
     where
-        T: IntoIterator<Item = I>,
+        U: IntoIterator<Item = T>,
     {
         self.0.extend(iter.into_iter().map(Into::into));
     }
@@ -1049,6 +2057,387 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_physical_key() {
+        for (input, expected) in [
+            (
+                "[KeyW]",
+                KeyInput::new(Key::Physical(Code::KeyW), Mods::NONE),
+            ),
+            (
+                "Shift+[Digit1]",
+                KeyInput::new(Key::Physical(Code::Digit1), Mods::SHIFT),
+            ),
+            (
+                "Ctrl+[Enter]",
+                KeyInput::new(Key::Physical(Code::Enter), Mods::CTRL),
+            ),
+            ("[", KeyInput::new('[', Mods::NONE)),
+            ("]", KeyInput::new(']', Mods::NONE)),
+        ] {
+            assert_eq!(input.parse(), Ok(expected), "input={input:?}");
+            assert_eq!(
+                expected.to_string().parse(),
+                Ok(expected),
+                "input={input:?}",
+            );
+        }
+
+        for (input, expected) in [
+            ("[]", Error::EmptyKey),
+            ("[Foo]", Error::UnknownKey("Foo".into())),
+            ("[keyw]", Error::UnknownKey("keyw".into())),
+            ("[KeyW", Error::UnknownKey("[KeyW".into())),
+        ] {
+            assert_eq!(input.parse::<KeyInput>(), Err(expected), "input={input:?}");
+        }
+    }
+
+    #[test]
+    fn key_input_eq_ignores_physical_key() {
+        use std::collections::HashMap;
+
+        let shift_x = KeyInput::with_code('X', Mods::CTRL | Mods::SHIFT, Code::KeyX);
+        assert_eq!(shift_x, KeyInput::new('X', Mods::CTRL));
+        assert_eq!(shift_x.mods(), Mods::CTRL);
+        assert_eq!(shift_x.physical_mods(), Mods::CTRL | Mods::SHIFT);
+        assert_eq!(shift_x.to_string(), "Ctrl+X");
+        assert_ne!(shift_x, KeyInput::new('x', Mods::CTRL));
+
+        let shift_up = KeyInput::with_code(Key::Up, Mods::SHIFT, Code::ArrowUp);
+        assert_eq!(shift_up, KeyInput::new(Key::Up, Mods::SHIFT));
+        assert_ne!(shift_up, KeyInput::new(Key::Up, Mods::NONE));
+
+        let mut map = HashMap::new();
+        map.insert(KeyInput::new('X', Mods::CTRL), 1);
+        map.insert(KeyInput::new(Key::Up, Mods::SHIFT), 2);
+        assert_eq!(map.get(&shift_x), Some(&1));
+        assert_eq!(map.get(&shift_up), Some(&2));
+    }
+
+    #[test]
+    fn match_physical_key() {
+        let w = KeyInput::with_code('w', Mods::NONE, Code::KeyW);
+        let shift_w = KeyInput::with_code('W', Mods::SHIFT, Code::KeyW);
+        let azerty_z = KeyInput::with_code('z', Mods::NONE, Code::KeyW);
+        let no_code = KeyInput::new('w', Mods::NONE);
+
+        for (seq, input, expected) in [
+            ("[KeyW]", w, Match::Matched),
+            ("[KeyW]", azerty_z, Match::Matched),
+            ("[KeyW]", shift_w, Match::Unmatch),
+            ("[KeyW]", no_code, Match::Unmatch),
+            ("Shift+[KeyW]", shift_w, Match::Matched),
+            ("Shift+[KeyW]", w, Match::Unmatch),
+            ("w", w, Match::Matched),
+            ("w", azerty_z, Match::Unmatch),
+            ("W", shift_w, Match::Matched),
+            ("z", azerty_z, Match::Matched),
+            ("[KeyA]", w, Match::Unmatch),
+            ("[KeyW] [KeyW]", w, Match::Prefix),
+        ] {
+            let seq: KeySeq = seq.parse().unwrap();
+            assert_eq!(
+                seq.match_to(&[input]),
+                expected,
+                "seq={seq:?} input={input:?}"
+            );
+        }
+
+        let seq: KeySeq = "[KeyW]".parse().unwrap();
+        let input = KeyInput::new(Key::Physical(Code::KeyW), Mods::NONE);
+        assert_eq!(seq.match_to(&[input]), Match::Matched);
+    }
+
+    #[test]
+    fn side_aware_mods() {
+        for (input, expected) in [
+            ("LCtrl+x", KeyInput::new('x', Mods::LCTRL)),
+            ("rctrl+x", KeyInput::new('x', Mods::RCTRL)),
+            ("LeftAlt+x", KeyInput::new('x', Mods::LALT)),
+            ("RIGHTALT+x", KeyInput::new('x', Mods::RALT)),
+            ("LShift+Up", KeyInput::new(Key::Up, Mods::LSHIFT)),
+            ("RightShift+Up", KeyInput::new(Key::Up, Mods::RSHIFT)),
+            (
+                "LCtrl+RCtrl+x",
+                KeyInput::new('x', Mods::LCTRL | Mods::RCTRL),
+            ),
+        ] {
+            assert_eq!(input.parse(), Ok(expected), "input={input:?}");
+        }
+        assert_eq!(
+            "LShift+a".parse::<KeyInput>(),
+            Err(Error::ShiftUnavailable(Key::Char('a'))),
+        );
+        assert_eq!(
+            KeyInput::new('a', Mods::RSHIFT | Mods::CTRL),
+            KeyInput::new('a', Mods::CTRL),
+        );
+
+        for (seq, mods, expected) in [
+            ("Ctrl+x", Mods::LCTRL, Match::Matched),
+            ("Ctrl+x", Mods::RCTRL, Match::Matched),
+            ("Ctrl+x", Mods::LCTRL | Mods::RCTRL, Match::Matched),
+            ("Ctrl+x", Mods::LCTRL | Mods::ALT, Match::Unmatch),
+            ("LCtrl+x", Mods::LCTRL, Match::Matched),
+            ("LCtrl+x", Mods::CTRL, Match::Unmatch),
+            ("LCtrl+x", Mods::RCTRL, Match::Unmatch),
+            ("LCtrl+x", Mods::LCTRL | Mods::RCTRL, Match::Unmatch),
+            ("RAlt+x", Mods::RALT, Match::Matched),
+            ("RAlt+x", Mods::LALT, Match::Unmatch),
+            ("Ctrl+RAlt+x", Mods::LCTRL | Mods::RALT, Match::Matched),
+            ("LCtrl+RCtrl+x", Mods::LCTRL | Mods::RCTRL, Match::Matched),
+        ] {
+            let seq: KeySeq = seq.parse().unwrap();
+            let input = KeyInput::new('x', mods);
+            assert_eq!(
+                seq.match_to(&[input]),
+                expected,
+                "seq={seq:?} mods={mods:?}"
+            );
+        }
+
+        let seq: KeySeq = "Shift+[KeyW]".parse().unwrap();
+        let input = KeyInput::with_code('W', Mods::LSHIFT, Code::KeyW);
+        assert_eq!(seq.match_to(&[input]), Match::Matched);
+        let seq: KeySeq = "W".parse().unwrap();
+        assert_eq!(seq.match_to(&[input]), Match::Matched);
+    }
+
+    #[test]
+    #[cfg(any(feature = "winit", feature = "iced"))]
+    fn mods_with_altgr() {
+        for (mods, platform, expected) in [
+            (Mods::LCTRL | Mods::RALT, Platform::Windows, Mods::ALTGR),
+            (Mods::CTRL | Mods::ALT, Platform::Windows, Mods::ALTGR),
+            (
+                Mods::LCTRL | Mods::RCTRL | Mods::RALT,
+                Platform::Windows,
+                Mods::RCTRL | Mods::ALTGR,
+            ),
+            (
+                Mods::LCTRL | Mods::LALT | Mods::RALT,
+                Platform::Windows,
+                Mods::LALT | Mods::ALTGR,
+            ),
+            (Mods::RALT, Platform::Linux, Mods::ALTGR),
+            (Mods::ALT, Platform::Linux, Mods::ALTGR),
+            (
+                Mods::LCTRL | Mods::RALT,
+                Platform::Linux,
+                Mods::LCTRL | Mods::ALTGR,
+            ),
+            (
+                Mods::RCTRL | Mods::RALT | Mods::SHIFT,
+                Platform::MacOS,
+                Mods::RCTRL | Mods::SHIFT | Mods::ALTGR,
+            ),
+        ] {
+            assert_eq!(
+                mods.with_altgr(platform),
+                expected,
+                "mods={mods:?} platform={platform:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn extra_mods() {
+        for (input, expected) in [
+            ("Meta+x", KeyInput::new('x', Mods::META)),
+            ("hyper+x", KeyInput::new('x', Mods::HYPER)),
+            ("ALTGR+Enter", KeyInput::new(Key::Enter, Mods::ALTGR)),
+            ("AltGraph+x", KeyInput::new('x', Mods::ALTGR)),
+            ("CapsLock+x", KeyInput::new('x', Mods::CAPS_LOCK)),
+            (
+                "NumLock+Ctrl+Up",
+                KeyInput::new(Key::Up, Mods::NUM_LOCK | Mods::CTRL),
+            ),
+            // `NumLock` is a modifier before `+` and a named key after `+`
+            (
+                "NumLock+NumLock",
+                KeyInput::new(Key::NumLock, Mods::NUM_LOCK),
+            ),
+        ] {
+            assert_eq!(input.parse(), Ok(expected), "input={input:?}");
+        }
+
+        for (seq, input, expected) in [
+            ("Alt+x", KeyInput::new('x', Mods::META), Match::Unmatch),
+            ("Meta+x", KeyInput::new('x', Mods::META), Match::Matched),
+            ("Hyper+x", KeyInput::new('x', Mods::HYPER), Match::Matched),
+            ("Hyper+x", KeyInput::new('x', Mods::SUPER), Match::Unmatch),
+            // AltGr is distinct from Ctrl+Alt
+            (
+                "Ctrl+Alt+@",
+                KeyInput::new('@', Mods::ALTGR),
+                Match::Unmatch,
+            ),
+            ("@", KeyInput::new('@', Mods::ALTGR), Match::Matched),
+            ("AltGr+@", KeyInput::new('@', Mods::ALTGR), Match::Matched),
+            ("AltGr+@", KeyInput::new('@', Mods::NONE), Match::Unmatch),
+            (
+                "Ctrl+@",
+                KeyInput::new('@', Mods::ALTGR | Mods::CTRL),
+                Match::Matched,
+            ),
+            // AltGr is not ignored with named keys
+            (
+                "Enter",
+                KeyInput::new(Key::Enter, Mods::ALTGR),
+                Match::Unmatch,
+            ),
+            // Lock states are ignored unless the key binding specifies them
+            (
+                "Ctrl+x",
+                KeyInput::new('x', Mods::CTRL | Mods::CAPS_LOCK),
+                Match::Matched,
+            ),
+            (
+                "x",
+                KeyInput::new('x', Mods::CAPS_LOCK | Mods::NUM_LOCK),
+                Match::Matched,
+            ),
+            (
+                "CapsLock+x",
+                KeyInput::new('x', Mods::CAPS_LOCK),
+                Match::Matched,
+            ),
+            ("CapsLock+x", KeyInput::new('x', Mods::NONE), Match::Unmatch),
+            (
+                "CapsLock+x",
+                KeyInput::new('x', Mods::NUM_LOCK),
+                Match::Unmatch,
+            ),
+            (
+                "NumLock+Up",
+                KeyInput::new(Key::Up, Mods::NUM_LOCK | Mods::CAPS_LOCK),
+                Match::Matched,
+            ),
+        ] {
+            let seq: KeySeq = seq.parse().unwrap();
+            assert_eq!(
+                seq.match_to(&[input]),
+                expected,
+                "seq={seq:?} input={input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn numpad_keys() {
+        for (input, expected) in [
+            ("Numpad+Enter", KeyInput::new(Key::Enter, Mods::NUMPAD)),
+            ("Num+5", KeyInput::new('5', Mods::NUMPAD)),
+            ("kp+Plus", KeyInput::new('+', Mods::NUMPAD)),
+            (
+                "Ctrl+NUMPAD+*",
+                KeyInput::new('*', Mods::CTRL | Mods::NUMPAD),
+            ),
+        ] {
+            assert_eq!(input.parse(), Ok(expected), "input={input:?}");
+        }
+
+        let plus = KeyInput::new('+', Mods::NONE);
+        let numpad_plus = KeyInput::new('+', Mods::NUMPAD);
+        for (seq, input, expected) in [
+            ("Plus", plus, Match::Matched),
+            ("Plus", numpad_plus, Match::Matched),
+            ("Num+Plus", numpad_plus, Match::Matched),
+            ("Num+Plus", plus, Match::Unmatch),
+            (
+                "Ctrl+Num+Plus",
+                KeyInput::new('+', Mods::CTRL | Mods::NUMPAD),
+                Match::Matched,
+            ),
+            (
+                "Num+Plus",
+                KeyInput::new('+', Mods::CTRL | Mods::NUMPAD),
+                Match::Unmatch,
+            ),
+            (
+                "[NumpadAdd]",
+                KeyInput::with_code('+', Mods::NUMPAD, Code::NumpadAdd),
+                Match::Matched,
+            ),
+        ] {
+            let seq: KeySeq = seq.parse().unwrap();
+            assert_eq!(
+                seq.match_to(&[input]),
+                expected,
+                "seq={seq:?} input={input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_with_layout() {
+        let tests = [
+            ("Shift+a", Layout::Us, KeyInput::new('A', Mods::NONE)),
+            ("Ctrl+Shift+p", Layout::Us, KeyInput::new('P', Mods::CTRL)),
+            ("Ctrl+Shift+P", Layout::Us, KeyInput::new('P', Mods::CTRL)),
+            ("Ctrl+P", Layout::Us, KeyInput::new('P', Mods::CTRL)),
+            ("Shift+!", Layout::Us, KeyInput::new('!', Mods::NONE)),
+            ("Shift+1", Layout::French, KeyInput::new('1', Mods::NONE)),
+            ("Shift+1", Layout::Us, KeyInput::new('!', Mods::NONE)),
+            ("Shift+2", Layout::Uk, KeyInput::new('"', Mods::NONE)),
+            ("Alt+Shift+7", Layout::Jis, KeyInput::new('\'', Mods::ALT)),
+            ("Shift+ö", Layout::German, KeyInput::new('Ö', Mods::NONE)),
+            ("Shift+é", Layout::French, KeyInput::new('2', Mods::NONE)),
+            ("Shift+Up", Layout::Us, KeyInput::new(Key::Up, Mods::SHIFT)),
+            ("Ctrl+x", Layout::Us, KeyInput::new('x', Mods::CTRL)),
+        ];
+        for (input, layout, expected) in tests {
+            assert_eq!(
+                KeyInput::parse_with_layout(input, layout),
+                Ok(expected),
+                "input={input:?} layout={layout:?}",
+            );
+        }
+
+        for (input, layout) in [("Shift+€", Layout::Us), ("Shift+♥", Layout::Uk)] {
+            let key = input.strip_prefix("Shift+").unwrap().parse().unwrap();
+            assert_eq!(
+                KeyInput::parse_with_layout(input, layout),
+                Err(Error::ShiftUnavailable(key)),
+                "input={input:?} layout={layout:?}",
+            );
+        }
+
+        assert_eq!(
+            KeySeq::parse_with_layout("Ctrl+Shift+x Shift+; Shift+Tab", Layout::Us),
+            "Ctrl+X : Shift+Tab".parse(),
+        );
+        assert_eq!(
+            KeySeq::parse_with_layout("", Layout::Us),
+            Err(Error::EmptyKeySequence),
+        );
+    }
+
+    #[test]
+    fn key_input_size() {
+        assert_eq!(std::mem::size_of::<KeyInput>(), 8);
+    }
+
+    #[test]
+    fn pack_key() {
+        let keys = NAMED_KEYS.iter().copied().chain([
+            Key::Char('a'),
+            Key::Char('\0'),
+            Key::Char(char::MAX),
+            Key::Physical(Code::KeyW),
+            "👍🏽".parse().unwrap(),
+        ]);
+        for key in keys {
+            assert_eq!(KeyInput::from(key).key(), key);
+            assert_eq!(KeyInput::with_code(key, Mods::NONE, Code::KeyA).key(), key);
+        }
+        assert_eq!(
+            KeyInput::from(Key::Physical(Code::KeyW)).code(),
+            Some(Code::KeyW)
+        );
+    }
+
     #[test]
     fn conversions() {
         for (actual, expected) in [
@@ -1062,14 +2451,16 @@ mod tests {
             (
                 KeyInput::from('a'),
                 KeyInput {
-                    key: Key::Char('a'),
+                    key: PackedKey::new(Key::Char('a')),
+                    code: None,
                     mods: Mods::NONE,
                 },
             ),
             (
                 KeyInput::from(Key::Enter),
                 KeyInput {
-                    key: Key::Enter,
+                    key: PackedKey::new(Key::Enter),
+                    code: None,
                     mods: Mods::NONE,
                 },
             ),
@@ -1080,27 +2471,29 @@ mod tests {
         for (actual, expected) in [
             (
                 KeySeq::from('a'),
-                KeySeq(smallvec![KeyInput {
-                    key: Key::Char('a'),
+                GenericKeySeq(smallvec![KeyInput {
+                    key: PackedKey::new(Key::Char('a')),
+                    code: None,
                     mods: Mods::NONE,
                 }]),
             ),
             (
                 KeySeq::from(Key::Enter),
-                KeySeq(smallvec![KeyInput::from(Key::Enter)]),
+                GenericKeySeq(smallvec![KeyInput::from(Key::Enter)]),
             ),
             (
                 KeySeq::from([KeyInput::from('x')]),
-                KeySeq(smallvec![KeyInput::from('x')]),
+                GenericKeySeq(smallvec![KeyInput::from('x')]),
             ),
             (
                 KeySeq::from(['x', 'y']),
-                KeySeq(smallvec![KeyInput::from('x'), KeyInput::from('y')]),
+                GenericKeySeq(smallvec![KeyInput::from('x'), KeyInput::from('y')]),
             ),
             (
                 KeySeq::from(KeyInput::new(Key::Enter, Mods::CTRL)),
-                KeySeq(smallvec![KeyInput {
-                    key: Key::Enter,
+                GenericKeySeq(smallvec![KeyInput {
+                    key: PackedKey::new(Key::Enter),
+                    code: None,
                     mods: Mods::CTRL,
                 }]),
             ),
@@ -1116,6 +2509,179 @@ mod tests {
         assert_eq!(seq.as_slice(), &['a'.into(), 'x'.into(), 'c'.into()]);
     }
 
+    #[test]
+    fn parse_extended_named_keys() {
+        for key in [
+            Key::Accept,
+            Key::Again,
+            Key::Attn,
+            Key::Cancel,
+            Key::Execute,
+            Key::Find,
+            Key::Props,
+            Key::Select,
+            Key::CrSel,
+            Key::EraseEof,
+            Key::ExSel,
+            Key::BrightnessDown,
+            Key::BrightnessUp,
+            Key::Eject,
+            Key::LogOff,
+            Key::Power,
+            Key::PowerOff,
+            Key::Hibernate,
+            Key::Standby,
+            Key::WakeUp,
+            Key::FastForward,
+            Key::Record,
+            Key::BrowserBack,
+            Key::BrowserFavorites,
+            Key::BrowserForward,
+            Key::BrowserHome,
+            Key::BrowserRefresh,
+            Key::BrowserSearch,
+            Key::BrowserStop,
+            Key::LaunchApplication1,
+            Key::LaunchApplication2,
+            Key::LaunchCalendar,
+            Key::LaunchContacts,
+            Key::LaunchMail,
+            Key::LaunchMediaPlayer,
+            Key::LaunchMusicPlayer,
+            Key::LaunchPhone,
+            Key::LaunchScreenSaver,
+            Key::LaunchSpreadsheet,
+            Key::LaunchWebBrowser,
+            Key::LaunchWebCam,
+            Key::LaunchWordProcessor,
+            Key::Close,
+            Key::New,
+            Key::Open,
+            Key::Print,
+            Key::Save,
+            Key::SpellCheck,
+            Key::MailForward,
+            Key::MailReply,
+            Key::MailSend,
+        ] {
+            let name = key.to_string();
+            assert_eq!(name.parse(), Ok(key), "name={name:?}");
+            assert_eq!(name.to_lowercase().parse(), Ok(key), "name={name:?}");
+            assert_eq!(name.to_uppercase().parse(), Ok(key), "name={name:?}");
+            assert!(key.is_named(), "key={key:?}");
+        }
+        assert_eq!(Key::FastForward.to_string(), "FastForward");
+        assert_eq!(
+            "Ctrl+BrowserBack".parse(),
+            Ok(KeyInput::new(Key::BrowserBack, Mods::CTRL)),
+        );
+    }
+
+    #[test]
+    fn parse_mouse_keys() {
+        for key in [
+            Key::MouseLeft,
+            Key::MouseRight,
+            Key::MouseMiddle,
+            Key::MouseBack,
+            Key::MouseForward,
+            Key::WheelUp,
+            Key::WheelDown,
+            Key::WheelLeft,
+            Key::WheelRight,
+        ] {
+            let name = key.to_string();
+            assert_eq!(name.parse(), Ok(key), "name={name:?}");
+            assert_eq!(name.to_lowercase().parse(), Ok(key), "name={name:?}");
+            assert_eq!(name.to_uppercase().parse(), Ok(key), "name={name:?}");
+            assert!(key.is_named(), "key={key:?}");
+        }
+        assert_eq!(
+            "Ctrl+MouseLeft".parse(),
+            Ok(KeyInput::new(Key::MouseLeft, Mods::CTRL)),
+        );
+        assert_eq!(
+            "Alt+WheelUp".parse(),
+            Ok(KeyInput::new(Key::WheelUp, Mods::ALT)),
+        );
+        assert_eq!(
+            "g MouseLeft".parse(),
+            Ok(KeySeq::from([
+                KeyInput::from('g'),
+                KeyInput::from(Key::MouseLeft),
+            ])),
+        );
+    }
+
+    #[cfg(any(feature = "winit", feature = "iced"))]
+    #[test]
+    fn wheel_delta() {
+        assert_eq!(Key::from_wheel_delta(0.0, 1.0), Key::WheelUp);
+        assert_eq!(Key::from_wheel_delta(0.0, -1.0), Key::WheelDown);
+        assert_eq!(Key::from_wheel_delta(1.0, 0.0), Key::WheelLeft);
+        assert_eq!(Key::from_wheel_delta(-1.0, 0.0), Key::WheelRight);
+        assert_eq!(Key::from_wheel_delta(1.0, 1.0), Key::WheelUp);
+        assert_eq!(Key::from_wheel_delta(2.0, -1.0), Key::WheelLeft);
+        assert_eq!(Key::from_wheel_delta(0.0, 0.0), Key::Ignored);
+        assert_eq!(Key::from_wheel_delta(f64::NAN, f64::NAN), Key::Ignored);
+    }
+
+    #[test]
+    fn multi_char_keys() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        for (input, expected) in [
+            (
+                "👍🏽",
+                KeyInput::new(Key::Str(KeyStr::new("👍🏽").unwrap()), Mods::NONE),
+            ),
+            (
+                "Ctrl+日本語",
+                KeyInput::new(Key::Str(KeyStr::new("日本語").unwrap()), Mods::CTRL),
+            ),
+            (
+                family,
+                KeyInput::new(Key::Str(KeyStr::new(family).unwrap()), Mods::NONE),
+            ),
+        ] {
+            assert_eq!(input.parse(), Ok(expected), "input={input:?}");
+            assert_eq!(expected.to_string(), input, "input={input:?}");
+        }
+        assert_eq!(
+            "Shift+👍🏽".parse::<KeyInput>(),
+            Err(Error::ShiftUnavailable(Key::from_text("👍🏽"))),
+        );
+        // Multi-character keys only consisting of ASCII characters are not allowed to detect typos of named keys
+        assert_eq!("Entr".parse::<Key>(), Err(Error::UnknownKey("Entr".into())),);
+        // Multi-character keys which cannot be represented in the syntax
+        for input in ["日本 語", "あ+い"] {
+            assert_eq!(
+                input.parse::<Key>(),
+                Err(Error::UnknownKey(input.into())),
+                "input={input:?}",
+            );
+        }
+
+        let seq: KeySeq = "Ctrl+👍🏽 日本語".parse().unwrap();
+        let inputs = [
+            KeyInput::new(Key::from_text("👍🏽"), Mods::CTRL),
+            KeyInput::new(Key::from_text("日本語"), Mods::NONE),
+        ];
+        assert_eq!(seq.match_to(&inputs), Match::Matched);
+        assert_eq!(seq.match_to(&inputs[..1]), Match::Prefix);
+        let inputs = [KeyInput::new(Key::from_text("👍"), Mods::CTRL)];
+        assert_eq!(seq.match_to(&inputs), Match::Unmatch);
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn normalize_multi_char_keys() {
+        // NFD of "é" is normalized into the single character
+        assert_eq!(Key::from_text("e\u{0301}"), Key::Char('é'));
+        assert_eq!("Ctrl+e\u{0301}".parse(), Ok(KeyInput::new('é', Mods::CTRL)));
+        let s = KeyStr::new("ｶﾞｷﾞ").unwrap();
+        assert_eq!(Key::from_text("ｶﾞｷﾞ"), Key::Str(s));
+    }
+
     #[test]
     fn key_is_named() {
         assert!(!Key::Char('a').is_named());
@@ -1145,6 +2711,51 @@ mod tests {
         assert_eq!(k.mods(), Mods::CTRL);
     }
 
+    #[test]
+    fn resolve_virtual_mods() {
+        let tests = [
+            (Mods::NONE, Mods::NONE, Mods::NONE),
+            (Mods::MOD, Mods::CMD, Mods::CTRL),
+            (Mods::SUPER, Mods::CMD, Mods::WIN),
+            (Mods::MOD | Mods::SUPER, Mods::CMD, Mods::CTRL | Mods::WIN),
+            (Mods::MOD | Mods::CTRL, Mods::CMD | Mods::CTRL, Mods::CTRL),
+            (
+                Mods::SUPER | Mods::SHIFT,
+                Mods::CMD | Mods::SHIFT,
+                Mods::WIN | Mods::SHIFT,
+            ),
+            (Mods::ALT, Mods::ALT, Mods::ALT),
+        ];
+        for (mods, mac, other) in tests {
+            assert_eq!(mods.resolve(Platform::MacOS), mac, "mods={mods:?}");
+            assert_eq!(mods.resolve(Platform::Linux), other, "mods={mods:?}");
+            assert_eq!(mods.resolve(Platform::Windows), other, "mods={mods:?}");
+            let input = KeyInput::new(Key::Enter, mods);
+            assert_eq!(input.resolve(Platform::MacOS).mods(), mac, "mods={mods:?}");
+        }
+    }
+
+    #[test]
+    fn match_key_seq_on_platform() {
+        let seq: KeySeq = "Mod+x Super+y".parse().unwrap();
+        let mac = [KeyInput::new('x', Mods::CMD), KeyInput::new('y', Mods::CMD)];
+        let win = [
+            KeyInput::new('x', Mods::CTRL),
+            KeyInput::new('y', Mods::WIN),
+        ];
+        let virt = [
+            KeyInput::new('x', Mods::MOD),
+            KeyInput::new('y', Mods::SUPER),
+        ];
+        assert_eq!(seq.match_to_on(&mac, Platform::MacOS), Match::Matched);
+        assert_eq!(seq.match_to_on(&win, Platform::MacOS), Match::Unmatch);
+        assert_eq!(seq.match_to_on(&win, Platform::Windows), Match::Matched);
+        assert_eq!(seq.match_to_on(&mac, Platform::Windows), Match::Unmatch);
+        assert_eq!(seq.match_to_on(&virt, Platform::MacOS), Match::Matched);
+        assert_eq!(seq.match_to_on(&virt, Platform::Linux), Match::Matched);
+        assert_eq!(seq.match_to_on(&mac[..1], Platform::MacOS), Match::Prefix);
+    }
+
     #[test]
     fn display_keyseq() {
         let tests = [
@@ -1163,14 +2774,38 @@ mod tests {
                 )),
                 "Ctrl+Cmd+Alt+Win+a",
             ),
-            #[cfg(not(target_os = "macos"))]
-            (KeySeq::from(KeyInput::new('a', Mods::MOD)), "Ctrl+a"),
-            #[cfg(target_os = "macos")]
-            (KeySeq::from(KeyInput::new('a', Mods::MOD)), "Cmd+a"),
-            #[cfg(not(target_os = "macos"))]
-            (KeySeq::from(KeyInput::new('a', Mods::SUPER)), "Win+a"),
-            #[cfg(target_os = "macos")]
-            (KeySeq::from(KeyInput::new('a', Mods::SUPER)), "Cmd+a"),
+            (KeySeq::from(KeyInput::new('a', Mods::MOD)), "Mod+a"),
+            (KeySeq::from(KeyInput::new('a', Mods::SUPER)), "Super+a"),
+            (
+                KeySeq::from(KeyInput::new('a', Mods::LCTRL | Mods::RALT)),
+                "LCtrl+RAlt+a",
+            ),
+            (
+                KeySeq::from(KeyInput::new(Key::Up, Mods::LSHIFT | Mods::RSHIFT)),
+                "LShift+RShift+Up",
+            ),
+            (
+                KeySeq::from(KeyInput::new('a', Mods::MOD | Mods::SUPER | Mods::ALT)),
+                "Mod+Alt+Super+a",
+            ),
+            (
+                KeySeq::from(KeyInput::new(
+                    'a',
+                    Mods::ALT | Mods::ALTGR | Mods::META | Mods::HYPER | Mods::SUPER,
+                )),
+                "Alt+AltGr+Meta+Hyper+Super+a",
+            ),
+            (
+                KeySeq::from(KeyInput::new(
+                    Key::Up,
+                    Mods::NUM_LOCK | Mods::CAPS_LOCK | Mods::SHIFT,
+                )),
+                "Shift+CapsLock+NumLock+Up",
+            ),
+            (
+                KeySeq::from(KeyInput::new('+', Mods::NUMPAD | Mods::CTRL)),
+                "Ctrl+Numpad+Plus",
+            ),
             (
                 KeySeq::from(KeyInput::new(Key::Enter, Mods::SHIFT)),
                 "Shift+Enter",
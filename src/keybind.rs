@@ -1,9 +1,6 @@
-use crate::{Key, KeyInput, KeySeq, Match, Result};
+use crate::{GenericKeySeq, Input, KeyInput, KeySeq, Layout, Match, Platform, Result};
 use std::time::{Duration, Instant};
 
-#[cfg(feature = "arbitrary")]
-use arbitrary::Arbitrary;
-
 /// Single key binding. A pair of a key sequence and its action.
 ///
 /// ```
@@ -17,11 +14,14 @@ use arbitrary::Arbitrary;
 /// keybinds.push(Keybind::new(KeyInput::new(Key::Left, Mods::CTRL), Action));
 /// keybinds.push(Keybind::new(KeySeq::from([KeyInput::new('x', Mods::ALT), KeyInput::new('y', Mods::ALT)]), Action));
 /// ```
+pub type Keybind<A> = GenericKeybind<A, KeyInput>;
+
+/// Single binding of a sequence of inputs and its action. The type parameter `I` is the type of inputs. See [`Input`]
+/// for input sources other than keyboards and [`Keybind`] for key bindings of keyboard inputs.
 #[derive(Clone, PartialEq, Eq, Debug)]
-#[cfg_attr(feature = "arbitrary", derive(Arbitrary))]
-pub struct Keybind<A> {
+pub struct GenericKeybind<A, I> {
     /// The key sequence that triggers the action.
-    pub seq: KeySeq,
+    pub seq: GenericKeySeq<I>,
     /// The action triggered by the key sequence.
     pub action: A,
 }
@@ -99,12 +99,24 @@ pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
 /// assert_eq!(keybinds.dispatch(KeyInput::new('b', Mods::CTRL)), None);
 /// assert_eq!(keybinds.dispatch(KeyInput::new('a', Mods::CTRL)), Some(&Action::Bar));
 /// ```
+///
+/// This is the dispatcher of keyboard inputs. To handle other input sources such as MIDI pads, gamepads or foot
+/// pedals, see [`GenericKeybinds`].
+pub type Keybinds<A> = GenericKeybinds<A, KeyInput>;
+
+/// A dispatcher that takes inputs and dispatches the corresponding bindings' actions. The type parameter `I` is the
+/// type of inputs.
+///
+/// [`Keybinds`] is the dispatcher of keyboard inputs. Implementing [`Input`] trait allows the dispatcher to handle
+/// other input sources such as MIDI pads, gamepads or foot pedals with the same sequence matching and timeout
+/// handling as [`Keybinds`]. See [`Input`] for an example.
 #[derive(PartialEq, Eq, Clone, Debug)]
-pub struct Keybinds<A> {
-    binds: Vec<Keybind<A>>,
-    ongoing: Vec<KeyInput>,
+pub struct GenericKeybinds<A, I: Input> {
+    binds: Vec<GenericKeybind<A, I>>,
+    ongoing: Vec<I>,
     last_input: Option<Instant>,
     timeout: Duration,
+    context: I::Context,
 }
 
 impl<A> Default for Keybinds<A> {
@@ -126,7 +138,7 @@ impl<A> Default for Keybinds<A> {
     }
 }
 
-impl<A> Keybinds<A> {
+impl<A, I: Input> GenericKeybinds<A, I> {
     /// Create a [`Keybinds`] instance from the array of key bindings.
     ///
     /// If you want to collect a [`Keybinds`] instance from an iterator, [`Keybinds::from_iter`] is also useful.
@@ -149,12 +161,13 @@ impl<A> Keybinds<A> {
     /// let keybinds = Keybinds::new(binds);
     /// assert_eq!(keybinds.as_slice().len(), 3);
     /// ```
-    pub fn new(binds: Vec<Keybind<A>>) -> Self {
+    pub fn new(binds: Vec<GenericKeybind<A, I>>) -> Self {
         Self {
             binds,
             ongoing: vec![],
             last_input: None,
             timeout: DEFAULT_TIMEOUT,
+            context: I::Context::default(),
         }
     }
 
@@ -171,7 +184,7 @@ impl<A> Keybinds<A> {
     /// keybinds.push(Keybind::new('x', Action));
     /// assert_eq!(keybinds.as_slice().len(), 1);
     /// ```
-    pub fn push(&mut self, bind: Keybind<A>) {
+    pub fn push(&mut self, bind: GenericKeybind<A, I>) {
         self.binds.push(bind);
         self.reset();
     }
@@ -197,8 +210,8 @@ impl<A> Keybinds<A> {
     /// assert_eq!(keybinds.dispatch(KeyInput::new('y', Mods::CTRL)), Some(&Action)); // Dispatched
     /// ```
     pub fn bind(&mut self, key_sequence: &str, action: A) -> Result<()> {
-        let seq: KeySeq = key_sequence.parse()?;
-        self.push(Keybind::new(seq, action));
+        let seq = GenericKeySeq::parse_in(key_sequence, &self.context)?;
+        self.push(GenericKeybind { seq, action });
         Ok(())
     }
 
@@ -217,7 +230,8 @@ impl<A> Keybinds<A> {
     ///
     /// This method accepts various values which implement `Into<KeyInput>`. For example, `char` value is converted
     /// into a single-character key input with no modifiers. Conversions from key event types in several frameworks
-    /// are supported by enabling the optional features.
+    /// are supported by enabling the optional features. Inputs ignored by [`Input::is_ignored`] such as
+    /// [`Key::Ignored`][crate::Key::Ignored] do nothing.
     ///
     /// ```
     /// use keybinds::{Keybinds, KeyInput, Key, Mods};
@@ -238,9 +252,9 @@ impl<A> Keybinds<A> {
     /// // Input "Enter" key with no modifiers
     /// assert_eq!(keybinds.dispatch(Key::Enter), Some(&Action::Foo));
     /// ```
-    pub fn dispatch<I: Into<KeyInput>>(&mut self, input: I) -> Option<&A> {
+    pub fn dispatch<T: Into<I>>(&mut self, input: T) -> Option<&A> {
         let input = input.into();
-        if input.key() == Key::Ignored {
+        if input.is_ignored() {
             return None;
         }
         self.handle_timeout();
@@ -250,7 +264,7 @@ impl<A> Keybinds<A> {
 
         let mut is_ongoing = false;
         for bind in self.binds.iter() {
-            match bind.seq.match_to(&self.ongoing) {
+            match bind.seq.match_to_in(&self.ongoing, &self.context) {
                 Match::Matched => {
                     self.ongoing.clear();
                     self.last_input = None;
@@ -354,7 +368,7 @@ impl<A> Keybinds<A> {
     ///
     /// assert_eq!(keybinds.as_slice(), &[Keybind::new('a', Action)]);
     /// ```
-    pub fn as_slice(&self) -> &[Keybind<A>] {
+    pub fn as_slice(&self) -> &[GenericKeybind<A, I>] {
         self.binds.as_slice()
     }
 
@@ -404,7 +418,7 @@ impl<A> Keybinds<A> {
     /// keybinds.dispatch('d');
     /// assert_eq!(keybinds.ongoing_inputs(), &[]);
     /// ```
-    pub fn ongoing_inputs(&self) -> &[KeyInput] {
+    pub fn ongoing_inputs(&self) -> &[I] {
         self.ongoing.as_slice()
     }
 
@@ -428,12 +442,210 @@ impl<A> Keybinds<A> {
     /// assert_eq!(keybinds.dispatch('a'), None);
     /// assert_eq!(keybinds.dispatch('b'), Some(&Action));
     /// ```
-    pub fn into_vec(self) -> Vec<Keybind<A>> {
+    pub fn into_vec(self) -> Vec<GenericKeybind<A, I>> {
         self.binds
     }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn set_context(&mut self, context: I::Context) {
+        self.context = context;
+    }
 }
 
-impl<A> FromIterator<Keybind<A>> for Keybinds<A> {
+impl<A> Keybinds<A> {
+    /// Set the platform to resolve the virtual modifiers `Mod` and `Super` while dispatching actions. The default
+    /// platform is the current platform ([`Platform::current`]).
+    ///
+    /// This is useful when the platform of the user is different from the platform where the application is running.
+    /// For example, a terminal application running on a Linux server may be used via SSH from macOS.
+    ///
+    /// ```
+    /// use keybinds::{Keybinds, KeyInput, Mods, Platform};
+    ///
+    /// #[derive(PartialEq, Eq, Debug)]
+    /// struct Action;
+    ///
+    /// let mut keybinds = Keybinds::default();
+    /// keybinds.bind("Mod+x", Action).unwrap();
+    ///
+    /// keybinds.set_platform(Platform::MacOS);
+    /// assert_eq!(keybinds.dispatch(KeyInput::new('x', Mods::CMD)), Some(&Action));
+    /// assert_eq!(keybinds.dispatch(KeyInput::new('x', Mods::CTRL)), None);
+    ///
+    /// keybinds.set_platform(Platform::Linux);
+    /// assert_eq!(keybinds.dispatch(KeyInput::new('x', Mods::CMD)), None);
+    /// assert_eq!(keybinds.dispatch(KeyInput::new('x', Mods::CTRL)), Some(&Action));
+    /// ```
+    pub fn set_platform(&mut self, platform: Platform) {
+        self.context.platform = platform;
+        self.reset();
+    }
+
+    /// Get the platform to resolve the virtual modifiers. See [`Keybinds::set_platform`] for more details.
+    ///
+    /// ```
+    /// use keybinds::{Keybinds, Platform};
+    ///
+    /// struct Action;
+    ///
+    /// let mut keybinds = Keybinds::<Action>::default();
+    /// assert_eq!(keybinds.platform(), Platform::current());
+    ///
+    /// keybinds.set_platform(Platform::Windows);
+    /// assert_eq!(keybinds.platform(), Platform::Windows);
+    /// ```
+    pub fn platform(&self) -> Platform {
+        self.context.platform
+    }
+
+    /// Set the keyboard layout to normalize <kbd>Shift</kbd> modifier with character keys in key sequences passed
+    /// to [`Keybinds::bind`]. For example, `Ctrl+Shift+p` is defined as `Ctrl+P` with the US layout. When `None` is
+    /// set (the default), such key sequences are rejected by [`Keybinds::bind`]. See [`Layout`] for more details.
+    ///
+    /// Note that the layout is not applied to the key bindings already defined. To deserialize key bindings with a
+    /// layout, use `keybinds::serde::WithLayout` with the `serde` feature.
+    ///
+    /// ```
+    /// use keybinds::{Keybinds, KeyInput, Layout, Mods};
+    ///
+    /// #[derive(PartialEq, Eq, Debug)]
+    /// struct Action;
+    ///
+    /// let mut keybinds = Keybinds::default();
+    /// keybinds.bind("Ctrl+Shift+p", Action).unwrap_err();
+    ///
+    /// keybinds.set_layout(Some(Layout::Us));
+    /// keybinds.bind("Ctrl+Shift+p", Action).unwrap();
+    ///
+    /// assert_eq!(keybinds.dispatch(KeyInput::new('P', Mods::CTRL)), Some(&Action));
+    /// ```
+    pub fn set_layout(&mut self, layout: Option<Layout>) {
+        self.context.layout = layout;
+    }
+
+    /// Get the keyboard layout to normalize <kbd>Shift</kbd> modifier. See [`Keybinds::set_layout`] for more
+    /// details.
+    ///
+    /// ```
+    /// use keybinds::{Keybinds, Layout};
+    ///
+    /// struct Action;
+    ///
+    /// let mut keybinds = Keybinds::<Action>::default();
+    /// assert_eq!(keybinds.layout(), None);
+    ///
+    /// keybinds.set_layout(Some(Layout::German));
+    /// assert_eq!(keybinds.layout(), Some(Layout::German));
+    /// ```
+    pub fn layout(&self) -> Option<Layout> {
+        self.context.layout
+    }
+
+    /// Enable or disable the normalization of key inputs into the form reported by legacy terminals. This is an opt-in
+    /// mode for terminal applications where <kbd>Ctrl</kbd> + <kbd>i</kbd> arrives as <kbd>Tab</kbd> for example.
+    /// While it is enabled, the key sequences of the key bindings and the key inputs passed to [`Keybinds::dispatch`]
+    /// are both normalized with [`KeyInput::normalize_for_terminal`] on matching. The key bindings themselves are not
+    /// modified. It is disabled by default.
+    ///
+    /// Key bindings which cannot be told apart after the normalization are found by
+    /// [`Keybinds::terminal_ambiguities`].
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Keybinds, Mods};
+    ///
+    /// #[derive(PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Foo,
+    /// }
+    ///
+    /// let mut keybinds = Keybinds::default();
+    /// keybinds.bind("Ctrl+i", Action::Foo).unwrap();
+    /// keybinds.set_terminal_normalization(true);
+    ///
+    /// // Legacy terminal reports Ctrl+i as Tab
+    /// assert_eq!(keybinds.dispatch(Key::Tab), Some(&Action::Foo));
+    /// // Terminals which can distinguish Ctrl+i from Tab
+    /// assert_eq!(keybinds.dispatch(KeyInput::new('i', Mods::CTRL)), Some(&Action::Foo));
+    /// ```
+    pub fn set_terminal_normalization(&mut self, enabled: bool) {
+        self.context.terminal = enabled;
+        self.reset();
+    }
+
+    /// Return whether the normalization for legacy terminals is enabled. See [`Keybinds::set_terminal_normalization`]
+    /// for more details.
+    ///
+    /// ```
+    /// use keybinds::Keybinds;
+    ///
+    /// struct Action;
+    ///
+    /// let mut keybinds = Keybinds::<Action>::default();
+    /// assert!(!keybinds.terminal_normalization());
+    ///
+    /// keybinds.set_terminal_normalization(true);
+    /// assert!(keybinds.terminal_normalization());
+    /// ```
+    pub fn terminal_normalization(&self) -> bool {
+        self.context.terminal
+    }
+
+    /// Find the pairs of key bindings which legacy terminals cannot tell apart. This is useful to warn users about their
+    /// configuration in terminal applications. Each pair contains key bindings whose key sequences are different but
+    /// conflict after [`KeySeq::normalize_for_terminal`]:
+    ///
+    /// - The key sequences become the same such as `Ctrl+i` and `Tab`
+    /// - One key sequence becomes the prefix of the other such as `Tab` and `Ctrl+i x`. The longer one can never be
+    ///   dispatched because the shorter one is matched first
+    ///
+    /// Whether [`Keybinds::set_terminal_normalization`] is enabled or not does not affect the result.
+    ///
+    /// ```
+    /// use keybinds::Keybinds;
+    ///
+    /// #[derive(PartialEq, Eq, Debug)]
+    /// enum Action {
+    ///     Foo,
+    ///     Bar,
+    ///     Piyo,
+    /// }
+    ///
+    /// let mut keybinds = Keybinds::default();
+    /// keybinds.bind("Ctrl+i", Action::Foo).unwrap();
+    /// keybinds.bind("Ctrl+x", Action::Bar).unwrap();
+    /// keybinds.bind("Tab", Action::Piyo).unwrap();
+    ///
+    /// let ambiguities = keybinds.terminal_ambiguities();
+    /// assert_eq!(ambiguities.len(), 1);
+    ///
+    /// let (a, b) = ambiguities[0];
+    /// assert_eq!((&a.action, &b.action), (&Action::Foo, &Action::Piyo));
+    /// ```
+    pub fn terminal_ambiguities(&self) -> Vec<(&Keybind<A>, &Keybind<A>)> {
+        let normalized: Vec<_> = self
+            .binds
+            .iter()
+            .map(|bind| bind.seq.normalize_for_terminal())
+            .collect();
+        let mut pairs = vec![];
+        for (i, (a, na)) in self.binds.iter().zip(normalized.iter()).enumerate() {
+            for (b, nb) in self.binds[i + 1..].iter().zip(normalized[i + 1..].iter()) {
+                let (sa, sb) = (a.seq.as_slice(), b.seq.as_slice());
+                let (na, nb) = (na.as_slice(), nb.as_slice());
+                // Sequences which are the same or prefixes of each other already conflict without terminals
+                let shadows = |n: &[KeyInput], m: &[KeyInput], s: &[KeyInput], t: &[KeyInput]| {
+                    m.starts_with(n) && !t.starts_with(s)
+                };
+                if shadows(na, nb, sa, sb) || shadows(nb, na, sb, sa) {
+                    pairs.push((a, b));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+impl<A, I: Input> FromIterator<GenericKeybind<A, I>> for GenericKeybinds<A, I> {
     /// Collect [`Keybinds`] instance from an iterator of [`Keybind`].
     ///
     /// ```
@@ -459,12 +671,12 @@ impl<A> FromIterator<Keybind<A>> for Keybinds<A> {
     ///
     /// assert_eq!(binds.as_slice().len(), 3);
     /// ```
-    fn from_iter<T: IntoIterator<Item = Keybind<A>>>(iter: T) -> Self {
-        Keybinds::new(iter.into_iter().collect())
+    fn from_iter<T: IntoIterator<Item = GenericKeybind<A, I>>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
     }
 }
 
-impl<A> Extend<Keybind<A>> for Keybinds<A> {
+impl<A, I: Input> Extend<GenericKeybind<A, I>> for GenericKeybinds<A, I> {
     /// Extend the key bindings with the iterator of [`Keybind`] instances. When some key binding matching is ongoing,
     /// it will be reset.
     ///
@@ -484,9 +696,9 @@ impl<A> Extend<Keybind<A>> for Keybinds<A> {
     /// // The matching state was reset
     /// assert!(!keybinds.is_ongoing());
     /// ```
-    fn extend<I>(&mut self, iter: I)
+    fn extend<T>(&mut self, iter: T)
     where
-        I: IntoIterator<Item = Keybind<A>>,
+        T: IntoIterator<Item = GenericKeybind<A, I>>,
     {
         self.binds.extend(iter);
         self.reset();
@@ -508,6 +720,31 @@ mod tests {
         Action5,
     }
 
+    #[test]
+    fn infer_key_input_type() {
+        // Key bindings of keyboard inputs are available without any type annotation
+        let mut k = Keybinds::new(vec![]);
+        k.bind("Ctrl+x", A::Action1).unwrap();
+        assert_eq!(k.dispatch('x'), None);
+        assert_eq!(
+            k.dispatch(KeyInput::new('x', Mods::CTRL)),
+            Some(&A::Action1)
+        );
+
+        let seq = KeySeq::from_iter(['a', 'b']);
+        assert_eq!(seq.as_slice(), &['a'.into(), 'b'.into()]);
+
+        let bind = Keybind {
+            seq: "Ctrl+y".parse().unwrap(),
+            action: A::Action2,
+        };
+        let mut k: Keybinds<_> = [bind].into_iter().collect();
+        assert_eq!(
+            k.dispatch(KeyInput::new('y', Mods::CTRL)),
+            Some(&A::Action2)
+        );
+    }
+
     #[test]
     fn handle_input() {
         let binds = vec![
@@ -593,6 +830,102 @@ mod tests {
         assert_eq!(keybinds.ongoing_inputs(), &[]);
     }
 
+    #[test]
+    fn dispatcher_normalize_for_terminal() {
+        let mut keybinds = Keybinds::default();
+        assert!(!keybinds.terminal_normalization());
+        keybinds.bind("Ctrl+x Ctrl+i", A::Action1).unwrap();
+        keybinds.bind("Ctrl+h", A::Action2).unwrap();
+        keybinds.set_terminal_normalization(true);
+        assert!(keybinds.terminal_normalization());
+        keybinds.bind("Ctrl+[ Ctrl+a", A::Action3).unwrap();
+        keybinds.push(Keybind::new(KeyInput::new('m', Mods::CTRL), A::Action4));
+        keybinds.extend([Keybind::new(
+            KeyInput::new('i', Mods::ALT | Mods::CTRL),
+            A::Action5,
+        )]);
+
+        // The key bindings are not modified
+        let seqs: Vec<_> = keybinds
+            .as_slice()
+            .iter()
+            .map(|b| b.seq.to_string())
+            .collect();
+        assert_eq!(
+            seqs,
+            [
+                "Ctrl+x Ctrl+i",
+                "Ctrl+h",
+                "Ctrl+[ Ctrl+a",
+                "Ctrl+m",
+                "Ctrl+Alt+i"
+            ],
+        );
+
+        let ctrl = |c| KeyInput::new(c, Mods::CTRL);
+        assert_eq!(keybinds.dispatch(ctrl('x')), None);
+        assert_eq!(keybinds.dispatch(Key::Tab), Some(&A::Action1));
+        assert_eq!(keybinds.dispatch(ctrl('x')), None);
+        assert_eq!(keybinds.dispatch(ctrl('i')), Some(&A::Action1));
+        assert_eq!(keybinds.dispatch(Key::Backspace), Some(&A::Action2));
+        assert_eq!(keybinds.dispatch(ctrl('h')), Some(&A::Action2));
+        assert_eq!(keybinds.dispatch(Key::Esc), None);
+        assert_eq!(keybinds.dispatch(ctrl('a')), Some(&A::Action3));
+        assert_eq!(keybinds.dispatch(ctrl('[')), None);
+        assert_eq!(keybinds.dispatch(ctrl('a')), Some(&A::Action3));
+        assert_eq!(keybinds.dispatch(Key::Enter), Some(&A::Action4));
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new(Key::Tab, Mods::ALT)),
+            Some(&A::Action5),
+        );
+
+        // Disabling the mode restores the original matching
+        keybinds.set_terminal_normalization(false);
+        assert_eq!(keybinds.dispatch(Key::Backspace), None);
+        assert_eq!(keybinds.dispatch(ctrl('h')), Some(&A::Action2));
+        assert_eq!(keybinds.dispatch(ctrl('x')), None);
+        assert_eq!(keybinds.dispatch(Key::Tab), None);
+        assert_eq!(keybinds.dispatch(ctrl('x')), None);
+        assert_eq!(keybinds.dispatch(ctrl('i')), Some(&A::Action1));
+        assert_eq!(keybinds.as_slice()[0].seq.to_string(), "Ctrl+x Ctrl+i");
+    }
+
+    #[test]
+    fn dispatcher_terminal_ambiguities() {
+        let mut keybinds = Keybinds::default();
+        assert!(keybinds.terminal_ambiguities().is_empty());
+
+        keybinds.bind("Ctrl+i", A::Action1).unwrap();
+        keybinds.bind("Ctrl+m", A::Action2).unwrap();
+        keybinds.bind("Tab", A::Action3).unwrap();
+        keybinds.bind("Ctrl+x Enter", A::Action4).unwrap();
+        keybinds.bind("Ctrl+x Ctrl+m", A::Action1).unwrap();
+        keybinds.bind("Ctrl+i", A::Action2).unwrap(); // Duplicate is not an ambiguity of terminals
+        keybinds.bind("Ctrl+x", A::Action3).unwrap(); // Prefix is not an ambiguity of terminals
+        keybinds.bind("Ctrl+i x", A::Action4).unwrap();
+        keybinds.bind("Ctrl+x Ctrl+m Ctrl+a", A::Action5).unwrap();
+        keybinds.bind("Enter Enter", A::Action5).unwrap();
+
+        let found: Vec<_> = keybinds
+            .terminal_ambiguities()
+            .into_iter()
+            .map(|(a, b)| (a.seq.to_string(), b.seq.to_string()))
+            .collect();
+        let expected = [
+            ("Ctrl+i", "Tab"),
+            ("Ctrl+m", "Enter Enter"),
+            ("Tab", "Ctrl+i"),
+            ("Tab", "Ctrl+i x"),
+            ("Ctrl+x Enter", "Ctrl+x Ctrl+m"),
+            ("Ctrl+x Enter", "Ctrl+x Ctrl+m Ctrl+a"),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect();
+        assert_eq!(found, expected);
+    }
+
     #[test]
     fn dispatcher_set_timeout() {
         let mut keybinds = Keybinds::<A>::default();
@@ -602,6 +935,71 @@ mod tests {
         assert_eq!(keybinds.timeout(), d);
     }
 
+    #[test]
+    fn dispatcher_resolve_virtual_mods() {
+        let mut keybinds = Keybinds::new(vec![
+            Keybind::new(KeyInput::new('a', Mods::MOD), A::Action1),
+            Keybind::new(KeyInput::new('b', Mods::SUPER), A::Action2),
+            Keybind::new(KeyInput::new('c', Mods::CTRL), A::Action3),
+        ]);
+        assert_eq!(keybinds.platform(), Platform::current());
+
+        keybinds.set_platform(Platform::MacOS);
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('a', Mods::CMD)),
+            Some(&A::Action1),
+        );
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('b', Mods::CMD)),
+            Some(&A::Action2),
+        );
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('b', Mods::SUPER)),
+            Some(&A::Action2),
+        );
+        assert_eq!(keybinds.dispatch(KeyInput::new('a', Mods::CTRL)), None);
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('c', Mods::CTRL)),
+            Some(&A::Action3),
+        );
+
+        keybinds.set_platform(Platform::Windows);
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('a', Mods::CTRL)),
+            Some(&A::Action1),
+        );
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('b', Mods::WIN)),
+            Some(&A::Action2),
+        );
+        assert_eq!(keybinds.dispatch(KeyInput::new('a', Mods::CMD)), None);
+    }
+
+    #[test]
+    fn bind_with_layout() {
+        let mut keybinds = Keybinds::default();
+        keybinds.set_layout(Some(Layout::Us));
+        keybinds.bind("Ctrl+Shift+p", A::Action1).unwrap();
+        keybinds.bind("Shift+1 Shift+Enter", A::Action2).unwrap();
+        keybinds.bind("Shift+€", A::Action3).unwrap_err();
+
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new('P', Mods::CTRL)),
+            Some(&A::Action1),
+        );
+        assert_eq!(keybinds.dispatch('!'), None);
+        assert_eq!(
+            keybinds.dispatch(KeyInput::new(Key::Enter, Mods::SHIFT)),
+            Some(&A::Action2),
+        );
+
+        // Bindings written with and without Shift are the same
+        let mut other = Keybinds::default();
+        other.bind("Ctrl+P", A::Action1).unwrap();
+        other.bind("! Shift+Enter", A::Action2).unwrap();
+        assert_eq!(keybinds.as_slice(), other.as_slice());
+    }
+
     #[test]
     fn dispatcher_ignore_keys() {
         let mut keybinds = Keybinds::new(vec![Keybind::new(['a', 'b'], A::Action1)]);
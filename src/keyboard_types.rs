@@ -0,0 +1,819 @@
+//! Support for [`keyboard-types`][keyboard_types] crate.
+//!
+//! [`keyboard-types`][keyboard_types] provides the key types defined by W3C [UI Events][ui-events] specification. It
+//! is used by several crates such as tao, dioxus, and egui-winit. This module provides:
+//!
+//! - the conversions between keyboard-types' `Key`, `Code`, and `Modifiers` and keybinds' [`Key`], [`Code`], and
+//!   [`Mods`] in both directions
+//! - the conversions between keyboard-types' `KeyboardEvent` and [`KeyInput`] in both directions
+//! - [`to_modifiers`] and [`to_keyboard_event`] functions to resolve the virtual modifiers with a specific platform
+//! - [`parse_key`] function to parse the W3C [`key`][key-values] strings like `"ArrowUp"` into [`Key`]
+//!
+//! The W3C `key` strings are available on any web or DOM-style input sources so they can be passed to
+//! [`Keybinds::dispatch`][crate::Keybinds::dispatch] via [`parse_key`].
+//!
+//! ```
+//! use keybinds::keyboard_types::parse_key;
+//! use keybinds::{KeyInput, Keybinds, Mods};
+//! use keyboard_types::{Code, Key, KeyboardEvent, Modifiers};
+//!
+//! // Actions dispatched by key bindings
+//! #[derive(PartialEq, Eq, Debug)]
+//! enum Action {
+//!     Mute,
+//!     Save,
+//! }
+//!
+//! let mut keybinds = Keybinds::default();
+//! keybinds.bind("Mute", Action::Mute).unwrap();
+//! keybinds.bind("Ctrl+s", Action::Save).unwrap();
+//!
+//! // Dispatch an action for the W3C `key` string sent from the web
+//! let key = parse_key("AudioVolumeMute").unwrap();
+//! assert_eq!(keybinds.dispatch(key), Some(&Action::Mute));
+//!
+//! // Dispatch an action for keyboard-types' `KeyboardEvent`
+//! let mut event = KeyboardEvent::key_down(Key::Character("s".into()), Code::KeyS);
+//! event.modifiers = Modifiers::CONTROL;
+//! assert_eq!(keybinds.dispatch(&event), Some(&Action::Save));
+//! ```
+//!
+//! [ui-events]: https://www.w3.org/TR/uievents/
+//! [key-values]: https://www.w3.org/TR/uievents-key/
+use crate::{Code, Error, Key, KeyInput, LatinFallback, Mods, Platform};
+use keyboard_types::{
+    Code as KeyboardCode, Key as KeyboardKey, KeyState, KeyboardEvent, Location, Modifiers,
+    NamedKey,
+};
+
+impl From<&KeyboardKey> for Key {
+    /// Convert keyboard-types' [`Key`][KeyboardKey] into [`Key`]. Modifier keys such as `NamedKey::Control` are
+    /// converted into [`Key::Ignored`] and unsupported keys are converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use keyboard_types::{Key as KeyboardKey, NamedKey};
+    ///
+    /// assert_eq!(Key::from(KeyboardKey::Named(NamedKey::ArrowUp)), Key::Up);
+    /// assert_eq!(Key::from(KeyboardKey::Character("a".into())), Key::Char('a'));
+    /// assert_eq!(Key::from(KeyboardKey::Named(NamedKey::Shift)), Key::Ignored);
+    /// ```
+    fn from(key: &KeyboardKey) -> Self {
+        match key {
+            KeyboardKey::Named(named) => Self::from(*named),
+            KeyboardKey::Character(s) => Self::from_text(s),
+        }
+    }
+}
+
+impl From<KeyboardKey> for Key {
+    fn from(key: KeyboardKey) -> Self {
+        Self::from(&key)
+    }
+}
+
+impl From<NamedKey> for Key {
+    #[allow(deprecated)] // `NamedKey::Hyper` and `NamedKey::Super` are legacy but still emitted by some platforms
+    fn from(key: NamedKey) -> Self {
+        match key {
+            NamedKey::ArrowUp => Self::Up,
+            NamedKey::ArrowRight => Self::Right,
+            NamedKey::ArrowDown => Self::Down,
+            NamedKey::ArrowLeft => Self::Left,
+            NamedKey::Enter => Self::Enter,
+            NamedKey::Backspace => Self::Backspace,
+            NamedKey::Delete => Self::Delete,
+            NamedKey::Home => Self::Home,
+            NamedKey::End => Self::End,
+            NamedKey::PageUp => Self::PageUp,
+            NamedKey::PageDown => Self::PageDown,
+            NamedKey::Escape => Self::Esc,
+            NamedKey::Tab => Self::Tab,
+            NamedKey::Insert => Self::Insert,
+            NamedKey::Copy => Self::Copy,
+            NamedKey::Cut => Self::Cut,
+            NamedKey::Paste => Self::Paste,
+            NamedKey::Clear => Self::Clear,
+            NamedKey::Undo => Self::Undo,
+            NamedKey::Redo => Self::Redo,
+            NamedKey::Help => Self::Help,
+            NamedKey::Accept => Self::Accept,
+            NamedKey::Again => Self::Again,
+            NamedKey::Attn => Self::Attn,
+            NamedKey::Cancel => Self::Cancel,
+            NamedKey::Execute => Self::Execute,
+            NamedKey::Find => Self::Find,
+            NamedKey::Props => Self::Props,
+            NamedKey::Select => Self::Select,
+            NamedKey::CrSel => Self::CrSel,
+            NamedKey::EraseEof => Self::EraseEof,
+            NamedKey::ExSel => Self::ExSel,
+            NamedKey::BrightnessDown => Self::BrightnessDown,
+            NamedKey::BrightnessUp => Self::BrightnessUp,
+            NamedKey::Eject => Self::Eject,
+            NamedKey::LogOff => Self::LogOff,
+            NamedKey::Power => Self::Power,
+            NamedKey::PowerOff => Self::PowerOff,
+            NamedKey::Hibernate => Self::Hibernate,
+            NamedKey::Standby => Self::Standby,
+            NamedKey::WakeUp => Self::WakeUp,
+            NamedKey::MediaFastForward => Self::FastForward,
+            NamedKey::MediaRecord => Self::Record,
+            NamedKey::BrowserBack => Self::BrowserBack,
+            NamedKey::BrowserFavorites => Self::BrowserFavorites,
+            NamedKey::BrowserForward => Self::BrowserForward,
+            NamedKey::BrowserHome => Self::BrowserHome,
+            NamedKey::BrowserRefresh => Self::BrowserRefresh,
+            NamedKey::BrowserSearch => Self::BrowserSearch,
+            NamedKey::BrowserStop => Self::BrowserStop,
+            NamedKey::LaunchApplication1 => Self::LaunchApplication1,
+            NamedKey::LaunchApplication2 => Self::LaunchApplication2,
+            NamedKey::LaunchCalendar => Self::LaunchCalendar,
+            NamedKey::LaunchContacts => Self::LaunchContacts,
+            NamedKey::LaunchMail => Self::LaunchMail,
+            NamedKey::LaunchMediaPlayer => Self::LaunchMediaPlayer,
+            NamedKey::LaunchMusicPlayer => Self::LaunchMusicPlayer,
+            NamedKey::LaunchPhone => Self::LaunchPhone,
+            NamedKey::LaunchScreenSaver => Self::LaunchScreenSaver,
+            NamedKey::LaunchSpreadsheet => Self::LaunchSpreadsheet,
+            NamedKey::LaunchWebBrowser => Self::LaunchWebBrowser,
+            NamedKey::LaunchWebCam => Self::LaunchWebCam,
+            NamedKey::LaunchWordProcessor => Self::LaunchWordProcessor,
+            NamedKey::Close => Self::Close,
+            NamedKey::New => Self::New,
+            NamedKey::Open => Self::Open,
+            NamedKey::Print => Self::Print,
+            NamedKey::Save => Self::Save,
+            NamedKey::SpellCheck => Self::SpellCheck,
+            NamedKey::MailForward => Self::MailForward,
+            NamedKey::MailReply => Self::MailReply,
+            NamedKey::MailSend => Self::MailSend,
+            NamedKey::ZoomIn => Self::ZoomIn,
+            NamedKey::ZoomOut => Self::ZoomOut,
+            NamedKey::ZoomToggle => Self::ZoomToggle,
+            NamedKey::ScrollLock => Self::ScrollLock,
+            NamedKey::NumLock => Self::NumLock,
+            NamedKey::FnLock => Self::FnLock,
+            NamedKey::PrintScreen => Self::PrintScreen,
+            NamedKey::ContextMenu => Self::Menu,
+            NamedKey::MediaPlay => Self::Play,
+            NamedKey::MediaPause => Self::Pause,
+            NamedKey::MediaPlayPause => Self::PlayPause,
+            NamedKey::MediaStop => Self::Stop,
+            NamedKey::MediaRewind => Self::Rewind,
+            NamedKey::MediaTrackNext => Self::NextTrack,
+            NamedKey::MediaTrackPrevious => Self::PrevTrack,
+            NamedKey::AudioVolumeUp => Self::VolumeUp,
+            NamedKey::AudioVolumeDown => Self::VolumeDown,
+            NamedKey::AudioVolumeMute => Self::Mute,
+            NamedKey::F1 => Self::F1,
+            NamedKey::F2 => Self::F2,
+            NamedKey::F3 => Self::F3,
+            NamedKey::F4 => Self::F4,
+            NamedKey::F5 => Self::F5,
+            NamedKey::F6 => Self::F6,
+            NamedKey::F7 => Self::F7,
+            NamedKey::F8 => Self::F8,
+            NamedKey::F9 => Self::F9,
+            NamedKey::F10 => Self::F10,
+            NamedKey::F11 => Self::F11,
+            NamedKey::F12 => Self::F12,
+            NamedKey::F13 => Self::F13,
+            NamedKey::F14 => Self::F14,
+            NamedKey::F15 => Self::F15,
+            NamedKey::F16 => Self::F16,
+            NamedKey::F17 => Self::F17,
+            NamedKey::F18 => Self::F18,
+            NamedKey::F19 => Self::F19,
+            NamedKey::F20 => Self::F20,
+            NamedKey::F21 => Self::F21,
+            NamedKey::F22 => Self::F22,
+            NamedKey::F23 => Self::F23,
+            NamedKey::F24 => Self::F24,
+            NamedKey::F25 => Self::F25,
+            NamedKey::F26 => Self::F26,
+            NamedKey::F27 => Self::F27,
+            NamedKey::F28 => Self::F28,
+            NamedKey::F29 => Self::F29,
+            NamedKey::F30 => Self::F30,
+            NamedKey::F31 => Self::F31,
+            NamedKey::F32 => Self::F32,
+            NamedKey::F33 => Self::F33,
+            NamedKey::F34 => Self::F34,
+            NamedKey::F35 => Self::F35,
+            NamedKey::Alt
+            | NamedKey::AltGraph
+            | NamedKey::CapsLock
+            | NamedKey::Control
+            | NamedKey::Fn
+            | NamedKey::Hyper
+            | NamedKey::Shift
+            | NamedKey::Meta
+            | NamedKey::Super
+            | NamedKey::Symbol
+            | NamedKey::SymbolLock => Self::Ignored,
+            _ => Self::Unidentified,
+        }
+    }
+}
+
+impl From<Key> for KeyboardKey {
+    /// Convert [`Key`] into keyboard-types' [`Key`][KeyboardKey]. Keys which have no W3C `key` value such as
+    /// [`Key::Physical`] and the mouse keys are converted into `NamedKey::Unidentified`.
+    ///
+    /// ```
+    /// use keybinds::{Code, Key};
+    /// use keyboard_types::{Key as KeyboardKey, NamedKey};
+    ///
+    /// assert_eq!(KeyboardKey::from(Key::Up), KeyboardKey::Named(NamedKey::ArrowUp));
+    /// assert_eq!(KeyboardKey::from(Key::Char('a')), KeyboardKey::Character("a".into()));
+    /// assert_eq!(KeyboardKey::from(Key::Physical(Code::KeyA)), KeyboardKey::Named(NamedKey::Unidentified));
+    /// ```
+    fn from(key: Key) -> Self {
+        let named = match key {
+            Key::Char(c) => return Self::Character(c.to_string()),
+            Key::Str(s) => return Self::Character(s.as_str().to_string()),
+            Key::Up => NamedKey::ArrowUp,
+            Key::Right => NamedKey::ArrowRight,
+            Key::Down => NamedKey::ArrowDown,
+            Key::Left => NamedKey::ArrowLeft,
+            Key::Enter => NamedKey::Enter,
+            Key::Backspace => NamedKey::Backspace,
+            Key::Delete => NamedKey::Delete,
+            Key::Home => NamedKey::Home,
+            Key::End => NamedKey::End,
+            Key::PageUp => NamedKey::PageUp,
+            Key::PageDown => NamedKey::PageDown,
+            Key::Esc => NamedKey::Escape,
+            Key::Tab => NamedKey::Tab,
+            Key::Insert => NamedKey::Insert,
+            Key::Copy => NamedKey::Copy,
+            Key::Cut => NamedKey::Cut,
+            Key::Paste => NamedKey::Paste,
+            Key::Clear => NamedKey::Clear,
+            Key::Undo => NamedKey::Undo,
+            Key::Redo => NamedKey::Redo,
+            Key::Help => NamedKey::Help,
+            Key::Accept => NamedKey::Accept,
+            Key::Again => NamedKey::Again,
+            Key::Attn => NamedKey::Attn,
+            Key::Cancel => NamedKey::Cancel,
+            Key::Execute => NamedKey::Execute,
+            Key::Find => NamedKey::Find,
+            Key::Props => NamedKey::Props,
+            Key::Select => NamedKey::Select,
+            Key::CrSel => NamedKey::CrSel,
+            Key::EraseEof => NamedKey::EraseEof,
+            Key::ExSel => NamedKey::ExSel,
+            Key::BrightnessDown => NamedKey::BrightnessDown,
+            Key::BrightnessUp => NamedKey::BrightnessUp,
+            Key::Eject => NamedKey::Eject,
+            Key::LogOff => NamedKey::LogOff,
+            Key::Power => NamedKey::Power,
+            Key::PowerOff => NamedKey::PowerOff,
+            Key::Hibernate => NamedKey::Hibernate,
+            Key::Standby => NamedKey::Standby,
+            Key::WakeUp => NamedKey::WakeUp,
+            Key::FastForward => NamedKey::MediaFastForward,
+            Key::Record => NamedKey::MediaRecord,
+            Key::BrowserBack => NamedKey::BrowserBack,
+            Key::BrowserFavorites => NamedKey::BrowserFavorites,
+            Key::BrowserForward => NamedKey::BrowserForward,
+            Key::BrowserHome => NamedKey::BrowserHome,
+            Key::BrowserRefresh => NamedKey::BrowserRefresh,
+            Key::BrowserSearch => NamedKey::BrowserSearch,
+            Key::BrowserStop => NamedKey::BrowserStop,
+            Key::LaunchApplication1 => NamedKey::LaunchApplication1,
+            Key::LaunchApplication2 => NamedKey::LaunchApplication2,
+            Key::LaunchCalendar => NamedKey::LaunchCalendar,
+            Key::LaunchContacts => NamedKey::LaunchContacts,
+            Key::LaunchMail => NamedKey::LaunchMail,
+            Key::LaunchMediaPlayer => NamedKey::LaunchMediaPlayer,
+            Key::LaunchMusicPlayer => NamedKey::LaunchMusicPlayer,
+            Key::LaunchPhone => NamedKey::LaunchPhone,
+            Key::LaunchScreenSaver => NamedKey::LaunchScreenSaver,
+            Key::LaunchSpreadsheet => NamedKey::LaunchSpreadsheet,
+            Key::LaunchWebBrowser => NamedKey::LaunchWebBrowser,
+            Key::LaunchWebCam => NamedKey::LaunchWebCam,
+            Key::LaunchWordProcessor => NamedKey::LaunchWordProcessor,
+            Key::Close => NamedKey::Close,
+            Key::New => NamedKey::New,
+            Key::Open => NamedKey::Open,
+            Key::Print => NamedKey::Print,
+            Key::Save => NamedKey::Save,
+            Key::SpellCheck => NamedKey::SpellCheck,
+            Key::MailForward => NamedKey::MailForward,
+            Key::MailReply => NamedKey::MailReply,
+            Key::MailSend => NamedKey::MailSend,
+            Key::ZoomIn => NamedKey::ZoomIn,
+            Key::ZoomOut => NamedKey::ZoomOut,
+            Key::ZoomToggle => NamedKey::ZoomToggle,
+            Key::ScrollLock => NamedKey::ScrollLock,
+            Key::NumLock => NamedKey::NumLock,
+            Key::FnLock => NamedKey::FnLock,
+            Key::PrintScreen => NamedKey::PrintScreen,
+            Key::Menu => NamedKey::ContextMenu,
+            Key::Play => NamedKey::MediaPlay,
+            Key::Pause => NamedKey::MediaPause,
+            Key::PlayPause => NamedKey::MediaPlayPause,
+            Key::Stop => NamedKey::MediaStop,
+            Key::Rewind => NamedKey::MediaRewind,
+            Key::NextTrack => NamedKey::MediaTrackNext,
+            Key::PrevTrack => NamedKey::MediaTrackPrevious,
+            Key::VolumeUp => NamedKey::AudioVolumeUp,
+            Key::VolumeDown => NamedKey::AudioVolumeDown,
+            Key::Mute => NamedKey::AudioVolumeMute,
+            Key::F1 => NamedKey::F1,
+            Key::F2 => NamedKey::F2,
+            Key::F3 => NamedKey::F3,
+            Key::F4 => NamedKey::F4,
+            Key::F5 => NamedKey::F5,
+            Key::F6 => NamedKey::F6,
+            Key::F7 => NamedKey::F7,
+            Key::F8 => NamedKey::F8,
+            Key::F9 => NamedKey::F9,
+            Key::F10 => NamedKey::F10,
+            Key::F11 => NamedKey::F11,
+            Key::F12 => NamedKey::F12,
+            Key::F13 => NamedKey::F13,
+            Key::F14 => NamedKey::F14,
+            Key::F15 => NamedKey::F15,
+            Key::F16 => NamedKey::F16,
+            Key::F17 => NamedKey::F17,
+            Key::F18 => NamedKey::F18,
+            Key::F19 => NamedKey::F19,
+            Key::F20 => NamedKey::F20,
+            Key::F21 => NamedKey::F21,
+            Key::F22 => NamedKey::F22,
+            Key::F23 => NamedKey::F23,
+            Key::F24 => NamedKey::F24,
+            Key::F25 => NamedKey::F25,
+            Key::F26 => NamedKey::F26,
+            Key::F27 => NamedKey::F27,
+            Key::F28 => NamedKey::F28,
+            Key::F29 => NamedKey::F29,
+            Key::F30 => NamedKey::F30,
+            Key::F31 => NamedKey::F31,
+            Key::F32 => NamedKey::F32,
+            Key::F33 => NamedKey::F33,
+            Key::F34 => NamedKey::F34,
+            Key::F35 => NamedKey::F35,
+            _ => NamedKey::Unidentified,
+        };
+        Self::Named(named)
+    }
+}
+
+impl From<&KeyboardCode> for Key {
+    /// Convert keyboard-types' [`Code`][KeyboardCode] into [`Key::Physical`]. Physical keys which cannot be
+    /// represented with [`Code`] are converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::{Code, Key};
+    /// use keyboard_types::Code as KeyboardCode;
+    ///
+    /// assert_eq!(Key::from(KeyboardCode::KeyW), Key::Physical(Code::KeyW));
+    /// assert_eq!(Key::from(KeyboardCode::Fn), Key::Unidentified);
+    /// ```
+    fn from(code: &KeyboardCode) -> Self {
+        // Both types are named after the W3C `code` values
+        match code.to_string().parse() {
+            Ok(code) => Self::Physical(code),
+            Err(_) => Self::Unidentified,
+        }
+    }
+}
+
+impl From<KeyboardCode> for Key {
+    fn from(code: KeyboardCode) -> Self {
+        Self::from(&code)
+    }
+}
+
+impl From<Code> for KeyboardCode {
+    /// Convert [`Code`] into keyboard-types' [`Code`][KeyboardCode].
+    ///
+    /// ```
+    /// use keybinds::Code;
+    /// use keyboard_types::Code as KeyboardCode;
+    ///
+    /// assert_eq!(KeyboardCode::from(Code::NumpadEnter), KeyboardCode::NumpadEnter);
+    /// ```
+    fn from(code: Code) -> Self {
+        code.to_string().parse().unwrap_or(Self::Unidentified)
+    }
+}
+
+impl From<&Modifiers> for Mods {
+    /// Convert keyboard-types' [`Modifiers`] into [`Mods`]. `Modifiers::META` is the <kbd>Command</kbd> key on macOS and
+    /// the <kbd>Windows</kbd> key on other platforms so it is converted into [`Mods::SUPER`].
+    ///
+    /// ```
+    /// use keybinds::Mods;
+    /// use keyboard_types::Modifiers;
+    ///
+    /// assert_eq!(Mods::from(Modifiers::CONTROL | Modifiers::SHIFT), Mods::CTRL | Mods::SHIFT);
+    /// assert_eq!(Mods::from(Modifiers::META), Mods::SUPER);
+    /// assert_eq!(Mods::from(Modifiers::ALT_GRAPH), Mods::ALTGR);
+    /// ```
+    fn from(from: &Modifiers) -> Self {
+        let mut to = Mods::NONE;
+        for (m, mods) in [
+            (Modifiers::CONTROL, Mods::CTRL),
+            (Modifiers::ALT, Mods::ALT),
+            (Modifiers::SHIFT, Mods::SHIFT),
+            (Modifiers::META, Mods::SUPER),
+            (Modifiers::ALT_GRAPH, Mods::ALTGR),
+            (Modifiers::CAPS_LOCK, Mods::CAPS_LOCK),
+            (Modifiers::NUM_LOCK, Mods::NUM_LOCK),
+        ] {
+            if from.contains(m) {
+                to |= mods;
+            }
+        }
+        to
+    }
+}
+
+impl From<Modifiers> for Mods {
+    fn from(mods: Modifiers) -> Self {
+        Self::from(&mods)
+    }
+}
+
+impl From<Mods> for Modifiers {
+    /// Convert [`Mods`] into keyboard-types' [`Modifiers`]. The virtual modifiers [`Mods::MOD`] and [`Mods::SUPER`] are
+    /// resolved with the platform this program is compiled for. Use [`to_modifiers`] to resolve them with another
+    /// platform. [`Mods::CMD`], [`Mods::WIN`], and [`Mods::META`] are converted into `Modifiers::META`.
+    /// [`Mods::NUMPAD`] is not a modifier so it is dropped. See the conversion into `KeyboardEvent` to keep it.
+    ///
+    /// ```
+    /// use keybinds::Mods;
+    /// use keyboard_types::Modifiers;
+    ///
+    /// assert_eq!(Modifiers::from(Mods::CTRL | Mods::SHIFT), Modifiers::CONTROL | Modifiers::SHIFT);
+    /// assert_eq!(Modifiers::from(Mods::CMD), Modifiers::META);
+    /// assert_eq!(Modifiers::from(Mods::RALT), Modifiers::ALT);
+    /// ```
+    fn from(from: Mods) -> Self {
+        to_modifiers(from, Platform::current())
+    }
+}
+
+/// Convert [`Mods`] into keyboard-types' [`Modifiers`] on the given platform. See [`Platform`] for which to pass.
+///
+/// ```
+/// use keybinds::keyboard_types::to_modifiers;
+/// use keybinds::{Mods, Platform};
+/// use keyboard_types::Modifiers;
+///
+/// assert_eq!(to_modifiers(Mods::MOD | Mods::SHIFT, Platform::MacOS), Modifiers::META | Modifiers::SHIFT);
+/// assert_eq!(to_modifiers(Mods::MOD | Mods::SHIFT, Platform::Linux), Modifiers::CONTROL | Modifiers::SHIFT);
+/// ```
+pub fn to_modifiers(mods: Mods, platform: Platform) -> Modifiers {
+    let mods = mods.resolve(platform);
+    let mut to = Modifiers::empty();
+    for (m, modifier) in [
+        (Mods::CTRL, Modifiers::CONTROL),
+        (Mods::ALT, Modifiers::ALT),
+        (Mods::SHIFT, Modifiers::SHIFT),
+        (Mods::CMD, Modifiers::META),
+        (Mods::WIN, Modifiers::META),
+        (Mods::META, Modifiers::META),
+        (Mods::ALTGR, Modifiers::ALT_GRAPH),
+        (Mods::CAPS_LOCK, Modifiers::CAPS_LOCK),
+        (Mods::NUM_LOCK, Modifiers::NUM_LOCK),
+    ] {
+        if mods.contains(m) {
+            to |= modifier;
+        }
+    }
+    to
+}
+
+impl From<&KeyboardEvent> for KeyInput {
+    /// Convert keyboard-types' [`KeyboardEvent`] into [`KeyInput`]. Events except for key presses and events during
+    /// IME composition are converted into `Key::Ignored` with no modifiers. Like other GUI frameworks' key events, the
+    /// physical key of the event is also converted and non-Latin characters typed with modifiers fall back to Latin
+    /// characters with [`LatinFallback::WithModifiers`]. Keys on the numeric keypad are converted with
+    /// [`Mods::NUMPAD`].
+    ///
+    /// ```
+    /// use keybinds::{Code, KeyInput, Mods};
+    /// use keyboard_types::{Code as KeyboardCode, Key as KeyboardKey, KeyboardEvent, Modifiers};
+    ///
+    /// let mut event = KeyboardEvent::key_down(KeyboardKey::Character("X".into()), KeyboardCode::KeyX);
+    /// event.modifiers = Modifiers::CONTROL | Modifiers::SHIFT;
+    /// assert_eq!(KeyInput::from(&event), KeyInput::with_code('X', Mods::CTRL | Mods::SHIFT, Code::KeyX));
+    ///
+    /// let event = KeyboardEvent::key_up(KeyboardKey::Character("X".into()), KeyboardCode::KeyX);
+    /// assert_eq!(KeyInput::from(&event), KeyInput::from(keybinds::Key::Ignored));
+    /// ```
+    fn from(event: &KeyboardEvent) -> Self {
+        if event.state != KeyState::Down || event.is_composing {
+            return Key::Ignored.into();
+        }
+        let mut mods = Mods::from(&event.modifiers);
+        if event.location == Location::Numpad {
+            mods |= Mods::NUMPAD;
+        }
+        match Key::from(&event.code) {
+            Key::Physical(code) => {
+                LatinFallback::default().apply(KeyInput::with_code(&event.key, mods, code))
+            }
+            _ => KeyInput::new(&event.key, mods),
+        }
+    }
+}
+
+impl From<KeyboardEvent> for KeyInput {
+    fn from(event: KeyboardEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
+impl From<KeyInput> for KeyboardEvent {
+    /// Convert [`KeyInput`] into keyboard-types' key press event. The event has the physical key when the input has
+    /// it. Otherwise the `code` field is `Code::Unidentified`. Inputs with [`Mods::NUMPAD`] are converted into the
+    /// events located on the numeric keypad.
+    ///
+    /// ```
+    /// use keybinds::{Code, Key, KeyInput, Mods};
+    /// use keyboard_types::{Code as KeyboardCode, Key as KeyboardKey, KeyboardEvent, Location, Modifiers, NamedKey};
+    ///
+    /// let event = KeyboardEvent::from(KeyInput::new(Key::Enter, Mods::CTRL | Mods::NUMPAD));
+    /// assert_eq!(event.key, KeyboardKey::Named(NamedKey::Enter));
+    /// assert_eq!(event.code, KeyboardCode::Unidentified);
+    /// assert_eq!(event.location, Location::Numpad);
+    /// assert_eq!(event.modifiers, Modifiers::CONTROL);
+    ///
+    /// let event = KeyboardEvent::from(KeyInput::with_code('a', Mods::NONE, Code::KeyA));
+    /// assert_eq!(event.code, KeyboardCode::KeyA);
+    /// ```
+    fn from(input: KeyInput) -> Self {
+        to_keyboard_event(input, Platform::current())
+    }
+}
+
+impl From<&KeyInput> for KeyboardEvent {
+    fn from(input: &KeyInput) -> Self {
+        Self::from(*input)
+    }
+}
+
+/// Convert [`KeyInput`] into keyboard-types' key press event on the given platform. See [`Platform`] for which to pass.
+///
+/// ```
+/// use keybinds::keyboard_types::to_keyboard_event;
+/// use keybinds::{KeyInput, Mods, Platform};
+/// use keyboard_types::Modifiers;
+///
+/// let event = to_keyboard_event(KeyInput::new('s', Mods::MOD), Platform::MacOS);
+/// assert_eq!(event.modifiers, Modifiers::META);
+/// let event = to_keyboard_event(KeyInput::new('s', Mods::MOD), Platform::Windows);
+/// assert_eq!(event.modifiers, Modifiers::CONTROL);
+/// ```
+pub fn to_keyboard_event(input: KeyInput, platform: Platform) -> KeyboardEvent {
+    let code = input.code().map(KeyboardCode::from).unwrap_or_default();
+    let mut event = KeyboardEvent::key_down(input.key(), code);
+    event.modifiers = to_modifiers(input.physical_mods(), platform);
+    if input.physical_mods().contains(Mods::NUMPAD) {
+        event.location = Location::Numpad;
+    }
+    event
+}
+
+/// Parse the W3C [`key`][key-values] string such as `"ArrowUp"` or `"a"` into [`Key`]. This is useful to dispatch
+/// actions for key events sent from the web or DOM-style input sources. Modifier keys such as `"Control"` are parsed
+/// into [`Key::Ignored`].
+///
+/// Note that the W3C `key` strings are different from the key names in the key binding syntax. For example,
+/// `"ArrowUp"` is `Up` and `"AudioVolumeMute"` is `Mute` in the syntax. To parse the W3C `code` strings such as
+/// `"KeyA"`, use [`Code`]'s `FromStr` implementation instead.
+///
+/// ```
+/// use keybinds::keyboard_types::parse_key;
+/// use keybinds::{Error, Key};
+///
+/// assert_eq!(parse_key("ArrowUp"), Ok(Key::Up));
+/// assert_eq!(parse_key("AudioVolumeMute"), Ok(Key::Mute));
+/// assert_eq!(parse_key(" "), Ok(Key::Char(' ')));
+/// assert_eq!(parse_key("Control"), Ok(Key::Ignored));
+/// assert_eq!(parse_key("Up"), Err(Error::UnknownKey("Up".into())));
+/// ```
+///
+/// [key-values]: https://www.w3.org/TR/uievents-key/
+pub fn parse_key(key: &str) -> Result<Key, Error> {
+    if key.is_empty() {
+        return Err(Error::EmptyKey);
+    }
+    match key.parse::<KeyboardKey>() {
+        Ok(key) => Ok(key.into()),
+        Err(_) => Err(Error::UnknownKey(key.into())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeySeq, KeyStr, Match};
+
+    #[test]
+    #[allow(deprecated)]
+    fn convert_key() {
+        assert_eq!(Key::from(KeyboardKey::Named(NamedKey::Escape)), Key::Esc);
+        assert_eq!(Key::from(KeyboardKey::Named(NamedKey::F35)), Key::F35);
+        assert_eq!(
+            Key::from(KeyboardKey::Named(NamedKey::MediaTrackNext)),
+            Key::NextTrack,
+        );
+        assert_eq!(Key::from(KeyboardKey::Named(NamedKey::FnLock)), Key::FnLock);
+        for named in [
+            NamedKey::Control,
+            NamedKey::Hyper,
+            NamedKey::Super,
+            NamedKey::Meta,
+        ] {
+            assert_eq!(
+                Key::from(KeyboardKey::Named(named)),
+                Key::Ignored,
+                "{named:?}",
+            );
+        }
+        assert_eq!(
+            Key::from(KeyboardKey::Named(NamedKey::TVInput)),
+            Key::Unidentified,
+        );
+        assert_eq!(
+            Key::from(KeyboardKey::Character(" ".into())),
+            Key::Char(' '),
+        );
+        let family = KeyStr::new("👨‍👩‍👧").unwrap();
+        assert_eq!(
+            Key::from(KeyboardKey::Character("👨‍👩‍👧".into())),
+            Key::Str(family),
+        );
+        assert_eq!(
+            Key::from(KeyboardKey::Character("👨‍👩‍👦".into())),
+            Key::Unidentified,
+        );
+    }
+
+    #[test]
+    fn key_round_trip() {
+        for key in [
+            Key::Up,
+            Key::Esc,
+            Key::Menu,
+            Key::Mute,
+            Key::PlayPause,
+            Key::BrowserBack,
+            Key::F24,
+            Key::Char('a'),
+            Key::Char(' '),
+            Key::Str(KeyStr::new("👨‍👩‍👧").unwrap()),
+        ] {
+            assert_eq!(Key::from(KeyboardKey::from(key)), key, "{key:?}");
+        }
+
+        for key in [
+            Key::Physical(Code::KeyA),
+            Key::MouseLeft,
+            Key::WheelUp,
+            Key::Ignored,
+            Key::Unidentified,
+        ] {
+            assert_eq!(
+                KeyboardKey::from(key),
+                KeyboardKey::Named(NamedKey::Unidentified),
+                "{key:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn convert_code() {
+        assert_eq!(
+            Key::from(KeyboardCode::Backquote),
+            Key::Physical(Code::Backquote)
+        );
+        assert_eq!(
+            Key::from(KeyboardCode::MetaLeft),
+            Key::Physical(Code::MetaLeft)
+        );
+        assert_eq!(Key::from(KeyboardCode::Unidentified), Key::Unidentified);
+        assert_eq!(KeyboardCode::from(Code::Digit0), KeyboardCode::Digit0);
+        assert_eq!(KeyboardCode::from(Code::IntlYen), KeyboardCode::IntlYen);
+    }
+
+    #[test]
+    fn convert_mods() {
+        assert_eq!(Mods::from(Modifiers::empty()), Mods::NONE);
+        assert_eq!(
+            Mods::from(Modifiers::ALT | Modifiers::CAPS_LOCK),
+            Mods::ALT | Mods::CAPS_LOCK,
+        );
+        assert_eq!(
+            Mods::from(Modifiers::FN | Modifiers::SCROLL_LOCK),
+            Mods::NONE
+        );
+
+        assert_eq!(Modifiers::from(Mods::NONE), Modifiers::empty());
+        assert_eq!(
+            Modifiers::from(Mods::LCTRL | Mods::ALTGR | Mods::NUMPAD),
+            Modifiers::CONTROL | Modifiers::ALT_GRAPH,
+        );
+        assert_eq!(Modifiers::from(Mods::WIN), Modifiers::META);
+        let expected = if Platform::current() == Platform::MacOS {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        };
+        assert_eq!(Modifiers::from(Mods::MOD), expected);
+
+        for (platform, expected) in [
+            (Platform::MacOS, Modifiers::META),
+            (Platform::Linux, Modifiers::CONTROL),
+            (Platform::Windows, Modifiers::CONTROL),
+        ] {
+            assert_eq!(to_modifiers(Mods::MOD, platform), expected, "{platform:?}");
+            let event = to_keyboard_event(KeyInput::new('a', Mods::MOD | Mods::NUMPAD), platform);
+            assert_eq!(event.modifiers, expected, "{platform:?}");
+            assert_eq!(event.location, Location::Numpad, "{platform:?}");
+        }
+        assert_eq!(to_modifiers(Mods::SUPER, Platform::MacOS), Modifiers::META);
+        assert_eq!(to_modifiers(Mods::SUPER, Platform::Linux), Modifiers::META);
+    }
+
+    #[test]
+    fn convert_event() {
+        let mut event = KeyboardEvent::key_down(NamedKey::Enter, KeyboardCode::NumpadEnter);
+        event.location = Location::Numpad;
+        event.modifiers = Modifiers::SHIFT;
+        let input = KeyInput::from(&event);
+        assert_eq!(
+            input,
+            KeyInput::with_code(Key::Enter, Mods::SHIFT | Mods::NUMPAD, Code::NumpadEnter),
+        );
+        let seq: KeySeq = "Shift+Numpad+Enter".parse().unwrap();
+        assert_eq!(seq.match_to(&[input]), Match::Matched);
+
+        // Latin fallback on non-Latin layouts
+        let mut event =
+            KeyboardEvent::key_down(KeyboardKey::Character("с".into()), KeyboardCode::KeyC);
+        event.modifiers = Modifiers::CONTROL;
+        assert_eq!(
+            KeyInput::from(event),
+            KeyInput::with_code('c', Mods::CTRL, Code::KeyC),
+        );
+
+        let mut event =
+            KeyboardEvent::key_down(KeyboardKey::Character("a".into()), KeyboardCode::KeyA);
+        event.is_composing = true;
+        assert_eq!(KeyInput::from(event), KeyInput::from(Key::Ignored));
+
+        let event = KeyboardEvent::key_down(
+            KeyboardKey::Character("a".into()),
+            KeyboardCode::Unidentified,
+        );
+        assert_eq!(KeyInput::from(event), KeyInput::new('a', Mods::NONE));
+    }
+
+    #[test]
+    fn event_round_trip() {
+        for input in [
+            KeyInput::new(Key::Up, Mods::CTRL | Mods::SHIFT),
+            KeyInput::new(Key::Enter, Mods::NUMPAD),
+            KeyInput::new('x', Mods::ALT),
+            KeyInput::with_code('W', Mods::SHIFT, Code::KeyW),
+        ] {
+            assert_eq!(
+                KeyInput::from(KeyboardEvent::from(input)),
+                input,
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_w3c_key() {
+        for (s, key) in [
+            ("ArrowDown", Key::Down),
+            ("Escape", Key::Esc),
+            ("ContextMenu", Key::Menu),
+            ("MediaPlayPause", Key::PlayPause),
+            ("AudioVolumeUp", Key::VolumeUp),
+            ("a", Key::Char('a')),
+            ("+", Key::Char('+')),
+            ("Shift", Key::Ignored),
+            ("Hyper", Key::Ignored),
+            ("Super", Key::Ignored),
+            ("Dead", Key::Unidentified),
+        ] {
+            assert_eq!(parse_key(s), Ok(key), "{s:?}");
+        }
+        assert_eq!(parse_key(""), Err(Error::EmptyKey));
+        assert_eq!(parse_key("Esc"), Err(Error::UnknownKey("Esc".into())));
+        assert_eq!(parse_key("ab"), Err(Error::UnknownKey("ab".into())));
+    }
+}
@@ -0,0 +1,724 @@
+use crate::terminal::{encode_paste, mods_param, resolve_mods};
+use crate::{Error, Key, Mods, Platform, Result, TerminalEvent};
+use bitflags::bitflags;
+use std::fmt::Write as _;
+
+bitflags! {
+    /// Progressive enhancement flags of the [kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/).
+    ///
+    /// Terminals supporting the protocol report key inputs unambiguously as CSI u sequences such as `ESC [ 105 ; 5 u`
+    /// for <kbd>Ctrl</kbd> + <kbd>i</kbd> after the application pushes the flags with [`KittyFlags::push_sequence`].
+    /// The sequences are decoded by [`TerminalDecoder`](crate::TerminalDecoder) and key inputs can be encoded back into
+    /// the sequences by [`TerminalEvent::encode_kitty`].
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, KittyFlags, Mods, TerminalDecoder, TerminalEvent};
+    ///
+    /// let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES | KittyFlags::REPORT_EVENT_TYPES;
+    ///
+    /// // Write this sequence to the terminal to enable the protocol
+    /// assert_eq!(flags.push_sequence(), b"\x1b[>3u");
+    ///
+    /// let mut decoder = TerminalDecoder::new();
+    /// decoder.feed(b"\x1b[105;5u\x1b[105;5:3u");
+    /// assert_eq!(
+    ///     decoder.next_event(),
+    ///     Some(TerminalEvent::Key(KeyInput::new('i', Mods::CTRL))),
+    /// );
+    /// assert_eq!(
+    ///     decoder.next_event(),
+    ///     Some(TerminalEvent::KeyRelease(KeyInput::new('i', Mods::CTRL))),
+    /// );
+    ///
+    /// // Write this sequence to the terminal to restore the previous flags
+    /// assert_eq!(KittyFlags::POP_SEQUENCE, b"\x1b[<u");
+    /// ```
+    #[repr(transparent)]
+    #[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub struct KittyFlags: u8 {
+        /// Report the keys which are ambiguous in the legacy encoding such as <kbd>Esc</kbd> or
+        /// <kbd>Ctrl</kbd> + <kbd>i</kbd> as CSI u sequences.
+        const DISAMBIGUATE_ESCAPE_CODES = 0b1;
+        /// Report key repeats and key releases.
+        const REPORT_EVENT_TYPES = 0b10;
+        /// Report the shifted keys such as `A` of <kbd>Shift</kbd> + <kbd>a</kbd> in addition to the unshifted keys.
+        const REPORT_ALTERNATE_KEYS = 0b100;
+        /// Report all keys including text keys and modifier keys as CSI u sequences.
+        const REPORT_ALL_KEYS_AS_ESCAPE_CODES = 0b1000;
+        /// Report the text generated by the key input along with the key.
+        const REPORT_ASSOCIATED_TEXT = 0b10000;
+    }
+}
+
+impl KittyFlags {
+    /// Escape sequence to restore the flags before the last [`KittyFlags::push_sequence`].
+    pub const POP_SEQUENCE: &'static [u8] = b"\x1b[<u";
+
+    /// Escape sequence to query the current flags. The terminal replies the flags and it is decoded as
+    /// [`TerminalEvent::KittyFlags`] by [`TerminalDecoder`](crate::TerminalDecoder).
+    ///
+    /// ```
+    /// use keybinds::{KittyFlags, TerminalDecoder, TerminalEvent};
+    ///
+    /// assert_eq!(KittyFlags::QUERY_SEQUENCE, b"\x1b[?u");
+    ///
+    /// // Reply from the terminal
+    /// let mut decoder = TerminalDecoder::new();
+    /// decoder.feed(b"\x1b[?1u");
+    /// assert_eq!(
+    ///     decoder.next_event(),
+    ///     Some(TerminalEvent::KittyFlags(KittyFlags::DISAMBIGUATE_ESCAPE_CODES)),
+    /// );
+    /// ```
+    pub const QUERY_SEQUENCE: &'static [u8] = b"\x1b[?u";
+
+    /// Escape sequence to push the flags to the terminal's stack. The flags are enabled until they are popped by
+    /// [`KittyFlags::POP_SEQUENCE`].
+    ///
+    /// ```
+    /// use keybinds::KittyFlags;
+    ///
+    /// let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES | KittyFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES;
+    /// assert_eq!(flags.push_sequence(), b"\x1b[>9u");
+    /// ```
+    pub fn push_sequence(self) -> Vec<u8> {
+        format!("\x1b[>{}u", self.bits()).into_bytes()
+    }
+}
+
+const FUNCTION_KEYS: [Key; 35] = [
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+    Key::F21,
+    Key::F22,
+    Key::F23,
+    Key::F24,
+    Key::F25,
+    Key::F26,
+    Key::F27,
+    Key::F28,
+    Key::F29,
+    Key::F30,
+    Key::F31,
+    Key::F32,
+    Key::F33,
+    Key::F34,
+    Key::F35,
+];
+
+// The key codes of F13 to F35 are consecutive
+const F13_CODE: u32 = 57376;
+
+// Keys on the numeric keypad. They are distinguished by `Mods::NUMPAD`.
+const KEYPAD_KEYS: [(u32, Key); 28] = [
+    (57399, Key::Char('0')),
+    (57400, Key::Char('1')),
+    (57401, Key::Char('2')),
+    (57402, Key::Char('3')),
+    (57403, Key::Char('4')),
+    (57404, Key::Char('5')),
+    (57405, Key::Char('6')),
+    (57406, Key::Char('7')),
+    (57407, Key::Char('8')),
+    (57408, Key::Char('9')),
+    (57409, Key::Char('.')),
+    (57410, Key::Char('/')),
+    (57411, Key::Char('*')),
+    (57412, Key::Char('-')),
+    (57413, Key::Char('+')),
+    (57414, Key::Enter),
+    (57415, Key::Char('=')),
+    (57416, Key::Char(',')),
+    (57417, Key::Left),
+    (57418, Key::Right),
+    (57419, Key::Up),
+    (57420, Key::Down),
+    (57421, Key::PageUp),
+    (57422, Key::PageDown),
+    (57423, Key::Home),
+    (57424, Key::End),
+    (57425, Key::Insert),
+    (57426, Key::Delete),
+];
+
+// Functional keys encoded as CSI u sequences
+const FUNCTIONAL_KEYS: [(u32, Key); 21] = [
+    (27, Key::Esc),
+    (13, Key::Enter),
+    (9, Key::Tab),
+    (127, Key::Backspace),
+    (57359, Key::ScrollLock),
+    (57360, Key::NumLock),
+    (57361, Key::PrintScreen),
+    (57362, Key::Pause),
+    (57363, Key::Menu),
+    (57428, Key::Play),
+    (57429, Key::Pause),
+    (57430, Key::PlayPause),
+    (57432, Key::Stop),
+    (57433, Key::FastForward),
+    (57434, Key::Rewind),
+    (57435, Key::NextTrack),
+    (57436, Key::PrevTrack),
+    (57437, Key::Record),
+    (57438, Key::VolumeDown),
+    (57439, Key::VolumeUp),
+    (57440, Key::Mute),
+];
+
+// Range of the key codes of the functional keys in the Unicode private use area
+const FUNCTIONAL_CODES: std::ops::RangeInclusive<u32> = 57344..=57454;
+
+// Convert the key code of functional keys in the private use area of Unicode. Modifier keys and CapsLock are ignored.
+pub(crate) fn functional_key(code: u32) -> Option<(Key, Mods)> {
+    if !FUNCTIONAL_CODES.contains(&code) {
+        return None;
+    }
+    if let Some(idx) = code.checked_sub(F13_CODE) {
+        if let Some(&key) = FUNCTION_KEYS.get(idx as usize + 12) {
+            return Some((key, Mods::NONE));
+        }
+    }
+    if let Some((_, key)) = KEYPAD_KEYS.iter().find(|(c, _)| *c == code) {
+        return Some((*key, Mods::NUMPAD));
+    }
+    let key = match code {
+        57358 => Key::Ignored,         // CapsLock
+        57441..=57454 => Key::Ignored, // Modifier keys
+        _ => FUNCTIONAL_KEYS
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, k)| *k)
+            .unwrap_or(Key::Unidentified),
+    };
+    Some((key, Mods::NONE))
+}
+
+// Keys encoded in the legacy form such as `ESC [ 1 ; 5 A` or `ESC [ 3 ; 5 ~` in the kitty keyboard protocol
+fn legacy_key(key: Key) -> Option<(u32, u8)> {
+    let seq = match key {
+        Key::Up => (1, b'A'),
+        Key::Down => (1, b'B'),
+        Key::Right => (1, b'C'),
+        Key::Left => (1, b'D'),
+        Key::Home => (1, b'H'),
+        Key::End => (1, b'F'),
+        Key::F1 => (1, b'P'),
+        Key::F2 => (1, b'Q'),
+        Key::F3 => (13, b'~'),
+        Key::F4 => (1, b'S'),
+        Key::Insert => (2, b'~'),
+        Key::Delete => (3, b'~'),
+        Key::PageUp => (5, b'~'),
+        Key::PageDown => (6, b'~'),
+        Key::F5 => (15, b'~'),
+        Key::F6 => (17, b'~'),
+        Key::F7 => (18, b'~'),
+        Key::F8 => (19, b'~'),
+        Key::F9 => (20, b'~'),
+        Key::F10 => (21, b'~'),
+        Key::F11 => (23, b'~'),
+        Key::F12 => (24, b'~'),
+        _ => return None,
+    };
+    Some(seq)
+}
+
+fn functional_code(key: Key, numpad: bool) -> Option<u32> {
+    if numpad {
+        if let Some((code, _)) = KEYPAD_KEYS.iter().find(|(_, k)| *k == key) {
+            return Some(*code);
+        }
+    }
+    if let Some(idx) = FUNCTION_KEYS.iter().position(|k| *k == key) {
+        if idx >= 12 {
+            return Some(F13_CODE + idx as u32 - 12);
+        }
+    }
+    FUNCTIONAL_KEYS
+        .iter()
+        .find(|(_, k)| *k == key)
+        .map(|(c, _)| *c)
+}
+
+fn to_lowercase(c: char) -> char {
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(l), None) => l,
+        _ => c,
+    }
+}
+
+impl TerminalEvent {
+    /// Encode the event into the escape sequence of the [kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/)
+    /// following the progressive enhancement flags. This is useful to forward key inputs to applications running in
+    /// terminal multiplexers. When the flags are empty, the event is encoded in the legacy way by
+    /// [`TerminalEvent::encode_legacy`]. Otherwise [`KittyFlags::DISAMBIGUATE_ESCAPE_CODES`] is assumed to be enabled.
+    ///
+    /// - Key inputs which generate text such as `a` or `A` are encoded as the text itself unless
+    ///   [`KittyFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES`] is enabled
+    /// - Key releases are encoded into empty bytes unless [`KittyFlags::REPORT_EVENT_TYPES`] is enabled
+    /// - Pasted text is encoded with the bracketed paste markers. `ESC` bytes in the text are removed so that the text
+    ///   cannot end the paste early
+    /// - Flags are encoded as the reply to [`KittyFlags::QUERY_SEQUENCE`]
+    ///
+    /// The virtual modifiers [`Mods::MOD`] and [`Mods::SUPER`] are resolved on the platform this program is compiled
+    /// for. Use [`TerminalEvent::encode_kitty_with_platform`] to resolve them on another platform.
+    /// <kbd>Cmd</kbd> and <kbd>Win</kbd> are encoded as the super modifier. Key inputs which cannot be represented by the
+    /// protocol such as mouse buttons cause [`Error::UnencodableInput`].
+    ///
+    /// ```
+    /// use keybinds::{Error, Key, KeyInput, KittyFlags, Mods, TerminalEvent};
+    ///
+    /// let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES;
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new('i', Mods::CTRL));
+    /// assert_eq!(event.encode_kitty(flags).unwrap(), b"\x1b[105;5u");
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new(Key::Up, Mods::SHIFT));
+    /// assert_eq!(event.encode_kitty(flags).unwrap(), b"\x1b[1;2A");
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new('a', Mods::NONE));
+    /// assert_eq!(event.encode_kitty(flags).unwrap(), b"a");
+    ///
+    /// let input = KeyInput::new(Key::MouseLeft, Mods::NONE);
+    /// let event = TerminalEvent::Key(input);
+    /// assert_eq!(event.encode_kitty(flags), Err(Error::UnencodableInput(input)));
+    /// ```
+    pub fn encode_kitty(&self, flags: KittyFlags) -> Result<Vec<u8>> {
+        self.encode_kitty_with_platform(flags, Platform::current())
+    }
+
+    /// Same as [`TerminalEvent::encode_kitty`] but on the given platform. See [`Platform`] for which to pass.
+    ///
+    /// ```
+    /// use keybinds::{KeyInput, KittyFlags, Mods, Platform, TerminalEvent};
+    ///
+    /// let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES;
+    /// let event = TerminalEvent::Key(KeyInput::new('a', Mods::MOD));
+    /// assert_eq!(event.encode_kitty_with_platform(flags, Platform::MacOS).unwrap(), b"\x1b[97;9u");
+    /// assert_eq!(event.encode_kitty_with_platform(flags, Platform::Linux).unwrap(), b"\x1b[97;5u");
+    /// ```
+    pub fn encode_kitty_with_platform(
+        &self,
+        flags: KittyFlags,
+        platform: Platform,
+    ) -> Result<Vec<u8>> {
+        let (input, release) = match self {
+            Self::Key(input) => (input, false),
+            Self::KeyRelease(input) => (input, true),
+            Self::Paste(text) => return Ok(encode_paste(text)),
+            Self::KittyFlags(flags) => return Ok(format!("\x1b[?{}u", flags.bits()).into_bytes()),
+        };
+        if flags.is_empty() {
+            return self.encode_legacy_with_platform(platform);
+        }
+        if release && !flags.contains(KittyFlags::REPORT_EVENT_TYPES) {
+            return Ok(vec![]);
+        }
+        let all_keys = flags.contains(KittyFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES);
+
+        let mut mods = resolve_mods(input.physical_mods(), platform);
+        if !all_keys {
+            mods.remove(Mods::CAPS_LOCK | Mods::NUM_LOCK); // Lock states are only reported with all keys
+        }
+        let key = input.key();
+        let unencodable = || Error::UnencodableInput(*input);
+
+        // Keys which generate text and Enter, Tab, Backspace are encoded in the legacy way
+        let numpad = mods.contains(Mods::NUMPAD);
+        mods.remove(Mods::NUMPAD);
+        if !all_keys && !release && !numpad {
+            let text = match key {
+                Key::Char(c) if mods.difference(Mods::ANY_SHIFT).is_empty() => Some(c.to_string()),
+                Key::Str(s) if mods.is_empty() => Some(s.as_str().to_string()),
+                Key::Enter if mods.is_empty() => Some("\r".to_string()),
+                Key::Tab if mods.is_empty() => Some("\t".to_string()),
+                Key::Backspace if mods.is_empty() => Some("\x7f".to_string()),
+                _ => None,
+            };
+            if let Some(text) = text {
+                return Ok(text.into_bytes());
+            }
+        }
+
+        let mut shifted = None;
+        let mut text = None;
+        let (code, fin) = if let Some(code) = functional_code(key, numpad) {
+            (code, b'u')
+        } else if let Some(seq) = legacy_key(key) {
+            seq
+        } else if let Key::Char(c) = key {
+            let lower = to_lowercase(c);
+            if lower != c {
+                mods |= Mods::SHIFT;
+                if flags.contains(KittyFlags::REPORT_ALTERNATE_KEYS) {
+                    shifted = Some(c);
+                }
+            }
+            let generates_text =
+                !mods.intersects(Mods::CTRL | Mods::ALT | Mods::SUPER | Mods::HYPER | Mods::META);
+            if all_keys
+                && !release
+                && generates_text
+                && flags.contains(KittyFlags::REPORT_ASSOCIATED_TEXT)
+            {
+                text = Some(c);
+            }
+            (lower as u32, b'u')
+        } else {
+            return Err(unencodable());
+        };
+
+        let param = mods_param(mods);
+        let mut seq = String::from("\x1b[");
+        if code != 1 || param > 1 || release {
+            write!(seq, "{code}").unwrap();
+        }
+        if let Some(c) = shifted {
+            write!(seq, ":{}", c as u32).unwrap();
+        }
+        if param > 1 || release || text.is_some() {
+            write!(seq, ";{param}").unwrap();
+            if release {
+                seq.push_str(":3");
+            }
+        }
+        if let Some(c) = text {
+            write!(seq, ";{}", c as u32).unwrap();
+        }
+        seq.push(fin as char);
+        Ok(seq.into_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyInput, TerminalDecoder};
+
+    fn decode_all(bytes: &[u8]) -> Vec<TerminalEvent> {
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(bytes);
+        let mut events = vec![];
+        while let Some(event) = decoder.next_event() {
+            events.push(event);
+        }
+        assert!(!decoder.has_pending(), "{bytes:?}");
+        events
+    }
+
+    fn press(key: impl Into<Key>, mods: Mods) -> TerminalEvent {
+        TerminalEvent::Key(KeyInput::new(key, mods))
+    }
+
+    fn release(key: impl Into<Key>, mods: Mods) -> TerminalEvent {
+        TerminalEvent::KeyRelease(KeyInput::new(key, mods))
+    }
+
+    #[test]
+    fn decode_csi_u() {
+        for (bytes, expected) in [
+            (&b"\x1b[97u"[..], press('a', Mods::NONE)),
+            (b"\x1b[97;5u", press('a', Mods::CTRL)),
+            (b"\x1b[105;5u", press('i', Mods::CTRL)),
+            (b"\x1b[9;5u", press(Key::Tab, Mods::CTRL)),
+            (b"\x1b[27u", press(Key::Esc, Mods::NONE)),
+            (b"\x1b[13;2u", press(Key::Enter, Mods::SHIFT)),
+            (b"\x1b[127;3u", press(Key::Backspace, Mods::ALT)),
+            (b"\x1b[97;9u", press('a', Mods::SUPER)),
+            (b"\x1b[97;17u", press('a', Mods::HYPER)),
+            (b"\x1b[97;33u", press('a', Mods::META)),
+            (b"\x1b[97;65u", press('a', Mods::CAPS_LOCK)),
+            (b"\x1b[97;129u", press('a', Mods::NUM_LOCK)),
+            // Shifted keys
+            (b"\x1b[97;2u", press('A', Mods::NONE)),
+            (b"\x1b[97:65;6u", press('A', Mods::CTRL)),
+            (b"\x1b[49:33;2u", press('!', Mods::NONE)),
+            (b"\x1b[49:33:49;2u", press('!', Mods::NONE)),
+            // Associated text
+            (b"\x1b[97;1;97u", press('a', Mods::NONE)),
+            (b"\x1b[97;2;65u", press('A', Mods::NONE)),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_functional_keys() {
+        for (bytes, expected) in [
+            (&b"\x1b[57376u"[..], press(Key::F13, Mods::NONE)),
+            (b"\x1b[57398;5u", press(Key::F35, Mods::CTRL)),
+            (b"\x1b[57399u", press('0', Mods::NUMPAD)),
+            (b"\x1b[57414u", press(Key::Enter, Mods::NUMPAD)),
+            (b"\x1b[57419;2u", press(Key::Up, Mods::NUMPAD | Mods::SHIFT)),
+            (b"\x1b[57361u", press(Key::PrintScreen, Mods::NONE)),
+            (b"\x1b[57430u", press(Key::PlayPause, Mods::NONE)),
+            (b"\x1b[57440u", press(Key::Mute, Mods::NONE)),
+            (b"\x1b[57358u", press(Key::Ignored, Mods::NONE)),
+            (b"\x1b[57441;2u", press(Key::Ignored, Mods::SHIFT)),
+            (b"\x1b[57454u", press(Key::Ignored, Mods::NONE)),
+            (b"\x1b[57427u", press(Key::Unidentified, Mods::NONE)),
+            (b"\x1b[57344u", press(Key::Unidentified, Mods::NONE)),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_event_types() {
+        for (bytes, expected) in [
+            (&b"\x1b[97;1:1u"[..], press('a', Mods::NONE)),
+            (b"\x1b[97;1:2u", press('a', Mods::NONE)),
+            (b"\x1b[97;1:3u", release('a', Mods::NONE)),
+            (b"\x1b[97;5:3u", release('a', Mods::CTRL)),
+            (b"\x1b[1;1:3A", release(Key::Up, Mods::NONE)),
+            (b"\x1b[1;5:3D", release(Key::Left, Mods::CTRL)),
+            (b"\x1b[3;1:3~", release(Key::Delete, Mods::NONE)),
+            (b"\x1b[13;1:3~", release(Key::F3, Mods::NONE)),
+            (b"\x1b[1;1:2P", press(Key::F1, Mods::NONE)),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_flags() {
+        assert_eq!(
+            decode_all(b"\x1b[?0u\x1b[?31u\x1b[?255u"),
+            [
+                TerminalEvent::KittyFlags(KittyFlags::empty()),
+                TerminalEvent::KittyFlags(KittyFlags::all()),
+                TerminalEvent::KittyFlags(KittyFlags::all()),
+            ],
+        );
+    }
+
+    #[test]
+    fn encode_keys() {
+        let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES;
+        for (event, expected) in [
+            (press('a', Mods::NONE), &b"a"[..]),
+            (press('A', Mods::NONE), b"A"),
+            (press('あ', Mods::NONE), "あ".as_bytes()),
+            (press(' ', Mods::NONE), b" "),
+            (press(Key::Enter, Mods::NONE), b"\r"),
+            (press(Key::Tab, Mods::NONE), b"\t"),
+            (press(Key::Backspace, Mods::NONE), b"\x7f"),
+            (press(Key::Esc, Mods::NONE), b"\x1b[27u"),
+            (press('a', Mods::CTRL), b"\x1b[97;5u"),
+            (press('A', Mods::CTRL), b"\x1b[97;6u"),
+            (press('i', Mods::CTRL), b"\x1b[105;5u"),
+            (press(Key::Tab, Mods::SHIFT), b"\x1b[9;2u"),
+            (press(Key::Enter, Mods::ALT), b"\x1b[13;3u"),
+            (press('x', Mods::HYPER | Mods::META), b"\x1b[120;49u"),
+            (press('a', Mods::ALT | Mods::CAPS_LOCK), b"\x1b[97;3u"),
+            (press(Key::Up, Mods::NONE), b"\x1b[A"),
+            (press(Key::Up, Mods::CTRL), b"\x1b[1;5A"),
+            (press(Key::F1, Mods::NONE), b"\x1b[P"),
+            (press(Key::F3, Mods::NONE), b"\x1b[13~"),
+            (press(Key::Delete, Mods::NONE), b"\x1b[3~"),
+            (press(Key::PageUp, Mods::SHIFT), b"\x1b[5;2~"),
+            (press(Key::F12, Mods::NONE), b"\x1b[24~"),
+            (press(Key::F13, Mods::NONE), b"\x1b[57376u"),
+            (press(Key::F35, Mods::CTRL), b"\x1b[57398;5u"),
+            (press(Key::PlayPause, Mods::NONE), b"\x1b[57430u"),
+            (press('5', Mods::NUMPAD), b"\x1b[57404u"),
+            (press(Key::Enter, Mods::NUMPAD), b"\x1b[57414u"),
+            (press(Key::Up, Mods::NUMPAD), b"\x1b[57419u"),
+            (release('a', Mods::NONE), b""),
+            (TerminalEvent::Paste("foo".into()), b"\x1b[200~foo\x1b[201~"),
+            (
+                TerminalEvent::Paste("foo\x1b[201~\x03bar".into()),
+                b"\x1b[200~foo[201~\x03bar\x1b[201~",
+            ),
+            (
+                TerminalEvent::KittyFlags(KittyFlags::REPORT_EVENT_TYPES),
+                b"\x1b[?2u",
+            ),
+        ] {
+            assert_eq!(event.encode_kitty(flags).unwrap(), expected, "{event:?}");
+        }
+    }
+
+    #[test]
+    fn encode_legacy_with_empty_flags() {
+        let flags = KittyFlags::empty();
+        for event in [
+            press('a', Mods::CTRL),
+            press(Key::Esc, Mods::NONE),
+            press(Key::Up, Mods::SHIFT),
+            release('a', Mods::NONE),
+        ] {
+            assert_eq!(
+                event.encode_kitty(flags),
+                event.encode_legacy(),
+                "{event:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn encode_keys_with_flags() {
+        let events = KittyFlags::DISAMBIGUATE_ESCAPE_CODES | KittyFlags::REPORT_EVENT_TYPES;
+        let all =
+            KittyFlags::DISAMBIGUATE_ESCAPE_CODES | KittyFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES;
+        let alternate = KittyFlags::DISAMBIGUATE_ESCAPE_CODES | KittyFlags::REPORT_ALTERNATE_KEYS;
+        for (event, flags, expected) in [
+            (release('a', Mods::NONE), events, &b"\x1b[97;1:3u"[..]),
+            (release('a', Mods::CTRL), events, b"\x1b[97;5:3u"),
+            (release(Key::Up, Mods::NONE), events, b"\x1b[1;1:3A"),
+            (release(Key::Delete, Mods::NONE), events, b"\x1b[3;1:3~"),
+            (press('a', Mods::NONE), events, b"a"),
+            (press('a', Mods::NONE), all, b"\x1b[97u"),
+            (press('A', Mods::NONE), all, b"\x1b[97;2u"),
+            (press(Key::Enter, Mods::NONE), all, b"\x1b[13u"),
+            (press('a', Mods::NUM_LOCK), all, b"\x1b[97;129u"),
+            (press('A', Mods::NONE), alternate | all, b"\x1b[97:65;2u"),
+            (press('A', Mods::CTRL), alternate, b"\x1b[97:65;6u"),
+            (
+                press('a', Mods::NONE),
+                all | KittyFlags::REPORT_ASSOCIATED_TEXT,
+                b"\x1b[97;1;97u",
+            ),
+            (
+                press('A', Mods::NONE),
+                all | KittyFlags::REPORT_ASSOCIATED_TEXT,
+                b"\x1b[97;2;65u",
+            ),
+            (
+                press('a', Mods::CTRL),
+                all | KittyFlags::REPORT_ASSOCIATED_TEXT,
+                b"\x1b[97;5u",
+            ),
+        ] {
+            assert_eq!(
+                event.encode_kitty(flags).unwrap(),
+                expected,
+                "{event:?} {flags:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn encode_resolved_mods() {
+        let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES;
+        for mods in [Mods::CMD, Mods::WIN, Mods::SUPER] {
+            let event = press('a', mods);
+            assert_eq!(
+                event.encode_kitty(flags).unwrap(),
+                b"\x1b[97;9u",
+                "{mods:?}",
+            );
+        }
+        let expected: &[u8] = if Platform::current() == Platform::MacOS {
+            b"\x1b[97;9u"
+        } else {
+            b"\x1b[97;5u"
+        };
+        assert_eq!(press('a', Mods::MOD).encode_kitty(flags).unwrap(), expected,);
+
+        for (platform, expected) in [
+            (Platform::MacOS, &b"\x1b[97;9u"[..]),
+            (Platform::Linux, b"\x1b[97;5u"),
+            (Platform::Windows, b"\x1b[97;5u"),
+        ] {
+            let event = press('a', Mods::MOD);
+            let encoded = event.encode_kitty_with_platform(flags, platform).unwrap();
+            assert_eq!(encoded, expected, "{platform:?}");
+        }
+        let event = press('a', Mods::MOD);
+        let encoded = event.encode_kitty_with_platform(KittyFlags::empty(), Platform::Linux);
+        assert_eq!(encoded.unwrap(), b"\x01");
+        let encoded = event.encode_kitty_with_platform(KittyFlags::empty(), Platform::MacOS);
+        assert_eq!(
+            encoded,
+            Err(Error::UnencodableInput(KeyInput::new('a', Mods::MOD)))
+        );
+    }
+
+    #[test]
+    fn encode_unencodable_keys() {
+        let flags = KittyFlags::DISAMBIGUATE_ESCAPE_CODES;
+        for input in [
+            KeyInput::new(Key::MouseLeft, Mods::NONE),
+            KeyInput::new(Key::WheelUp, Mods::CTRL),
+            KeyInput::new(Key::Copy, Mods::NONE),
+            KeyInput::new(Key::Unidentified, Mods::NONE),
+            KeyInput::new(Key::Ignored, Mods::NONE),
+            KeyInput::new(Key::Str(crate::KeyStr::new("👍🏽").unwrap()), Mods::CTRL),
+            KeyInput::new(Key::Physical(crate::Code::KeyA), Mods::NONE),
+        ] {
+            assert_eq!(
+                TerminalEvent::Key(input).encode_kitty(flags),
+                Err(Error::UnencodableInput(input)),
+            );
+        }
+        // Multi-character text can be encoded without modifiers
+        assert_eq!(
+            press(Key::Str(crate::KeyStr::new("👍🏽").unwrap()), Mods::NONE)
+                .encode_kitty(flags)
+                .unwrap(),
+            "👍🏽".as_bytes(),
+        );
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let flags = KittyFlags::all();
+        for event in [
+            press('a', Mods::NONE),
+            press('A', Mods::CTRL),
+            press('i', Mods::CTRL | Mods::ALT | Mods::SHIFT),
+            press('あ', Mods::SUPER),
+            press(Key::Esc, Mods::NONE),
+            press(Key::Enter, Mods::SHIFT),
+            press(Key::Tab, Mods::CTRL),
+            press(Key::Backspace, Mods::NONE),
+            press(Key::Up, Mods::NONE),
+            press(Key::End, Mods::HYPER),
+            press(Key::F1, Mods::META),
+            press(Key::F3, Mods::CTRL),
+            press(Key::F24, Mods::NONE),
+            press(Key::PageDown, Mods::NONE),
+            press('9', Mods::NUMPAD | Mods::CTRL),
+            press(Key::VolumeUp, Mods::NONE),
+            press('x', Mods::CAPS_LOCK),
+            release('a', Mods::NONE),
+            release(Key::Home, Mods::ALT),
+            TerminalEvent::Paste("foo\nbar".into()),
+            TerminalEvent::KittyFlags(KittyFlags::REPORT_ALTERNATE_KEYS),
+        ] {
+            let encoded = event.encode_kitty(flags).unwrap();
+            assert_eq!(decode_all(&encoded), vec![event], "{encoded:?}");
+        }
+    }
+
+    #[test]
+    fn encode_paste_with_end_marker() {
+        let event = TerminalEvent::Paste("foo\x1b[201~\x03bar".into());
+        let encoded = event.encode_kitty(KittyFlags::all()).unwrap();
+        assert_eq!(
+            decode_all(&encoded),
+            [TerminalEvent::Paste("foo[201~\x03bar".into())],
+        );
+    }
+}
@@ -0,0 +1,262 @@
+/// Keyboard layout to normalize a key input with <kbd>Shift</kbd> modifier such as `Shift+a` into the character typed
+/// by the key combination such as `A`.
+///
+/// By default, `Shift` modifier is only available with named keys as described in the [syntax document](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
+/// With a layout, `Shift` modifier with a character key is converted into the shifted character on the layout when
+/// parsing a key sequence. See [`KeySeq::parse_with_layout`][crate::KeySeq::parse_with_layout] and
+/// [`Keybinds::set_layout`][crate::Keybinds::set_layout].
+///
+/// ```
+/// use keybinds::{Layout, KeySeq, KeyInput, Mods};
+///
+/// assert_eq!(Layout::Us.shift('a'), Some('A'));
+/// assert_eq!(Layout::Us.shift('1'), Some('!'));
+/// assert_eq!(Layout::Uk.shift('3'), Some('£'));
+/// assert_eq!(Layout::Us.shift('A'), None); // Not an unshifted character
+///
+/// let seq = KeySeq::parse_with_layout("Ctrl+Shift+p", Layout::Us).unwrap();
+/// assert_eq!(seq, KeySeq::from(KeyInput::new('P', Mods::CTRL)));
+/// ```
+///
+/// This enum is non-exhaustive because more layouts may be added in the future.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Layout {
+    /// US QWERTY layout.
+    Us,
+    /// UK QWERTY layout.
+    Uk,
+    /// Japanese JIS layout.
+    Jis,
+    /// German QWERTZ layout.
+    German,
+    /// French AZERTY layout.
+    French,
+}
+
+impl Layout {
+    /// Return the character typed by pressing the key of the given unshifted character with <kbd>Shift</kbd> on the
+    /// layout. `None` is returned when the character is not typed without <kbd>Shift</kbd> on the layout or the key
+    /// has no shifted character.
+    ///
+    /// Lowercase letters are converted into uppercase letters. Symbols and digits are converted following the layout.
+    ///
+    /// ```
+    /// use keybinds::Layout;
+    ///
+    /// assert_eq!(Layout::Us.shift('2'), Some('@'));
+    /// assert_eq!(Layout::Jis.shift('2'), Some('"'));
+    /// assert_eq!(Layout::German.shift('ü'), Some('Ü'));
+    /// assert_eq!(Layout::French.shift('é'), Some('2'));
+    /// assert_eq!(Layout::Us.shift('@'), None);
+    /// ```
+    pub fn shift(self, c: char) -> Option<char> {
+        if let Some((_, shifted)) = self.table().iter().find(|(unshifted, _)| *unshifted == c) {
+            return Some(*shifted);
+        }
+
+        if c.is_lowercase() {
+            let mut upper = c.to_uppercase();
+            if let (Some(u), None) = (upper.next(), upper.next()) {
+                return Some(u);
+            }
+        }
+
+        None
+    }
+
+    // Return true when the character is typed with Shift on the layout such as `A` or `!` on the US layout. Shift
+    // modifier with such a character is redundant.
+    pub(crate) fn is_shifted(self, c: char) -> bool {
+        if self.table().iter().any(|(_, shifted)| *shifted == c) {
+            return true;
+        }
+        let mut lower = c.to_lowercase();
+        match (lower.next(), lower.next()) {
+            (Some(l), None) if l != c => self.shift(l) == Some(c),
+            _ => false,
+        }
+    }
+
+    // Pairs of unshifted and shifted characters other than letters
+    fn table(self) -> &'static [(char, char)] {
+        match self {
+            Self::Us => &[
+                ('`', '~'),
+                ('1', '!'),
+                ('2', '@'),
+                ('3', '#'),
+                ('4', '$'),
+                ('5', '%'),
+                ('6', '^'),
+                ('7', '&'),
+                ('8', '*'),
+                ('9', '('),
+                ('0', ')'),
+                ('-', '_'),
+                ('=', '+'),
+                ('[', '{'),
+                (']', '}'),
+                ('\\', '|'),
+                (';', ':'),
+                ('\'', '"'),
+                (',', '<'),
+                ('.', '>'),
+                ('/', '?'),
+            ],
+            Self::Uk => &[
+                ('`', '¬'),
+                ('1', '!'),
+                ('2', '"'),
+                ('3', '£'),
+                ('4', '$'),
+                ('5', '%'),
+                ('6', '^'),
+                ('7', '&'),
+                ('8', '*'),
+                ('9', '('),
+                ('0', ')'),
+                ('-', '_'),
+                ('=', '+'),
+                ('[', '{'),
+                (']', '}'),
+                ('#', '~'),
+                ('\\', '|'),
+                (';', ':'),
+                ('\'', '@'),
+                (',', '<'),
+                ('.', '>'),
+                ('/', '?'),
+            ],
+            Self::Jis => &[
+                ('1', '!'),
+                ('2', '"'),
+                ('3', '#'),
+                ('4', '$'),
+                ('5', '%'),
+                ('6', '&'),
+                ('7', '\''),
+                ('8', '('),
+                ('9', ')'),
+                ('-', '='),
+                ('^', '~'),
+                ('¥', '|'),
+                ('@', '`'),
+                ('[', '{'),
+                (';', '+'),
+                (':', '*'),
+                (']', '}'),
+                (',', '<'),
+                ('.', '>'),
+                ('/', '?'),
+                ('\\', '_'),
+            ],
+            Self::German => &[
+                ('^', '°'),
+                ('1', '!'),
+                ('2', '"'),
+                ('3', '§'),
+                ('4', '$'),
+                ('5', '%'),
+                ('6', '&'),
+                ('7', '/'),
+                ('8', '('),
+                ('9', ')'),
+                ('0', '='),
+                ('ß', '?'),
+                ('´', '`'),
+                ('+', '*'),
+                ('#', '\''),
+                ('<', '>'),
+                (',', ';'),
+                ('.', ':'),
+                ('-', '_'),
+            ],
+            Self::French => &[
+                ('&', '1'),
+                ('é', '2'),
+                ('"', '3'),
+                ('\'', '4'),
+                ('(', '5'),
+                ('-', '6'),
+                ('è', '7'),
+                ('_', '8'),
+                ('ç', '9'),
+                ('à', '0'),
+                (')', '°'),
+                ('=', '+'),
+                ('^', '¨'),
+                ('$', '£'),
+                ('ù', '%'),
+                ('*', 'µ'),
+                ('<', '>'),
+                (',', '?'),
+                (';', '.'),
+                (':', '/'),
+                ('!', '§'),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shift_letters() {
+        for layout in [
+            Layout::Us,
+            Layout::Uk,
+            Layout::Jis,
+            Layout::German,
+            Layout::French,
+        ] {
+            assert_eq!(layout.shift('a'), Some('A'), "layout={layout:?}");
+            assert_eq!(layout.shift('z'), Some('Z'), "layout={layout:?}");
+            assert_eq!(layout.shift('A'), None, "layout={layout:?}");
+            assert_eq!(layout.shift(' '), None, "layout={layout:?}");
+        }
+        assert_eq!(Layout::German.shift('ä'), Some('Ä'));
+        assert_eq!(Layout::German.shift('ß'), Some('?'));
+    }
+
+    #[test]
+    fn shift_symbols() {
+        for (layout, c, expected) in [
+            (Layout::Us, '1', Some('!')),
+            (Layout::Us, '/', Some('?')),
+            (Layout::Us, '?', None),
+            (Layout::Uk, '2', Some('"')),
+            (Layout::Uk, '\'', Some('@')),
+            (Layout::Jis, '7', Some('\'')),
+            (Layout::Jis, '0', None),
+            (Layout::Jis, ':', Some('*')),
+            (Layout::German, '7', Some('/')),
+            (Layout::German, 'y', Some('Y')),
+            (Layout::French, '&', Some('1')),
+            (Layout::French, 'ù', Some('%')),
+            (Layout::French, '1', None),
+        ] {
+            assert_eq!(layout.shift(c), expected, "layout={layout:?} c={c:?}");
+        }
+    }
+
+    #[test]
+    fn shifted_chars() {
+        for (layout, c, expected) in [
+            (Layout::Us, 'A', true),
+            (Layout::Us, '!', true),
+            (Layout::Us, 'a', false),
+            (Layout::Us, '1', false),
+            (Layout::Us, 'é', false),
+            (Layout::Uk, '£', true),
+            (Layout::German, 'Ö', true),
+            (Layout::German, '?', true),
+            (Layout::French, '1', true),
+            (Layout::French, '&', false),
+        ] {
+            assert_eq!(layout.is_shifted(c), expected, "layout={layout:?} c={c:?}");
+        }
+    }
+}
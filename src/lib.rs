@@ -6,12 +6,22 @@
 //! - Provide the syntax to easily define key bindings in a configuration file like `Ctrl+a`
 //! - Support key sequences like `Ctrl+x Ctrl+s` for complicated key bindings like Vim style
 //! - Core API independent from any platforms and frameworks with minimal dependencies (only two crates)
+//! - Support physical keys like `[KeyW]` for key bindings independent from keyboard layouts
 //! - Support several platforms and frameworks as optional features
 //!   - [crossterm][]
 //!   - [termwiz][]
+//!   - [termion][] (Unix-like platforms only)
 //!   - [winit][]
 //!   - [iced][]
+//!   - [egui][]
+//!   - [bevy][]
+//!   - [keyboard-types][] for W3C key types and `key` strings like `ArrowUp`
+//!   - [gilrs][] for gamepad combo sequences like `Down DownRight Right A`
+//! - Decode raw terminal input bytes into key inputs without any TUI library with [`TerminalDecoder`], including the
+//!   kitty keyboard protocol, and encode key inputs back into the bytes with [`TerminalEvent`]
 //! - Support parsing/generating a key bindings configuration using [serde][] optionally
+//! - Format key bindings for menus and cheat sheets in platform-specific styles like `⌃⌥X` and
+//!   localized key names with [`KeySeqFormatter`]
 //! - Support structure-aware fuzzing using [arbitrary][] optionally.
 //!
 //! # Installation
@@ -85,6 +95,11 @@
 //! [crossterm]: https://crates.io/crates/crossterm
 //! [winit]: https://crates.io/crates/winit
 //! [iced]: https://crates.io/crates/iced
+//! [termion]: https://crates.io/crates/termion
+//! [egui]: https://crates.io/crates/egui
+//! [bevy]: https://crates.io/crates/bevy
+//! [gilrs]: https://crates.io/crates/gilrs
+//! [keyboard-types]: https://crates.io/crates/keyboard-types
 //! [termwiz]: https://crates.io/crates/termwiz
 //! [arbitrary]: https://crates.io/crates/arbitrary
 //! [examples]: https://github.com/rhysd/keybinds-rs/tree/main/examples
@@ -98,28 +113,63 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg, doc_cfg_hide))]
 #![cfg_attr(docsrs, doc(cfg_hide(doc, docsrs)))]
 
+mod code;
 mod error;
+mod fallback;
+mod format;
+mod input;
+mod intern;
 mod key;
 mod keybind;
+mod kitty;
+mod layout;
+mod locale;
+mod platform;
+mod terminal;
 
 #[cfg(feature = "crossterm")]
 pub mod crossterm;
 
+#[cfg(feature = "gilrs")]
+pub mod gilrs;
+
 #[cfg(feature = "serde")]
 pub mod serde;
 
 #[cfg(feature = "termwiz")]
 pub mod termwiz;
 
+#[cfg(all(feature = "termion", unix))]
+pub mod termion;
+
 #[cfg(feature = "winit")]
 pub mod winit;
 
 #[cfg(feature = "iced")]
 pub mod iced;
 
+#[cfg(feature = "egui")]
+pub mod egui;
+
+#[cfg(feature = "bevy")]
+pub mod bevy;
+
+#[cfg(feature = "keyboard-types")]
+pub mod keyboard_types;
+
 #[cfg(feature = "arbitrary")]
 pub mod arbitrary;
 
+pub use code::Code;
 pub use error::{Error, Result};
-pub use key::{Key, KeyInput, KeySeq, Match, Mods};
-pub use keybind::{Keybind, Keybinds, DEFAULT_TIMEOUT};
+pub use fallback::LatinFallback;
+pub use format::{FormatStyle, KeySeqFormatter};
+pub use input::Input;
+pub use intern::KeyStr;
+pub use key::{GenericKeySeq, Key, KeyContext, KeyInput, KeySeq, Match, Mods};
+pub use keybind::{GenericKeybind, GenericKeybinds, Keybind, Keybinds, DEFAULT_TIMEOUT};
+pub use kitty::KittyFlags;
+pub use layout::Layout;
+pub use locale::Locale;
+pub use platform::Platform;
+pub use terminal::{TerminalDecoder, TerminalEvent, DEFAULT_ESC_TIMEOUT};
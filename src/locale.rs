@@ -0,0 +1,189 @@
+use crate::{Key, Mods};
+
+/// Locale of the names of keys and modifiers displayed by [`KeySeqFormatter`][crate::KeySeqFormatter].
+///
+/// Each locale is a lookup table of the localized display names of named keys and modifiers. Keys and modifiers
+/// which are not in the table are displayed with the English names. Note that the localized names are only for
+/// displaying key bindings. Parsing key bindings always accepts only the canonical English names described in the
+/// [syntax document](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
+///
+/// ```
+/// use keybinds::{Locale, Key, Mods};
+///
+/// assert_eq!(Locale::German.key_name(Key::Delete), Some("Entf"));
+/// assert_eq!(Locale::German.mod_name(Mods::CTRL), Some("Strg"));
+/// assert_eq!(Locale::French.key_name(Key::Esc), Some("Échap"));
+/// assert_eq!(Locale::English.key_name(Key::Delete), None);
+/// ```
+///
+/// To support other languages or to fix some names, use [`KeySeqFormatter::set_key_name`][crate::KeySeqFormatter::set_key_name]
+/// and [`KeySeqFormatter::set_mod_name`][crate::KeySeqFormatter::set_mod_name].
+///
+/// This enum is non-exhaustive because more locales may be added in the future.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Locale {
+    /// English. This is the default locale.
+    #[default]
+    English,
+    /// Japanese.
+    Japanese,
+    /// German.
+    German,
+    /// French.
+    French,
+}
+
+impl Locale {
+    /// Return the localized name of the key. `None` is returned when the key has no localized name.
+    ///
+    /// ```
+    /// use keybinds::{Locale, Key};
+    ///
+    /// assert_eq!(Locale::Japanese.key_name(Key::Char(' ')), Some("スペース"));
+    /// assert_eq!(Locale::German.key_name(Key::Char('x')), None);
+    /// ```
+    pub fn key_name(self, key: Key) -> Option<&'static str> {
+        match self {
+            Self::English => None,
+            Self::Japanese => match key {
+                Key::Char(' ') => Some("スペース"),
+                Key::Up => Some("上"),
+                Key::Right => Some("右"),
+                Key::Down => Some("下"),
+                Key::Left => Some("左"),
+                Key::Enter => Some("エンター"),
+                Key::Backspace => Some("バックスペース"),
+                Key::Delete => Some("デリート"),
+                Key::Home => Some("ホーム"),
+                Key::End => Some("エンド"),
+                Key::PageUp => Some("ページアップ"),
+                Key::PageDown => Some("ページダウン"),
+                Key::Esc => Some("エスケープ"),
+                Key::Tab => Some("タブ"),
+                Key::Insert => Some("インサート"),
+                Key::Copy => Some("コピー"),
+                Key::Cut => Some("切り取り"),
+                Key::Paste => Some("貼り付け"),
+                Key::Undo => Some("元に戻す"),
+                Key::Redo => Some("やり直し"),
+                Key::Help => Some("ヘルプ"),
+                _ => None,
+            },
+            Self::German => match key {
+                Key::Char(' ') => Some("Leertaste"),
+                Key::Up => Some("Nach-oben"),
+                Key::Right => Some("Nach-rechts"),
+                Key::Down => Some("Nach-unten"),
+                Key::Left => Some("Nach-links"),
+                Key::Enter => Some("Eingabe"),
+                Key::Backspace => Some("Rücktaste"),
+                Key::Delete => Some("Entf"),
+                Key::Home => Some("Pos1"),
+                Key::End => Some("Ende"),
+                Key::PageUp => Some("Bild-auf"),
+                Key::PageDown => Some("Bild-ab"),
+                Key::Insert => Some("Einfg"),
+                Key::PrintScreen => Some("Druck"),
+                Key::ScrollLock => Some("Rollen"),
+                Key::Menu => Some("Menü"),
+                Key::Copy => Some("Kopieren"),
+                Key::Cut => Some("Ausschneiden"),
+                Key::Paste => Some("Einfügen"),
+                Key::Undo => Some("Rückgängig"),
+                Key::Redo => Some("Wiederholen"),
+                Key::Help => Some("Hilfe"),
+                _ => None,
+            },
+            Self::French => match key {
+                Key::Char(' ') => Some("Espace"),
+                Key::Up => Some("Haut"),
+                Key::Right => Some("Droite"),
+                Key::Down => Some("Bas"),
+                Key::Left => Some("Gauche"),
+                Key::Enter => Some("Entrée"),
+                Key::Backspace => Some("Retour arrière"),
+                Key::Delete => Some("Suppr"),
+                Key::Home => Some("Origine"),
+                Key::End => Some("Fin"),
+                Key::PageUp => Some("Pg préc"),
+                Key::PageDown => Some("Pg suiv"),
+                Key::Esc => Some("Échap"),
+                Key::Insert => Some("Inser"),
+                Key::PrintScreen => Some("Impr écran"),
+                Key::ScrollLock => Some("Arrêt défil"),
+                Key::NumLock => Some("Verr num"),
+                Key::Copy => Some("Copier"),
+                Key::Cut => Some("Couper"),
+                Key::Paste => Some("Coller"),
+                Key::Undo => Some("Annuler"),
+                Key::Redo => Some("Rétablir"),
+                Key::Help => Some("Aide"),
+                _ => None,
+            },
+        }
+    }
+
+    /// Return the localized name of the single modifier key. `None` is returned when the modifier has no localized
+    /// name.
+    ///
+    /// ```
+    /// use keybinds::{Locale, Mods};
+    ///
+    /// assert_eq!(Locale::French.mod_name(Mods::SHIFT), Some("Maj"));
+    /// assert_eq!(Locale::French.mod_name(Mods::ALT), None);
+    /// ```
+    pub fn mod_name(self, m: Mods) -> Option<&'static str> {
+        match self {
+            Self::English => None,
+            Self::Japanese => match m {
+                Mods::CMD => Some("コマンド"),
+                _ => None,
+            },
+            Self::German => match m {
+                Mods::CTRL => Some("Strg"),
+                Mods::CMD => Some("Befehl"),
+                Mods::SHIFT => Some("Umschalt"),
+                _ => None,
+            },
+            Self::French => match m {
+                Mods::CMD => Some("Commande"),
+                Mods::SHIFT => Some("Maj"),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn english_has_no_localized_name() {
+        for key in [Key::Char(' '), Key::Up, Key::Delete, Key::F1] {
+            assert_eq!(Locale::English.key_name(key), None, "key={key:?}");
+        }
+        for m in [Mods::CTRL, Mods::CMD, Mods::ALT, Mods::WIN, Mods::SHIFT] {
+            assert_eq!(Locale::English.mod_name(m), None, "mod={m:?}");
+        }
+        assert_eq!(Locale::default(), Locale::English);
+    }
+
+    #[test]
+    fn localized_names() {
+        for locale in [Locale::Japanese, Locale::German, Locale::French] {
+            for key in [Key::Char(' '), Key::Up, Key::Enter, Key::Delete] {
+                assert!(locale.key_name(key).is_some(), "{locale:?} {key:?}");
+            }
+            for key in [Key::Char('a'), Key::F1, Key::Unidentified] {
+                assert_eq!(locale.key_name(key), None, "{locale:?} {key:?}");
+            }
+        }
+        assert_eq!(Locale::German.key_name(Key::Delete), Some("Entf"));
+        assert_eq!(Locale::German.mod_name(Mods::CTRL), Some("Strg"));
+        assert_eq!(Locale::French.key_name(Key::Esc), Some("Échap"));
+        assert_eq!(Locale::French.mod_name(Mods::SHIFT), Some("Maj"));
+        assert_eq!(Locale::Japanese.key_name(Key::Left), Some("左"));
+    }
+}
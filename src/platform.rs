@@ -0,0 +1,89 @@
+/// Platform which key bindings are used on.
+///
+/// Some behaviors such as how key inputs are displayed depend on the platform. The platform is a runtime value so
+/// that, for example, an application running on Linux can render a cheat sheet for macOS users.
+///
+/// The virtual modifiers [`Mods::MOD`][crate::Mods::MOD] and [`Mods::SUPER`][crate::Mods::SUPER] are resolved with a
+/// platform (see [`Mods::resolve`][crate::Mods::resolve]). Functions which take no platform resolve them on
+/// [`Platform::current`]. When a function takes a platform, pass the one where the key inputs are typed rather than
+/// the host running the program. For example, when a terminal on macOS connects to the program on Linux via SSH,
+/// <kbd>Mod</kbd> should be resolved as <kbd>Cmd</kbd>.
+///
+/// ```
+/// use keybinds::Platform;
+///
+/// let platform = Platform::current();
+///
+/// #[cfg(target_os = "macos")]
+/// assert_eq!(platform, Platform::MacOS);
+/// #[cfg(target_os = "windows")]
+/// assert_eq!(platform, Platform::Windows);
+/// #[cfg(target_os = "linux")]
+/// assert_eq!(platform, Platform::Linux);
+/// ```
+///
+/// This enum is non-exhaustive because more platforms may be added in the future.
+#[non_exhaustive]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Platform {
+    /// macOS.
+    MacOS,
+    /// Windows.
+    Windows,
+    /// Linux.
+    Linux,
+    /// Other platforms such as FreeBSD.
+    Other,
+}
+
+impl Platform {
+    /// Return the platform which the current program was compiled for.
+    ///
+    /// ```
+    /// use keybinds::Platform;
+    ///
+    /// #[cfg(target_os = "linux")]
+    /// assert_eq!(Platform::current(), Platform::Linux);
+    /// ```
+    pub const fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOS
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "linux") {
+            Self::Linux
+        } else {
+            Self::Other
+        }
+    }
+}
+
+impl Default for Platform {
+    /// Return the current platform. This is equivalent to [`Platform::current`].
+    ///
+    /// ```
+    /// use keybinds::Platform;
+    ///
+    /// assert_eq!(Platform::default(), Platform::current());
+    /// ```
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_platform() {
+        let p = Platform::current();
+        #[cfg(target_os = "macos")]
+        assert_eq!(p, Platform::MacOS);
+        #[cfg(target_os = "windows")]
+        assert_eq!(p, Platform::Windows);
+        #[cfg(target_os = "linux")]
+        assert_eq!(p, Platform::Linux);
+        assert_eq!(Platform::default(), p);
+    }
+}
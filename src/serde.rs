@@ -1,7 +1,7 @@
 //! Support for [`serde`] crate.
 //!
-//! This module provides [`Deserialize`] and [`Serialize`] traits support for [`Keybinds`] and some other types
-//! following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
+//! This module provides [`Deserialize`] and [`Serialize`] traits support for [`Keybinds`] and some
+//! other types following the [syntax](https://github.com/rhysd/keybinds-rs/blob/main/doc/binding_syntax.md).
 //! This is useful for parsing key bindings from a configuration file.
 //!
 //! ```
@@ -37,10 +37,17 @@
 //!
 //! assert_eq!(&generated, configuration);
 //! ```
-use crate::{KeyInput, KeySeq, Keybind, Keybinds};
-use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+//!
+//! To normalize <kbd>Shift</kbd> modifier with character keys such as `Ctrl+Shift+p` in a configuration file, use
+//! [`WithLayout`] to deserialize the key bindings with a keyboard layout.
+use crate::{
+    GenericKeySeq, GenericKeybind, GenericKeybinds, Input, KeyContext, KeyInput, KeySeq, Keybinds,
+    Layout,
+};
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, Visitor};
 use serde::ser::{Error as _, Serialize, SerializeMap, Serializer};
 use std::fmt;
+use std::marker::PhantomData;
 
 impl<'de> Deserialize<'de> for KeyInput {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
@@ -62,49 +69,161 @@ impl<'de> Deserialize<'de> for KeyInput {
     }
 }
 
-impl<'de> Deserialize<'de> for KeySeq {
+struct KeySeqVisitor<I: Input> {
+    context: I::Context,
+}
+
+impl<I: Input> Visitor<'_> for KeySeqVisitor<I> {
+    type Value = GenericKeySeq<I>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("key sequence for a key bind")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        GenericKeySeq::parse_in(v, &self.context).map_err(E::custom)
+    }
+}
+
+fn deserialize_key_seq<'de, I: Input, D: Deserializer<'de>>(
+    deserializer: D,
+    context: I::Context,
+) -> Result<GenericKeySeq<I>, D::Error> {
+    deserializer.deserialize_str(KeySeqVisitor { context })
+}
+
+impl<'de, I: Input> Deserialize<'de> for GenericKeySeq<I> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        struct V;
+        deserialize_key_seq(deserializer, I::Context::default())
+    }
+}
 
-        impl Visitor<'_> for V {
-            type Value = KeySeq;
+struct KeybindsVisitor<A, I: Input> {
+    context: I::Context,
+    _marker: PhantomData<A>,
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("key sequence for a key bind")
-            }
+impl<'de, A: Deserialize<'de>, I: Input> Visitor<'de> for KeybindsVisitor<A, I> {
+    type Value = GenericKeybinds<A, I>;
 
-            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
-                v.parse().map_err(E::custom)
-            }
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("key bindings object as pairs of key sequences and actions")
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
+        let mut binds = vec![];
+        while let Some(seq) = access.next_key_seed(KeySeqSeed::<I>(&self.context))? {
+            let action = access.next_value()?;
+            binds.push(GenericKeybind { seq, action });
         }
+        let mut keybinds = GenericKeybinds::new(binds);
+        keybinds.set_context(self.context);
+        Ok(keybinds)
+    }
+}
 
-        deserializer.deserialize_str(V)
+struct KeySeqSeed<'a, I: Input>(&'a I::Context);
+
+impl<'de, I: Input> DeserializeSeed<'de> for KeySeqSeed<'_, I> {
+    type Value = GenericKeySeq<I>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserialize_key_seq(deserializer, self.0.clone())
     }
 }
 
-impl<'de, A: Deserialize<'de>> Deserialize<'de> for Keybinds<A> {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        use std::marker::PhantomData;
+fn deserialize_keybinds<'de, A: Deserialize<'de>, I: Input, D: Deserializer<'de>>(
+    deserializer: D,
+    context: I::Context,
+) -> Result<GenericKeybinds<A, I>, D::Error> {
+    deserializer.deserialize_str(KeybindsVisitor {
+        context,
+        _marker: PhantomData,
+    })
+}
 
-        struct V<A>(PhantomData<A>);
+impl<'de, A: Deserialize<'de>, I: Input> Deserialize<'de> for GenericKeybinds<A, I> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_keybinds(deserializer, I::Context::default())
+    }
+}
 
-        impl<'de, A: Deserialize<'de>> Visitor<'de> for V<A> {
-            type Value = Keybinds<A>;
+/// [`DeserializeSeed`] to deserialize [`Keybinds`] or [`KeySeq`] with a keyboard layout.
+///
+/// <kbd>Shift</kbd> modifier with a character key such as `Ctrl+Shift+p` is normalized into the shifted character
+/// on the layout while deserializing. The deserialized [`Keybinds`] also remembers the layout for the
+/// following [`Keybinds::bind`][crate::Keybinds::bind] calls. See [`Layout`] for more details.
+///
+/// ```
+/// use serde::de::DeserializeSeed;
+/// use keybinds::serde::WithLayout;
+/// use keybinds::{Keybinds, KeyInput, Layout, Mods};
+///
+/// #[derive(serde::Deserialize, PartialEq, Debug)]
+/// enum Action {
+///     OpenPalette,
+/// }
+///
+/// let configuration = r#""Ctrl+Shift+p" = "OpenPalette""#;
+///
+/// let deserializer = toml::Deserializer::new(configuration);
+/// let seed = WithLayout::<Keybinds<Action>>::new(Layout::Us);
+/// let mut keybinds = seed.deserialize(deserializer).unwrap();
+///
+/// assert_eq!(keybinds.layout(), Some(Layout::Us));
+/// assert_eq!(keybinds.dispatch(KeyInput::new('P', Mods::CTRL)), Some(&Action::OpenPalette));
+/// ```
+pub struct WithLayout<T> {
+    layout: Layout,
+    _marker: PhantomData<fn() -> T>,
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("key bindings object as pairs of key sequences and actions")
-            }
+impl<T> WithLayout<T> {
+    /// Create a new [`DeserializeSeed`] with the keyboard layout.
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            layout,
+            _marker: PhantomData,
+        }
+    }
 
-            fn visit_map<M: MapAccess<'de>>(self, mut access: M) -> Result<Self::Value, M::Error> {
-                let mut binds = vec![];
-                while let Some((seq, action)) = access.next_entry::<KeySeq, A>()? {
-                    binds.push(Keybind::new(seq, action));
-                }
-                Ok(Keybinds::new(binds))
-            }
+    fn context(&self) -> KeyContext {
+        KeyContext {
+            layout: Some(self.layout),
+            ..KeyContext::default()
         }
+    }
+}
+
+impl<T> Clone for WithLayout<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WithLayout<T> {}
 
-        deserializer.deserialize_str(V(PhantomData::<A>))
+impl<T> fmt::Debug for WithLayout<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithLayout")
+            .field("layout", &self.layout)
+            .finish()
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for WithLayout<KeySeq> {
+    type Value = KeySeq;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserialize_key_seq(deserializer, self.context())
+    }
+}
+
+impl<'de, A: Deserialize<'de>> DeserializeSeed<'de> for WithLayout<Keybinds<A>> {
+    type Value = Keybinds<A>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserialize_keybinds(deserializer, self.context())
     }
 }
 
@@ -114,7 +233,7 @@ impl Serialize for KeyInput {
     }
 }
 
-impl Serialize for KeySeq {
+impl<I: Input> Serialize for GenericKeySeq<I> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         if self.as_slice().is_empty() {
             return Err(S::Error::custom("Key sequence must not be empty"));
@@ -123,7 +242,7 @@ impl Serialize for KeySeq {
     }
 }
 
-impl<A: Serialize> Serialize for Keybinds<A> {
+impl<A: Serialize, I: Input> Serialize for GenericKeybinds<A, I> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let mut map = serializer.serialize_map(Some(self.as_slice().len()))?;
         for keybind in self.as_slice().iter() {
@@ -136,7 +255,7 @@ impl<A: Serialize> Serialize for Keybinds<A> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Key, KeyInput, Mods};
+    use crate::{Key, KeyInput, KeySeq, Keybind, Keybinds, Layout, Mods};
     use serde::{Deserialize, Serialize};
 
     #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
@@ -217,6 +336,49 @@ mod tests {
         assert_eq!(actual.as_slice(), &expected);
     }
 
+    #[test]
+    fn deserialize_with_layout() {
+        let input = r#"
+        "Ctrl+Shift+p" = "Action1"
+        "Shift+1 Shift+Up" = "Action2"
+        "Ctrl+P" = "Action3"
+        "#;
+
+        let mut keybinds = WithLayout::<Keybinds<A>>::new(Layout::Us)
+            .deserialize(toml::Deserializer::new(input))
+            .unwrap();
+        let expected = [
+            Keybind::new(KeyInput::new('P', Mods::CTRL), A::Action1),
+            Keybind::new(
+                [KeyInput::from('!'), KeyInput::new(Key::Up, Mods::SHIFT)],
+                A::Action2,
+            ),
+            Keybind::new(KeyInput::new('P', Mods::CTRL), A::Action3),
+        ];
+        assert_eq!(keybinds.as_slice(), &expected);
+        assert_eq!(keybinds.layout(), Some(Layout::Us));
+
+        keybinds.bind("Shift+2", A::Action4).unwrap();
+        assert_eq!(keybinds.dispatch('@'), Some(&A::Action4));
+
+        let keybinds = WithLayout::<Keybinds<A>>::new(Layout::German)
+            .deserialize(toml::Deserializer::new(r#""Ctrl+Shift+7" = "Action1""#))
+            .unwrap();
+        let expected = [Keybind::new(KeyInput::new('/', Mods::CTRL), A::Action1)];
+        assert_eq!(keybinds.as_slice(), &expected);
+
+        let seq = WithLayout::<KeySeq>::new(Layout::Uk)
+            .deserialize(toml::Value::String("Shift+3 Shift+a".into()))
+            .unwrap();
+        assert_eq!(seq, KeySeq::from(['£', 'A']));
+
+        for input in [r#""Shift+€" = "Action1""#, r#""Foo" = "Action1""#] {
+            let result = WithLayout::<Keybinds<A>>::new(Layout::Us)
+                .deserialize(toml::Deserializer::new(input));
+            assert!(result.is_err(), "input={input:?}");
+        }
+    }
+
     #[test]
     fn serialize_ok() {
         let binds = vec![
@@ -245,6 +407,13 @@ Up = "Action2"
 "#;
 
         assert_eq!(&actual, expected);
+
+        // The normalization for terminals does not modify the key bindings
+        let mut bindings = Keybinds::default();
+        bindings.bind("Ctrl+i", A::Action1).unwrap();
+        bindings.set_terminal_normalization(true);
+        let actual = toml::to_string(&Config { bindings }).unwrap();
+        assert_eq!(actual, "[bindings]\n\"Ctrl+i\" = \"Action1\"\n");
     }
 
     #[test]
@@ -345,6 +514,8 @@ Up = "Action2"
             (TestInput::new(Key::Enter, Mods::NONE), r#"key = "Enter""#),
             (TestInput::new('a', Mods::CTRL), r#"key = "Ctrl+a""#),
             (TestInput::new(Key::Tab, Mods::ALT), r#"key = "Alt+Tab""#),
+            (TestInput::new('a', Mods::MOD), r#"key = "Mod+a""#),
+            (TestInput::new('a', Mods::SUPER), r#"key = "Super+a""#),
         ] {
             assert_eq!(
                 toml::to_string(&input).unwrap().trim(),
@@ -353,4 +524,43 @@ Up = "Action2"
             );
         }
     }
+
+    #[test]
+    fn custom_input_keybinds() {
+        use crate::Error;
+
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        struct Button(u8);
+
+        impl fmt::Display for Button {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "B{}", self.0)
+            }
+        }
+
+        impl Input for Button {
+            type Context = ();
+
+            fn parse(s: &str) -> Result<Self, Error> {
+                match s.strip_prefix('B').and_then(|n| n.parse().ok()) {
+                    Some(n) => Ok(Self(n)),
+                    None => Err(Error::UnknownKey(s.into())),
+                }
+            }
+        }
+
+        #[derive(Deserialize, Serialize)]
+        struct Config {
+            bindings: GenericKeybinds<A, Button>,
+        }
+
+        let input = "[bindings]\n\"B1 B2\" = \"Action1\"\nB3 = \"Action2\"\n";
+        let mut config: Config = toml::from_str(input).unwrap();
+        assert_eq!(config.bindings.dispatch(Button(1)), None);
+        assert_eq!(config.bindings.dispatch(Button(2)), Some(&A::Action1));
+        assert_eq!(config.bindings.dispatch(Button(3)), Some(&A::Action2));
+        assert_eq!(toml::to_string(&config).unwrap(), input);
+
+        assert!(toml::from_str::<Config>("[bindings]\nFoo = \"Action1\"\n").is_err());
+    }
 }
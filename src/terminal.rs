@@ -0,0 +1,1594 @@
+use crate::kitty::{self, KittyFlags};
+use crate::{Error, Key, KeyInput, KeySeq, Mods, Platform, Result};
+use std::time::{Duration, Instant};
+
+const ESC: u8 = 0x1b;
+const PASTE_START: &[u8] = b"\x1b[200~";
+const PASTE_END: &[u8] = b"\x1b[201~";
+// Pasted text longer than this is split into multiple `TerminalEvent::Paste` events
+const MAX_PASTE_LEN: usize = 1024 * 1024;
+
+/// The default timeout to wait for the rest of an escape sequence after <kbd>Esc</kbd> key input is received by
+/// [`TerminalDecoder`].
+///
+/// The value is 50 milliseconds. To change the timeout, see [`TerminalDecoder::set_timeout`].
+pub const DEFAULT_ESC_TIMEOUT: Duration = Duration::from_millis(50);
+
+/// Event decoded from the raw terminal input by [`TerminalDecoder`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum TerminalEvent {
+    /// Key input. Key repeats reported by the kitty keyboard protocol are also key inputs.
+    Key(KeyInput),
+    /// Key release reported by the kitty keyboard protocol when [`KittyFlags::REPORT_EVENT_TYPES`] is enabled.
+    KeyRelease(KeyInput),
+    /// Text pasted while the bracketed paste mode is enabled. The text between the start marker `ESC [ 200 ~` and the
+    /// end marker `ESC [ 201 ~` is not decoded as key inputs. Text longer than 1 MiB is split into multiple events
+    /// so that a missing end marker does not make [`TerminalDecoder`] buffer the input without bound.
+    Paste(String),
+    /// Current flags of the kitty keyboard protocol replied by the terminal for the query sequence
+    /// [`KittyFlags::QUERY_SEQUENCE`].
+    KittyFlags(KittyFlags),
+}
+
+impl From<TerminalEvent> for KeyInput {
+    /// Convert the terminal event into [`KeyInput`]. Events other than key inputs such as pasted text or key releases
+    /// are converted into `Key::Ignored`.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Mods, TerminalEvent};
+    ///
+    /// let input = KeyInput::new('a', Mods::CTRL);
+    /// assert_eq!(KeyInput::from(TerminalEvent::Key(input)), input);
+    /// assert_eq!(KeyInput::from(TerminalEvent::Paste("foo".into())), Key::Ignored.into());
+    /// ```
+    fn from(event: TerminalEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
+impl From<&TerminalEvent> for KeyInput {
+    fn from(event: &TerminalEvent) -> Self {
+        match event {
+            TerminalEvent::Key(input) => *input,
+            _ => Key::Ignored.into(),
+        }
+    }
+}
+
+impl TerminalEvent {
+    /// Encode the event into the legacy byte sequence sent by xterm-compatible terminals. This is useful to forward
+    /// unbound key inputs to the child process of terminal multiplexers. The encoded bytes can be decoded by
+    /// [`TerminalDecoder`].
+    ///
+    /// - Character keys are encoded as UTF-8 text and <kbd>Ctrl</kbd> modifier is encoded as control characters such as
+    ///   `0x03` for <kbd>Ctrl</kbd> + <kbd>c</kbd>
+    /// - <kbd>Alt</kbd> modifier is encoded as the `ESC` prefix such as `ESC x` for <kbd>Alt</kbd> + <kbd>x</kbd>
+    /// - Cursor keys, editing keys and function keys are encoded as CSI or SS3 sequences with modifiers such as
+    ///   `ESC [ 1 ; 5 A` for <kbd>Ctrl</kbd> + <kbd>Up</kbd>
+    /// - Key releases are encoded into empty bytes since terminals don't report them
+    /// - Pasted text is encoded with the bracketed paste markers. `ESC` bytes in the text are removed so that the text
+    ///   cannot end the paste early
+    ///
+    /// The legacy encoding cannot represent many key inputs such as <kbd>Ctrl</kbd> + <kbd>1</kbd>,
+    /// <kbd>Shift</kbd> + <kbd>Enter</kbd>, <kbd>Shift</kbd> with lower case letters (see [`KeyInput::with_code`]),
+    /// super modifier with character keys, or mouse buttons. They cause
+    /// [`Error::UnencodableInput`]. Note that some key inputs are encoded into the same bytes as other key inputs. For
+    /// example, <kbd>Ctrl</kbd> + <kbd>i</kbd> is encoded into `0x09` which is the same as <kbd>Tab</kbd>. Consider
+    /// the [kitty keyboard protocol](TerminalEvent::encode_kitty) to represent them unambiguously.
+    ///
+    /// ```
+    /// use keybinds::{Error, Key, KeyInput, Mods, TerminalEvent};
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new('c', Mods::CTRL));
+    /// assert_eq!(event.encode_legacy().unwrap(), b"\x03");
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new('x', Mods::ALT));
+    /// assert_eq!(event.encode_legacy().unwrap(), b"\x1bx");
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new(Key::Up, Mods::CTRL));
+    /// assert_eq!(event.encode_legacy().unwrap(), b"\x1b[1;5A");
+    ///
+    /// let input = KeyInput::new('1', Mods::CTRL);
+    /// let event = TerminalEvent::Key(input);
+    /// assert_eq!(event.encode_legacy(), Err(Error::UnencodableInput(input)));
+    /// ```
+    ///
+    /// The virtual modifiers [`Mods::MOD`] and [`Mods::SUPER`] are resolved on the platform this program is compiled
+    /// for. Use [`TerminalEvent::encode_legacy_with_platform`] to resolve them on another platform.
+    pub fn encode_legacy(&self) -> Result<Vec<u8>> {
+        self.encode_legacy_with_platform(Platform::current())
+    }
+
+    /// Same as [`TerminalEvent::encode_legacy`] but on the given platform. See [`Platform`] for which to pass.
+    ///
+    /// ```
+    /// use keybinds::{Error, KeyInput, Mods, Platform, TerminalEvent};
+    ///
+    /// let event = TerminalEvent::Key(KeyInput::new('c', Mods::MOD));
+    /// assert_eq!(event.encode_legacy_with_platform(Platform::Linux).unwrap(), b"\x03");
+    ///
+    /// // Super modifier cannot be represented by the legacy encoding
+    /// let input = KeyInput::new('c', Mods::MOD);
+    /// assert_eq!(
+    ///     event.encode_legacy_with_platform(Platform::MacOS),
+    ///     Err(Error::UnencodableInput(input)),
+    /// );
+    /// ```
+    pub fn encode_legacy_with_platform(&self, platform: Platform) -> Result<Vec<u8>> {
+        let input = match self {
+            Self::Key(input) => input,
+            Self::KeyRelease(_) => return Ok(vec![]),
+            Self::Paste(text) => return Ok(encode_paste(text)),
+            Self::KittyFlags(flags) => return Ok(format!("\x1b[?{}u", flags.bits()).into_bytes()),
+        };
+        let unencodable = || Error::UnencodableInput(*input);
+        let key = input.key();
+        let mut mods = resolve_mods(input.physical_mods(), platform);
+        mods.remove(Mods::STATES | Mods::ALTGR); // They are not sent by terminals
+
+        // CSI sequences can represent all modifiers
+        if let Some(b) = final_byte(key) {
+            let b = b as char;
+            let seq = match mods_param(mods) {
+                1 if matches!(key, Key::F1 | Key::F2 | Key::F3 | Key::F4) => format!("\x1bO{b}"),
+                1 => format!("\x1b[{b}"),
+                param => format!("\x1b[1;{param}{b}"),
+            };
+            return Ok(seq.into_bytes());
+        }
+        if let Some(n) = function_key_number(key) {
+            let seq = match mods_param(mods) {
+                1 => format!("\x1b[{n}~"),
+                param => format!("\x1b[{n};{param}~"),
+            };
+            return Ok(seq.into_bytes());
+        }
+
+        // Other keys can only represent Ctrl and Alt modifiers. Shift modifier must be a part of the character. Shift
+        // with a lower case letter such as `Shift+[KeyA]` typing `a` cannot be represented.
+        let ctrl = mods.intersects(Mods::LCTRL | Mods::RCTRL);
+        let alt = mods.intersects(Mods::LALT | Mods::RALT);
+        let shift = mods.intersects(Mods::ANY_SHIFT);
+        mods.remove(Mods::LCTRL | Mods::RCTRL | Mods::LALT | Mods::RALT | Mods::ANY_SHIFT);
+        if !mods.is_empty() {
+            return Err(unencodable());
+        }
+
+        let mut bytes = if alt { vec![ESC] } else { vec![] };
+        match key {
+            Key::Char(c) if shift && c.is_lowercase() => return Err(unencodable()),
+            Key::Str(s) if shift && s.as_str().chars().any(char::is_lowercase) => {
+                return Err(unencodable());
+            }
+            Key::Char(c) if ctrl => bytes.push(control_char(c).ok_or_else(unencodable)?),
+            Key::Char(c) => bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            Key::Str(s) if !ctrl => bytes.extend_from_slice(s.as_str().as_bytes()),
+            Key::Tab if shift && !ctrl => bytes.extend_from_slice(b"\x1b[Z"),
+            _ if shift || ctrl => return Err(unencodable()),
+            Key::Enter => bytes.push(b'\r'),
+            Key::Tab => bytes.push(b'\t'),
+            Key::Backspace => bytes.push(0x7f),
+            Key::Esc => bytes.push(ESC),
+            _ => return Err(unencodable()),
+        }
+        Ok(bytes)
+    }
+}
+
+impl KeyInput {
+    /// Normalize the key input into the form reported by legacy terminals. Legacy terminals send <kbd>Ctrl</kbd> +
+    /// character keys as control characters and some of them are the same bytes as other keys. For example,
+    /// <kbd>Ctrl</kbd> + <kbd>i</kbd> arrives as <kbd>Tab</kbd> and <kbd>Ctrl</kbd> + <kbd>h</kbd> arrives as
+    /// <kbd>Backspace</kbd> on some terminals.
+    ///
+    /// - <kbd>Ctrl</kbd> + <kbd>i</kbd> is normalized into <kbd>Tab</kbd>
+    /// - <kbd>Ctrl</kbd> + <kbd>m</kbd> is normalized into <kbd>Enter</kbd>
+    /// - <kbd>Ctrl</kbd> + <kbd>[</kbd> and <kbd>Ctrl</kbd> + <kbd>3</kbd> are normalized into <kbd>Esc</kbd>
+    /// - <kbd>Ctrl</kbd> + <kbd>h</kbd>, <kbd>Ctrl</kbd> + <kbd>?</kbd> and <kbd>Ctrl</kbd> + <kbd>8</kbd> are
+    ///   normalized into <kbd>Backspace</kbd>
+    /// - <kbd>Ctrl</kbd> + <kbd>@</kbd> and <kbd>Ctrl</kbd> + <kbd>2</kbd> are normalized into <kbd>Ctrl</kbd> +
+    ///   <kbd>Space</kbd>, and <kbd>Ctrl</kbd> + <kbd>\\</kbd>, <kbd>]</kbd>, <kbd>^</kbd>, <kbd>_</kbd>, <kbd>/</kbd> are
+    ///   normalized into <kbd>Ctrl</kbd> + <kbd>4</kbd> to <kbd>7</kbd>
+    /// - <kbd>Ctrl</kbd> + upper case letters are normalized into lower case letters
+    ///
+    /// Other modifiers such as <kbd>Alt</kbd> are kept. The virtual modifier [`Mods::MOD`] is not resolved so specify
+    /// <kbd>Ctrl</kbd> explicitly for terminal applications.
+    ///
+    /// This normalization is opt-in. Apply it to both the key inputs from terminals and the key bindings (see
+    /// [`Keybinds::set_terminal_normalization`][crate::Keybinds::set_terminal_normalization]) so that they compare equal.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Mods};
+    ///
+    /// let ctrl_i = KeyInput::new('i', Mods::CTRL);
+    /// assert_eq!(ctrl_i.normalize_for_terminal(), KeyInput::new(Key::Tab, Mods::NONE));
+    ///
+    /// let ctrl_alt_h = KeyInput::new('h', Mods::CTRL | Mods::ALT);
+    /// assert_eq!(ctrl_alt_h.normalize_for_terminal(), KeyInput::new(Key::Backspace, Mods::ALT));
+    ///
+    /// // Key inputs which are not ambiguous are not changed
+    /// let ctrl_x = KeyInput::new('x', Mods::CTRL);
+    /// assert_eq!(ctrl_x.normalize_for_terminal(), ctrl_x);
+    /// ```
+    pub fn normalize_for_terminal(self) -> Self {
+        let Key::Char(c) = self.key() else {
+            return self;
+        };
+        let mods = self.physical_mods();
+        let ctrl = Mods::LCTRL | Mods::RCTRL;
+        if !mods.intersects(ctrl) {
+            return self;
+        }
+        let Some(b) = control_char(c) else {
+            return self;
+        };
+        let (key, mods) = match b {
+            b'\t' => (Key::Tab, mods.difference(ctrl)),
+            b'\r' => (Key::Enter, mods.difference(ctrl)),
+            ESC => (Key::Esc, mods.difference(ctrl)),
+            0x08 | 0x7f => (Key::Backspace, mods.difference(ctrl)),
+            b => match decode_plain(&[b]) {
+                Some((input, _)) => (input.key(), mods),
+                None => return self,
+            },
+        };
+        match self.code() {
+            Some(code) => Self::with_code(key, mods, code),
+            None => Self::new(key, mods),
+        }
+    }
+}
+
+impl KeySeq {
+    /// Normalize all key inputs in the key sequence into the form reported by legacy terminals. See
+    /// [`KeyInput::normalize_for_terminal`] for the details.
+    ///
+    /// ```
+    /// use keybinds::KeySeq;
+    ///
+    /// let seq: KeySeq = "Ctrl+x Ctrl+i".parse().unwrap();
+    /// let expected: KeySeq = "Ctrl+x Tab".parse().unwrap();
+    /// assert_eq!(seq.normalize_for_terminal(), expected);
+    /// ```
+    pub fn normalize_for_terminal(&self) -> Self {
+        self.as_slice()
+            .iter()
+            .map(|input| input.normalize_for_terminal())
+            .collect()
+    }
+}
+
+enum Decoded {
+    Event(TerminalEvent, usize),
+    Incomplete,
+}
+
+/// Decoder to convert the raw bytes read from a terminal into [`KeyInput`]s without depending on any TUI library.
+///
+/// The decoder understands the VT/xterm escape sequences sent by terminals in raw mode:
+///
+/// - Control characters such as `0x03` for <kbd>Ctrl</kbd> + <kbd>c</kbd> and `0x7f` for <kbd>Backspace</kbd>
+/// - UTF-8 text such as `é` or `あ`
+/// - CSI and SS3 sequences for the cursor keys, the editing keys and the function keys such as `ESC [ 1 ; 5 A` for
+///   <kbd>Ctrl</kbd> + <kbd>Up</kbd>
+/// - xterm's modifyOtherKeys sequences such as `ESC [ 27 ; 5 ; 105 ~` for <kbd>Ctrl</kbd> + <kbd>i</kbd>, in both
+///   `formatOtherKeys` forms
+/// - <kbd>Alt</kbd> modifier sent as the `ESC` prefix such as `ESC x` for <kbd>Alt</kbd> + <kbd>x</kbd>
+/// - Bracketed paste markers `ESC [ 200 ~` and `ESC [ 201 ~`
+/// - [Kitty keyboard protocol](https://sw.kovidgoyal.net/kitty/keyboard-protocol/) such as `ESC [ 105 ; 5 u` for
+///   <kbd>Ctrl</kbd> + <kbd>i</kbd> and `ESC [ 97 ; 1 : 3 u` for releasing <kbd>a</kbd>. See [`KittyFlags`] to enable
+///   the protocol
+///
+/// Bytes can be fed incrementally with [`TerminalDecoder::feed`] as they are read. An incomplete sequence is kept in
+/// the decoder until the rest of the bytes are fed. Since the `ESC` byte is both the <kbd>Esc</kbd> key and the start
+/// of escape sequences, a lone `ESC` is decoded as the <kbd>Esc</kbd> key only after the timeout passes without any
+/// following bytes. The caller should wait for the next input at most [`TerminalDecoder::pending_timeout`] and call
+/// [`TerminalDecoder::next_event`] again after that.
+///
+/// Unknown escape sequences are decoded as `Key::Unidentified`.
+///
+/// ```
+/// use keybinds::{Key, KeyInput, Mods, TerminalDecoder, TerminalEvent};
+///
+/// let mut decoder = TerminalDecoder::new();
+///
+/// // Ctrl+x followed by Ctrl+Up
+/// decoder.feed(b"\x18\x1b[1;5");
+/// assert_eq!(
+///     decoder.next_event(),
+///     Some(TerminalEvent::Key(KeyInput::new('x', Mods::CTRL))),
+/// );
+/// // The sequence for Ctrl+Up is not complete yet
+/// assert_eq!(decoder.next_event(), None);
+///
+/// decoder.feed(b"A");
+/// assert_eq!(
+///     decoder.next_event(),
+///     Some(TerminalEvent::Key(KeyInput::new(Key::Up, Mods::CTRL))),
+/// );
+/// assert_eq!(decoder.next_event(), None);
+/// ```
+///
+/// With [`Keybinds`](crate::Keybinds), the decoded events can be dispatched directly.
+///
+/// ```no_run
+/// use keybinds::{Keybinds, TerminalDecoder};
+/// use std::io::{stdin, Read};
+///
+/// #[derive(PartialEq, Eq, Debug)]
+/// enum Action {
+///     SayHi,
+///     Exit,
+/// }
+///
+/// let mut keybinds = Keybinds::default();
+/// keybinds.bind("h i", Action::SayHi).unwrap();
+/// keybinds.bind("Ctrl+x Ctrl+c", Action::Exit).unwrap();
+///
+/// // Terminal must be in raw mode here
+/// let mut decoder = TerminalDecoder::new();
+/// let mut buf = [0; 1024];
+/// loop {
+///     let size = stdin().read(&mut buf).unwrap();
+///     decoder.feed(&buf[..size]);
+///
+///     // A lone Esc is pending until the timeout. A real application should wait for the next read at most
+///     // `decoder.pending_timeout()` using `poll(2)` or similar.
+///     while let Some(event) = decoder.next_event() {
+///         match keybinds.dispatch(event) {
+///             Some(Action::SayHi) => println!("Hi!\r"),
+///             Some(Action::Exit) => return,
+///             None => {}
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TerminalDecoder {
+    buf: Vec<u8>,
+    pending: Option<Instant>,
+    timeout: Duration,
+    scanned: usize,
+}
+
+impl Default for TerminalDecoder {
+    fn default() -> Self {
+        Self {
+            buf: vec![],
+            pending: None,
+            timeout: DEFAULT_ESC_TIMEOUT,
+            scanned: 0,
+        }
+    }
+}
+
+impl TerminalDecoder {
+    /// Create a new decoder with the default timeout [`DEFAULT_ESC_TIMEOUT`].
+    ///
+    /// ```
+    /// use keybinds::{TerminalDecoder, DEFAULT_ESC_TIMEOUT};
+    ///
+    /// let decoder = TerminalDecoder::new();
+    /// assert_eq!(decoder.timeout(), DEFAULT_ESC_TIMEOUT);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the bytes read from the terminal to the decoder. The decoded events can be taken by
+    /// [`TerminalDecoder::next_event`].
+    ///
+    /// ```
+    /// use keybinds::{KeyInput, Mods, TerminalDecoder, TerminalEvent};
+    ///
+    /// let mut decoder = TerminalDecoder::new();
+    ///
+    /// // UTF-8 sequence can be split
+    /// decoder.feed(&[0xc3]);
+    /// assert_eq!(decoder.next_event(), None);
+    /// decoder.feed(&[0xa9]);
+    /// assert_eq!(
+    ///     decoder.next_event(),
+    ///     Some(TerminalEvent::Key(KeyInput::new('é', Mods::NONE))),
+    /// );
+    /// ```
+    pub fn feed(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        self.buf.extend_from_slice(bytes);
+        // The pending sequence is still arriving. Restart the timeout so that a sequence split into slow chunks (e.g.
+        // over SSH) is not resolved partway through.
+        if self.pending.is_some() {
+            self.pending = Some(Instant::now());
+        }
+    }
+
+    /// Decode the next event from the fed bytes. `None` is returned when no complete event is available.
+    ///
+    /// When the remaining bytes start with `ESC` and they are ambiguous, the decoder waits for the next bytes until
+    /// the timeout passes. After that, a lone `ESC` is decoded as the <kbd>Esc</kbd> key and `ESC` followed by other
+    /// bytes is decoded as the <kbd>Alt</kbd> modifier.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Mods, TerminalDecoder, TerminalEvent};
+    /// use std::time::Duration;
+    /// use std::thread::sleep;
+    ///
+    /// let mut decoder = TerminalDecoder::new();
+    /// decoder.set_timeout(Duration::from_millis(10));
+    ///
+    /// decoder.feed(b"\x1b");
+    /// // `ESC` may be the start of an escape sequence
+    /// assert_eq!(decoder.next_event(), None);
+    ///
+    /// sleep(Duration::from_millis(50));
+    ///
+    /// // No sequence followed. It is the Esc key
+    /// assert_eq!(
+    ///     decoder.next_event(),
+    ///     Some(TerminalEvent::Key(KeyInput::new(Key::Esc, Mods::NONE))),
+    /// );
+    /// ```
+    pub fn next_event(&mut self) -> Option<TerminalEvent> {
+        if self.buf.is_empty() {
+            self.pending = None;
+            return None;
+        }
+        if self.buf.starts_with(PASTE_START) {
+            return self.next_paste(); // Paste never times out
+        }
+
+        let (event, len) = match decode(&self.buf) {
+            Decoded::Event(event, len) => (event, len),
+            Decoded::Incomplete => {
+                let now = Instant::now();
+                let since = *self.pending.get_or_insert(now);
+                if now.duration_since(since) < self.timeout {
+                    return None;
+                }
+                resolve_incomplete(&self.buf)
+            }
+        };
+
+        self.buf.drain(..len);
+        self.pending = None;
+        Some(event)
+    }
+
+    // `ESC [ 200 ~ {text} ESC [ 201 ~`. The bytes already searched for the end marker are remembered in `scanned` not
+    // to rescan the whole text every time the next bytes are fed.
+    fn next_paste(&mut self) -> Option<TerminalEvent> {
+        let start = PASTE_START.len();
+        let from = self.scanned.max(start);
+        if let Some(pos) = self.buf[from..]
+            .windows(PASTE_END.len())
+            .position(|w| w == PASTE_END)
+        {
+            let end = from + pos;
+            let pasted = String::from_utf8_lossy(&self.buf[start..end]).into_owned();
+            self.buf.drain(..end + PASTE_END.len());
+            self.scanned = 0;
+            self.pending = None;
+            return Some(TerminalEvent::Paste(pasted));
+        }
+
+        // The last bytes may be the start of the end marker
+        let mut end = self
+            .buf
+            .len()
+            .saturating_sub(PASTE_END.len() - 1)
+            .max(start);
+        if end - start < MAX_PASTE_LEN {
+            self.scanned = end;
+            return None;
+        }
+
+        // Flush the text received so far. The start marker is kept since the rest of the bytes are still pasted text.
+        // Do not split a UTF-8 sequence, which is at most 4 bytes. When no lead byte is found, the bytes are invalid
+        // UTF-8 and they are replaced by `from_utf8_lossy` anyway.
+        if let Some(lead) = (end.saturating_sub(3).max(start + 1)..=end)
+            .rev()
+            .find(|&i| self.buf[i] & 0xc0 != 0x80)
+        {
+            end = lead;
+        }
+        let pasted = String::from_utf8_lossy(&self.buf[start..end]).into_owned();
+        self.buf.drain(start..end);
+        self.scanned = start;
+        Some(TerminalEvent::Paste(pasted))
+    }
+
+    /// Return how long the caller should wait for the next bytes before calling [`TerminalDecoder::next_event`] again
+    /// to resolve the ambiguous pending bytes such as a lone `ESC`. `None` is returned when no bytes are pending.
+    ///
+    /// ```
+    /// use keybinds::TerminalDecoder;
+    ///
+    /// let mut decoder = TerminalDecoder::new();
+    /// assert_eq!(decoder.pending_timeout(), None);
+    ///
+    /// decoder.feed(b"\x1b");
+    /// assert_eq!(decoder.next_event(), None);
+    /// assert!(decoder.pending_timeout().is_some());
+    /// ```
+    pub fn pending_timeout(&self) -> Option<Duration> {
+        let since = self.pending?;
+        Some(self.timeout.saturating_sub(since.elapsed()))
+    }
+
+    /// Set the timeout to wait for the rest of an escape sequence after `ESC` is received. For the default timeout
+    /// value, see [`DEFAULT_ESC_TIMEOUT`].
+    ///
+    /// A longer timeout is more robust against slow connections such as SSH, but the <kbd>Esc</kbd> key is reported
+    /// with more delay.
+    ///
+    /// ```
+    /// use keybinds::TerminalDecoder;
+    /// use std::time::Duration;
+    ///
+    /// let mut decoder = TerminalDecoder::new();
+    /// decoder.set_timeout(Duration::from_millis(100));
+    /// assert_eq!(decoder.timeout(), Duration::from_millis(100));
+    /// ```
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Get the timeout to wait for the rest of an escape sequence. See [`TerminalDecoder::set_timeout`] to know the
+    /// details.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Return true when some bytes are fed but not decoded yet.
+    ///
+    /// ```
+    /// use keybinds::TerminalDecoder;
+    ///
+    /// let mut decoder = TerminalDecoder::new();
+    /// decoder.feed(b"\x1b[");
+    /// assert_eq!(decoder.next_event(), None);
+    /// assert!(decoder.has_pending());
+    /// ```
+    pub fn has_pending(&self) -> bool {
+        !self.buf.is_empty()
+    }
+}
+
+fn key_event(key: impl Into<Key>, mods: Mods) -> TerminalEvent {
+    TerminalEvent::Key(KeyInput::new(key, mods))
+}
+
+// Resolve the ambiguous bytes after the timeout. `ESC` is treated as the Alt modifier prefix when some bytes follow it.
+fn resolve_incomplete(buf: &[u8]) -> (TerminalEvent, usize) {
+    if buf[0] != ESC {
+        return (key_event(Key::Unidentified, Mods::NONE), 1); // Broken UTF-8 sequence
+    }
+    match decode_plain(&buf[1..]) {
+        Some((input, len)) => (key_event(input.key(), input.mods() | Mods::ALT), len + 1),
+        None => (key_event(Key::Esc, Mods::NONE), 1),
+    }
+}
+
+fn decode(buf: &[u8]) -> Decoded {
+    if buf[0] != ESC {
+        return match decode_plain(buf) {
+            Some((input, len)) => Decoded::Event(TerminalEvent::Key(input), len),
+            None => Decoded::Incomplete,
+        };
+    }
+
+    match buf.get(1) {
+        None => Decoded::Incomplete,
+        Some(b'[') => decode_csi(buf),
+        Some(b'O') => decode_ss3(buf),
+        Some(&ESC) if buf.len() == 2 => Decoded::Incomplete,
+        // Alt modifier with a CSI or SS3 sequence like `ESC ESC [ A` for Alt+Up
+        // The prefix is consumed with any event so that it is not decoded as a separate Esc key.
+        Some(&ESC) if matches!(buf.get(2), Some(b'[' | b'O')) => match decode(&buf[1..]) {
+            Decoded::Event(event, len) => {
+                let alt = |input: KeyInput| KeyInput::new(input.key(), input.mods() | Mods::ALT);
+                let event = match event {
+                    TerminalEvent::Key(input) => TerminalEvent::Key(alt(input)),
+                    TerminalEvent::KeyRelease(input) => TerminalEvent::KeyRelease(alt(input)),
+                    event => event,
+                };
+                Decoded::Event(event, len + 1)
+            }
+            Decoded::Incomplete => Decoded::Incomplete,
+        },
+        Some(_) => match decode_plain(&buf[1..]) {
+            Some((input, len)) => {
+                Decoded::Event(key_event(input.key(), input.mods() | Mods::ALT), len + 1)
+            }
+            None => Decoded::Incomplete,
+        },
+    }
+}
+
+// Decode a control character or a UTF-8 character. `None` means no byte or an incomplete UTF-8 sequence.
+fn decode_plain(buf: &[u8]) -> Option<(KeyInput, usize)> {
+    let input = match *buf.first()? {
+        b'\r' => KeyInput::new(Key::Enter, Mods::NONE),
+        b'\t' => KeyInput::new(Key::Tab, Mods::NONE),
+        0x7f => KeyInput::new(Key::Backspace, Mods::NONE),
+        ESC => KeyInput::new(Key::Esc, Mods::NONE),
+        0x00 => KeyInput::new(' ', Mods::CTRL),
+        b @ 0x01..=0x1a => KeyInput::new((b - 0x01 + b'a') as char, Mods::CTRL),
+        b @ 0x1c..=0x1f => KeyInput::new((b - 0x1c + b'4') as char, Mods::CTRL),
+        b @ 0x20..=0x7e => KeyInput::new(b as char, Mods::NONE),
+        b => {
+            let len = match b {
+                0xc2..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf4 => 4,
+                _ => return Some((KeyInput::new(Key::Unidentified, Mods::NONE), 1)),
+            };
+            let bytes = buf.get(..len)?;
+            return Some(match std::str::from_utf8(bytes) {
+                Ok(s) => (KeyInput::new(Key::from_text(s), Mods::NONE), len),
+                Err(_) => (KeyInput::new(Key::Unidentified, Mods::NONE), 1),
+            });
+        }
+    };
+    Some((input, 1))
+}
+
+// Bits of the modifier parameter of CSI sequences
+const MOD_BITS: [(u32, Mods); 8] = [
+    (0b1, Mods::SHIFT),
+    (0b10, Mods::ALT),
+    (0b100, Mods::CTRL),
+    (0b1000, Mods::SUPER),
+    (0b10000, Mods::HYPER),
+    (0b100000, Mods::META),
+    (0b1000000, Mods::CAPS_LOCK),
+    (0b10000000, Mods::NUM_LOCK),
+];
+
+// Convert the modifier parameter of CSI sequences. The parameter is 1 + the bitmask of the modifiers.
+fn param_mods(param: Option<u32>) -> Mods {
+    let bits = param.unwrap_or(1).saturating_sub(1);
+    let mut mods = Mods::NONE;
+    for (bit, m) in MOD_BITS {
+        if bits & bit != 0 {
+            mods |= m;
+        }
+    }
+    mods
+}
+
+// Resolve the virtual modifiers on the platform where the key inputs are typed to encode them into escape sequences.
+// Cmd and Win keys are encoded as the super modifier.
+pub(crate) fn resolve_mods(mods: Mods, platform: Platform) -> Mods {
+    let mut mods = mods.resolve(platform);
+    if mods.intersects(Mods::CMD | Mods::WIN) {
+        mods |= Mods::SUPER;
+    }
+    mods
+}
+
+// Wrap the pasted text with the bracketed paste markers. `ESC` bytes are removed from the text so that an embedded
+// `ESC [ 201 ~` cannot end the paste early and inject the rest of the text as key inputs.
+pub(crate) fn encode_paste(text: &str) -> Vec<u8> {
+    let mut bytes = PASTE_START.to_vec();
+    bytes.extend(text.bytes().filter(|&b| b != ESC));
+    bytes.extend_from_slice(PASTE_END);
+    bytes
+}
+
+// Convert the modifiers into the modifier parameter of CSI sequences. Modifiers must be resolved in advance.
+pub(crate) fn mods_param(mods: Mods) -> u32 {
+    let mut bits = 0;
+    for (bit, m) in MOD_BITS {
+        if mods.contains(m) {
+            bits |= bit;
+        }
+    }
+    bits + 1
+}
+
+// Convert the Unicode code point reported by modifyOtherKeys sequences and the kitty keyboard protocol.
+fn codepoint_key(code: u32) -> Key {
+    match code {
+        0x08 | 0x7f => Key::Backspace,
+        0x09 => Key::Tab,
+        0x0d => Key::Enter,
+        0x1b => Key::Esc,
+        c => char::from_u32(c)
+            .map(Key::Char)
+            .unwrap_or(Key::Unidentified),
+    }
+}
+
+fn function_key(n: u32) -> Key {
+    match n {
+        1 | 7 => Key::Home,
+        2 => Key::Insert,
+        3 => Key::Delete,
+        4 | 8 => Key::End,
+        5 => Key::PageUp,
+        6 => Key::PageDown,
+        11 => Key::F1,
+        12 => Key::F2,
+        13 => Key::F3,
+        14 => Key::F4,
+        15 => Key::F5,
+        17 => Key::F6,
+        18 => Key::F7,
+        19 => Key::F8,
+        20 => Key::F9,
+        21 => Key::F10,
+        23 => Key::F11,
+        24 => Key::F12,
+        25 => Key::F13,
+        26 => Key::F14,
+        28 => Key::F15,
+        29 => Key::F16,
+        31 => Key::F17,
+        32 => Key::F18,
+        33 => Key::F19,
+        34 => Key::F20,
+        _ => Key::Unidentified,
+    }
+}
+
+// Reverse of `function_key`. Home and End keys are encoded with `final_byte` instead.
+fn function_key_number(key: Key) -> Option<u32> {
+    let n = match key {
+        Key::Insert => 2,
+        Key::Delete => 3,
+        Key::PageUp => 5,
+        Key::PageDown => 6,
+        Key::F5 => 15,
+        Key::F6 => 17,
+        Key::F7 => 18,
+        Key::F8 => 19,
+        Key::F9 => 20,
+        Key::F10 => 21,
+        Key::F11 => 23,
+        Key::F12 => 24,
+        Key::F13 => 25,
+        Key::F14 => 26,
+        Key::F15 => 28,
+        Key::F16 => 29,
+        Key::F17 => 31,
+        Key::F18 => 32,
+        Key::F19 => 33,
+        Key::F20 => 34,
+        _ => return None,
+    };
+    Some(n)
+}
+
+// Decode the final byte shared by CSI and SS3 sequences such as `A` of `ESC [ A` and `ESC O A`.
+fn final_key(b: u8) -> Option<Key> {
+    let key = match b {
+        b'A' => Key::Up,
+        b'B' => Key::Down,
+        b'C' => Key::Right,
+        b'D' => Key::Left,
+        b'H' => Key::Home,
+        b'F' => Key::End,
+        b'P' => Key::F1,
+        b'Q' => Key::F2,
+        b'R' => Key::F3,
+        b'S' => Key::F4,
+        _ => return None,
+    };
+    Some(key)
+}
+
+// Reverse of `final_key`
+fn final_byte(key: Key) -> Option<u8> {
+    let b = match key {
+        Key::Up => b'A',
+        Key::Down => b'B',
+        Key::Right => b'C',
+        Key::Left => b'D',
+        Key::Home => b'H',
+        Key::End => b'F',
+        Key::F1 => b'P',
+        Key::F2 => b'Q',
+        Key::F3 => b'R',
+        Key::F4 => b'S',
+        _ => return None,
+    };
+    Some(b)
+}
+
+// Control character sent for the character key with Ctrl modifier such as 0x03 for Ctrl+c
+fn control_char(c: char) -> Option<u8> {
+    let b = match c {
+        'a'..='z' => c as u8 - b'a' + 0x01,
+        'A'..='Z' => c as u8 - b'A' + 0x01,
+        ' ' | '@' | '2' => 0x00,
+        '[' | '3' => 0x1b,
+        '\\' | '4' => 0x1c,
+        ']' | '5' => 0x1d,
+        '^' | '6' => 0x1e,
+        '_' | '/' | '7' => 0x1f,
+        '?' | '8' => 0x7f,
+        _ => return None,
+    };
+    Some(b)
+}
+
+// `ESC O {final}`
+fn decode_ss3(buf: &[u8]) -> Decoded {
+    let Some(&b) = buf.get(2) else {
+        return Decoded::Incomplete;
+    };
+    match final_key(b) {
+        Some(key) => Decoded::Event(key_event(key, Mods::NONE), 3),
+        None if b == b'M' => Decoded::Event(key_event(Key::Enter, Mods::NONE), 3), // Enter key on keypad
+        None => Decoded::Event(key_event('O', Mods::ALT), 2),
+    }
+}
+
+// `ESC [ {params} {intermediates} {final}`
+fn decode_csi(buf: &[u8]) -> Decoded {
+    let body = &buf[2..];
+    let Some(end) = body.iter().position(|b| !(0x20..=0x3f).contains(b)) else {
+        return Decoded::Incomplete;
+    };
+    let len = end + 3;
+    let unidentified = Decoded::Event(key_event(Key::Unidentified, Mods::NONE), len);
+
+    let fin = body[end];
+    if !(0x40..=0x7e).contains(&fin) {
+        // Broken sequence
+        return Decoded::Event(key_event(Key::Unidentified, Mods::NONE), len - 1);
+    }
+
+    let params = &body[..end];
+    if params.is_empty() && fin == b'M' {
+        // X10 mouse report `ESC [ M {button} {column} {row}` is followed by 3 raw bytes
+        if buf.len() < len + 3 {
+            return Decoded::Incomplete;
+        }
+        return Decoded::Event(key_event(Key::Ignored, Mods::NONE), len + 3);
+    }
+    if params.first() == Some(&b'?') && fin == b'u' {
+        // Reply to the query of the kitty keyboard protocol flags
+        let flags = Params::new(&params[1..]).get(0).unwrap_or(0);
+        let flags = KittyFlags::from_bits_truncate(flags as u8);
+        return Decoded::Event(TerminalEvent::KittyFlags(flags), len);
+    }
+    if params.first().is_some_and(|b| (0x3c..=0x3f).contains(b)) {
+        return unidentified; // Private sequences such as SGR mouse reports are not supported
+    }
+    let params = Params::new(params);
+
+    let (key, mods) = match fin {
+        b'~' => match params.get(0) {
+            Some(27) => match params.get(2) {
+                Some(code) => (codepoint_key(code), param_mods(params.get(1))), // modifyOtherKeys
+                None => return unidentified,
+            },
+            Some(n) => (function_key(n), param_mods(params.get(1))),
+            None => return unidentified,
+        },
+        // The kitty keyboard protocol or modifyOtherKeys with formatOtherKeys=1
+        b'u' => match params.get(0) {
+            Some(code) => decode_csi_u(code, params.sub(0, 1), param_mods(params.get(1))),
+            None => return unidentified,
+        },
+        b'Z' => (Key::Tab, Mods::SHIFT), // Back tab
+        b'I' | b'O' if params.is_empty() => (Key::Ignored, Mods::NONE), // Focus events
+        b => match final_key(b) {
+            Some(key) => (key, param_mods(params.get(1))),
+            None => return unidentified,
+        },
+    };
+
+    let input = KeyInput::new(key, mods);
+    // Event type of the kitty keyboard protocol: 1 for press, 2 for repeat, 3 for release
+    let event = match params.sub(1, 1) {
+        Some(3) => TerminalEvent::KeyRelease(input),
+        _ => TerminalEvent::Key(input),
+    };
+    Decoded::Event(event, len)
+}
+
+// `ESC [ {code}:{shifted}:{base} ; {mods}:{event} ; {text} u`. The kitty keyboard protocol reports the unshifted key
+// with the Shift modifier such as `ESC [ 97 ; 2 u` for Shift+a. The shifted key is only available when
+// `KittyFlags::REPORT_ALTERNATE_KEYS` is enabled so the key is converted to upper case as a fallback.
+fn decode_csi_u(code: u32, shifted: Option<u32>, mods: Mods) -> (Key, Mods) {
+    if let Some((key, m)) = kitty::functional_key(code) {
+        return (key, mods | m);
+    }
+    let key = match (codepoint_key(code), shifted.and_then(char::from_u32)) {
+        (Key::Char(_), Some(c)) if mods.contains(Mods::SHIFT) => Key::Char(c),
+        (Key::Char(c), None) if mods.contains(Mods::SHIFT) => {
+            let mut upper = c.to_uppercase();
+            match (upper.next(), upper.next()) {
+                (Some(u), None) => Key::Char(u),
+                _ => Key::Char(c),
+            }
+        }
+        (key, _) => key,
+    };
+    (key, mods)
+}
+
+// Parameters of CSI sequence separated by `;`. Each parameter may have sub-parameters separated by `:` such as
+// `97:65;5:3`.
+struct Params<'a>(Vec<&'a str>);
+
+impl<'a> Params<'a> {
+    fn new(params: &'a [u8]) -> Self {
+        // Parameters are ASCII characters
+        Self(
+            std::str::from_utf8(params)
+                .unwrap_or_default()
+                .split(';')
+                .collect(),
+        )
+    }
+
+    fn get(&self, idx: usize) -> Option<u32> {
+        self.sub(idx, 0)
+    }
+
+    fn sub(&self, idx: usize, sub: usize) -> Option<u32> {
+        self.0.get(idx)?.split(':').nth(sub)?.parse().ok()
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self.0.as_slice(), [""])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Code, Keybinds};
+    use std::thread::sleep;
+
+    fn decode_all(bytes: &[u8]) -> Vec<TerminalEvent> {
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(bytes);
+        let mut events = vec![];
+        while let Some(event) = decoder.next_event() {
+            events.push(event);
+        }
+        assert!(!decoder.has_pending(), "{bytes:?}");
+        events
+    }
+
+    fn key(key: impl Into<Key>, mods: Mods) -> TerminalEvent {
+        key_event(key, mods)
+    }
+
+    #[test]
+    fn decode_plain_chars() {
+        assert_eq!(
+            decode_all(b"aZ \r\t\x7f\x08\n\x00\x1d"),
+            [
+                key('a', Mods::NONE),
+                key('Z', Mods::NONE),
+                key(' ', Mods::NONE),
+                key(Key::Enter, Mods::NONE),
+                key(Key::Tab, Mods::NONE),
+                key(Key::Backspace, Mods::NONE),
+                key('h', Mods::CTRL),
+                key('j', Mods::CTRL),
+                key(' ', Mods::CTRL),
+                key('5', Mods::CTRL),
+            ],
+        );
+        assert_eq!(
+            decode_all("é🐶".as_bytes()),
+            [key('é', Mods::NONE), key('🐶', Mods::NONE)],
+        );
+        assert_eq!(
+            decode_all(&[0x80, b'a']),
+            [key(Key::Unidentified, Mods::NONE), key('a', Mods::NONE)],
+        );
+    }
+
+    #[test]
+    fn decode_cursor_keys() {
+        for (bytes, expected) in [
+            (&b"\x1b[A"[..], key(Key::Up, Mods::NONE)),
+            (b"\x1b[B", key(Key::Down, Mods::NONE)),
+            (b"\x1b[C", key(Key::Right, Mods::NONE)),
+            (b"\x1b[D", key(Key::Left, Mods::NONE)),
+            (b"\x1b[H", key(Key::Home, Mods::NONE)),
+            (b"\x1b[F", key(Key::End, Mods::NONE)),
+            (b"\x1bOA", key(Key::Up, Mods::NONE)),
+            (b"\x1bOH", key(Key::Home, Mods::NONE)),
+            (b"\x1bOM", key(Key::Enter, Mods::NONE)),
+            (b"\x1b[1;5A", key(Key::Up, Mods::CTRL)),
+            (b"\x1b[1;2D", key(Key::Left, Mods::SHIFT)),
+            (
+                b"\x1b[1;8C",
+                key(Key::Right, Mods::CTRL | Mods::ALT | Mods::SHIFT),
+            ),
+            (b"\x1b[1;9B", key(Key::Down, Mods::SUPER)),
+            (b"\x1b[Z", key(Key::Tab, Mods::SHIFT)),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_function_keys() {
+        for (bytes, expected) in [
+            (&b"\x1bOP"[..], key(Key::F1, Mods::NONE)),
+            (b"\x1bOS", key(Key::F4, Mods::NONE)),
+            (b"\x1b[1;3P", key(Key::F1, Mods::ALT)),
+            (b"\x1b[15~", key(Key::F5, Mods::NONE)),
+            (b"\x1b[24;5~", key(Key::F12, Mods::CTRL)),
+            (b"\x1b[34~", key(Key::F20, Mods::NONE)),
+            (b"\x1b[2~", key(Key::Insert, Mods::NONE)),
+            (b"\x1b[3;2~", key(Key::Delete, Mods::SHIFT)),
+            (b"\x1b[5~", key(Key::PageUp, Mods::NONE)),
+            (b"\x1b[6~", key(Key::PageDown, Mods::NONE)),
+            (b"\x1b[1~", key(Key::Home, Mods::NONE)),
+            (b"\x1b[4~", key(Key::End, Mods::NONE)),
+            (b"\x1b[99~", key(Key::Unidentified, Mods::NONE)),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_modify_other_keys() {
+        for (bytes, expected) in [
+            (&b"\x1b[27;5;105~"[..], key('i', Mods::CTRL)),
+            (b"\x1b[27;5;9~", key(Key::Tab, Mods::CTRL)),
+            (b"\x1b[27;2;13~", key(Key::Enter, Mods::SHIFT)),
+            (b"\x1b[27;6;65~", key('A', Mods::CTRL)),
+            (b"\x1b[105;5u", key('i', Mods::CTRL)),
+            (
+                b"\x1b[27;7;127~",
+                key(Key::Backspace, Mods::CTRL | Mods::ALT),
+            ),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_alt_prefix() {
+        for (bytes, expected) in [
+            (&b"\x1bx"[..], key('x', Mods::ALT)),
+            (b"\x1bX", key('X', Mods::ALT)),
+            (b"\x1b\r", key(Key::Enter, Mods::ALT)),
+            (b"\x1b\x7f", key(Key::Backspace, Mods::ALT)),
+            (b"\x1b\x03", key('c', Mods::CTRL | Mods::ALT)),
+            (b"\x1b\x1b[A", key(Key::Up, Mods::ALT)),
+            (b"\x1b\x1b[1;5A", key(Key::Up, Mods::CTRL | Mods::ALT)),
+            ("\x1bé".as_bytes(), key('é', Mods::ALT)),
+            (
+                b"\x1b\x1b[97;1:3u",
+                TerminalEvent::KeyRelease(KeyInput::new('a', Mods::ALT)),
+            ),
+            (
+                b"\x1b\x1b[?1u",
+                TerminalEvent::KittyFlags(KittyFlags::DISAMBIGUATE_ESCAPE_CODES),
+            ),
+        ] {
+            assert_eq!(decode_all(bytes), [expected], "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn decode_paste() {
+        assert_eq!(
+            decode_all(b"a\x1b[200~foo\x1b[Abar\r\x1b[201~b"),
+            [
+                key('a', Mods::NONE),
+                TerminalEvent::Paste("foo\x1b[Abar\r".into()),
+                key('b', Mods::NONE),
+            ],
+        );
+        assert_eq!(
+            decode_all(b"\x1b[200~\x1b[201~"),
+            [TerminalEvent::Paste("".into())],
+        );
+    }
+
+    #[test]
+    fn decode_unsupported_sequences() {
+        assert_eq!(
+            decode_all(b"\x1b[<0;10;20Ma"),
+            [key(Key::Unidentified, Mods::NONE), key('a', Mods::NONE)],
+        );
+        assert_eq!(
+            decode_all(b"\x1b[I\x1b[O"),
+            [key(Key::Ignored, Mods::NONE), key(Key::Ignored, Mods::NONE)],
+        );
+        // X10 mouse report consumes the following 3 bytes
+        assert_eq!(
+            decode_all(b"\x1b[M #!a"),
+            [key(Key::Ignored, Mods::NONE), key('a', Mods::NONE)],
+        );
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(b"\x1b[M ");
+        assert_eq!(decoder.next_event(), None);
+        decoder.feed(b"#!");
+        assert_eq!(decoder.next_event(), Some(key(Key::Ignored, Mods::NONE)));
+        assert!(!decoder.has_pending());
+        // Broken sequence interrupted by a control character
+        assert_eq!(
+            decode_all(b"\x1b[1\x03"),
+            [key(Key::Unidentified, Mods::NONE), key('c', Mods::CTRL)],
+        );
+        assert_eq!(
+            decode_all(b"\x1bOx"),
+            [key('O', Mods::ALT), key('x', Mods::NONE)],
+        );
+    }
+
+    #[test]
+    fn decode_incrementally() {
+        let bytes = "\x1b[1;5A\x1b[27;5;105~\x1bxあ\x1b[200~foo\x1b[201~\x1b[15~".as_bytes();
+        let expected = decode_all(bytes);
+        assert_eq!(expected.len(), 6);
+
+        let mut decoder = TerminalDecoder::new();
+        let mut actual = vec![];
+        for b in bytes {
+            decoder.feed(&[*b]);
+            while let Some(event) = decoder.next_event() {
+                actual.push(event);
+            }
+        }
+        assert_eq!(actual, expected);
+        assert!(!decoder.has_pending());
+        assert_eq!(decoder.pending_timeout(), None);
+    }
+
+    #[test]
+    fn resolve_esc_on_timeout() {
+        let mut decoder = TerminalDecoder::new();
+        decoder.set_timeout(Duration::from_millis(10));
+        assert_eq!(decoder.pending_timeout(), None);
+
+        decoder.feed(b"\x1b");
+        assert_eq!(decoder.next_event(), None);
+        assert!(decoder.pending_timeout().unwrap() <= Duration::from_millis(10));
+        sleep(Duration::from_millis(50));
+        assert_eq!(decoder.pending_timeout(), Some(Duration::ZERO));
+        assert_eq!(decoder.next_event(), Some(key(Key::Esc, Mods::NONE)));
+        assert_eq!(decoder.next_event(), None);
+        assert_eq!(decoder.pending_timeout(), None);
+
+        // Bytes followed before the timeout
+        decoder.feed(b"\x1b");
+        assert_eq!(decoder.next_event(), None);
+        decoder.feed(b"[B");
+        assert_eq!(decoder.next_event(), Some(key(Key::Down, Mods::NONE)));
+
+        // Incomplete sequences are resolved as Alt prefix
+        for (bytes, expected) in [
+            (&b"\x1b["[..], key('[', Mods::ALT)),
+            (b"\x1bO", key('O', Mods::ALT)),
+            (b"\x1b\x1b", key(Key::Esc, Mods::ALT)),
+        ] {
+            decoder.feed(bytes);
+            assert_eq!(decoder.next_event(), None, "{bytes:?}");
+            sleep(Duration::from_millis(50));
+            assert_eq!(decoder.next_event(), Some(expected), "{bytes:?}");
+            assert!(!decoder.has_pending(), "{bytes:?}");
+        }
+
+        // Rest of the incomplete sequence is decoded as plain characters
+        decoder.feed(b"\x1b[1;");
+        assert_eq!(decoder.next_event(), None);
+        sleep(Duration::from_millis(50));
+        let mut events = vec![];
+        while let Some(event) = decoder.next_event() {
+            events.push(event);
+        }
+        assert_eq!(
+            events,
+            [
+                key('[', Mods::ALT),
+                key('1', Mods::NONE),
+                key(';', Mods::NONE),
+            ],
+        );
+
+        // Pasted text never times out
+        decoder.feed(b"\x1b[200~foo");
+        assert_eq!(decoder.next_event(), None);
+        sleep(Duration::from_millis(50));
+        assert_eq!(decoder.next_event(), None);
+        decoder.feed(b"\x1b[201~");
+        assert_eq!(
+            decoder.next_event(),
+            Some(TerminalEvent::Paste("foo".into()))
+        );
+    }
+
+    #[test]
+    fn decode_paste_incrementally() {
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(b"\x1b[200~foo\x1b[2");
+        assert_eq!(decoder.next_event(), None);
+        decoder.feed(b"01");
+        assert_eq!(decoder.next_event(), None);
+        decoder.feed(b"~a");
+        assert_eq!(
+            decoder.next_event(),
+            Some(TerminalEvent::Paste("foo".into())),
+        );
+        assert_eq!(decoder.next_event(), Some(key('a', Mods::NONE)));
+        assert!(!decoder.has_pending());
+    }
+
+    #[test]
+    fn split_too_long_paste() {
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(b"\x1b[200~");
+        let chunk = "é".repeat(1000);
+        let mut pasted = String::new();
+        // The end marker is missing
+        while pasted.len() <= MAX_PASTE_LEN {
+            decoder.feed(chunk.as_bytes());
+            pasted.push_str(&chunk);
+        }
+        decoder.feed(b"\x1b[201");
+
+        let Some(TerminalEvent::Paste(first)) = decoder.next_event() else {
+            panic!("pasted text was not flushed");
+        };
+        assert!(first.len() >= MAX_PASTE_LEN, "{}", first.len());
+        assert!(pasted.starts_with(&first));
+        assert_eq!(decoder.next_event(), None);
+
+        // The rest of the bytes are still decoded as pasted text
+        decoder.feed(b"~a");
+        let Some(TerminalEvent::Paste(second)) = decoder.next_event() else {
+            panic!("rest of pasted text was not decoded");
+        };
+        assert_eq!(first + &second, pasted);
+        assert_eq!(decoder.next_event(), Some(key('a', Mods::NONE)));
+        assert!(!decoder.has_pending());
+    }
+
+    #[test]
+    fn split_too_long_invalid_paste() {
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(b"\x1b[200~");
+        // Continuation bytes without any lead byte and the end marker
+        decoder.feed(&vec![0x80; MAX_PASTE_LEN + 10]);
+
+        let Some(TerminalEvent::Paste(first)) = decoder.next_event() else {
+            panic!("pasted text was not flushed");
+        };
+        assert!(!first.is_empty());
+        assert!(decoder.buf.len() < PASTE_START.len() + PASTE_END.len());
+        assert_eq!(decoder.next_event(), None);
+
+        decoder.feed(b"\x1b[201~a");
+        assert!(matches!(
+            decoder.next_event(),
+            Some(TerminalEvent::Paste(_))
+        ));
+        assert_eq!(decoder.next_event(), Some(key('a', Mods::NONE)));
+        assert_eq!(decoder.next_event(), None);
+        assert!(!decoder.has_pending());
+    }
+
+    #[test]
+    fn restart_timeout_on_split_sequence() {
+        let mut decoder = TerminalDecoder::new();
+        decoder.set_timeout(Duration::from_millis(100));
+
+        // Each chunk arrives before the timeout but the whole sequence takes longer than the timeout
+        decoder.feed(b"\x1b");
+        assert_eq!(decoder.next_event(), None);
+        for chunk in [&b"["[..], b"1", b";", b"5"] {
+            sleep(Duration::from_millis(60));
+            decoder.feed(chunk);
+            assert_eq!(decoder.next_event(), None, "{chunk:?}");
+        }
+        sleep(Duration::from_millis(60));
+        decoder.feed(b"A");
+        assert_eq!(decoder.next_event(), Some(key(Key::Up, Mods::CTRL)));
+        assert!(!decoder.has_pending());
+    }
+
+    #[test]
+    fn encode_legacy_keys() {
+        for (event, expected) in [
+            (key('a', Mods::NONE), &b"a"[..]),
+            (key('A', Mods::NONE), b"A"),
+            (key('é', Mods::NONE), "é".as_bytes()),
+            (
+                key(Key::Str(crate::KeyStr::new("👍🏽").unwrap()), Mods::NONE),
+                "👍🏽".as_bytes(),
+            ),
+            (key('c', Mods::CTRL), b"\x03"),
+            (key('C', Mods::CTRL), b"\x03"),
+            (key(' ', Mods::CTRL), b"\x00"),
+            (key('4', Mods::CTRL), b"\x1c"),
+            (key('?', Mods::CTRL), b"\x7f"),
+            (key('x', Mods::ALT), b"\x1bx"),
+            (key('x', Mods::LALT), b"\x1bx"),
+            (key('c', Mods::CTRL | Mods::ALT), b"\x1b\x03"),
+            (key('a', Mods::CAPS_LOCK | Mods::NUMPAD), b"a"),
+            (key(Key::Enter, Mods::NONE), b"\r"),
+            (key(Key::Enter, Mods::ALT), b"\x1b\r"),
+            (key(Key::Tab, Mods::NONE), b"\t"),
+            (key(Key::Tab, Mods::SHIFT), b"\x1b[Z"),
+            (key(Key::Backspace, Mods::NONE), b"\x7f"),
+            (key(Key::Esc, Mods::NONE), b"\x1b"),
+            (key(Key::Esc, Mods::ALT), b"\x1b\x1b"),
+            (key(Key::Up, Mods::NONE), b"\x1b[A"),
+            (key(Key::Left, Mods::SHIFT), b"\x1b[1;2D"),
+            (key(Key::Home, Mods::CTRL | Mods::ALT), b"\x1b[1;7H"),
+            (key(Key::F1, Mods::NONE), b"\x1bOP"),
+            (key(Key::F4, Mods::CTRL), b"\x1b[1;5S"),
+            (key(Key::Insert, Mods::NONE), b"\x1b[2~"),
+            (key(Key::PageDown, Mods::SHIFT), b"\x1b[6;2~"),
+            (key(Key::F5, Mods::NONE), b"\x1b[15~"),
+            (key(Key::F20, Mods::ALT), b"\x1b[34;3~"),
+            // Shift is carried by the character
+            (
+                TerminalEvent::Key(KeyInput::with_code('A', Mods::SHIFT, Code::KeyA)),
+                b"A",
+            ),
+            (
+                TerminalEvent::Key(KeyInput::with_code(
+                    'A',
+                    Mods::CTRL | Mods::SHIFT,
+                    Code::KeyA,
+                )),
+                b"\x01",
+            ),
+            (
+                TerminalEvent::Key(KeyInput::with_code(
+                    '!',
+                    Mods::LSHIFT | Mods::ALT,
+                    Code::Digit1,
+                )),
+                b"\x1b!",
+            ),
+            (
+                TerminalEvent::KeyRelease(KeyInput::new('a', Mods::NONE)),
+                b"",
+            ),
+            (TerminalEvent::Paste("foo".into()), b"\x1b[200~foo\x1b[201~"),
+        ] {
+            assert_eq!(event.encode_legacy().unwrap(), expected, "{event:?}");
+        }
+    }
+
+    #[test]
+    fn encode_legacy_ambiguous_keys() {
+        // These inputs are encoded into the same bytes as other keys
+        for (event, same) in [
+            (key('i', Mods::CTRL), key(Key::Tab, Mods::NONE)),
+            (key('m', Mods::CTRL), key(Key::Enter, Mods::NONE)),
+            (key('[', Mods::CTRL), key(Key::Esc, Mods::NONE)),
+            (key('3', Mods::CTRL), key(Key::Esc, Mods::NONE)),
+            (key('8', Mods::CTRL), key(Key::Backspace, Mods::NONE)),
+        ] {
+            assert_eq!(event.encode_legacy(), same.encode_legacy(), "{event:?}");
+        }
+    }
+
+    #[test]
+    fn encode_legacy_unencodable_keys() {
+        for input in [
+            KeyInput::new('1', Mods::CTRL),
+            KeyInput::new('あ', Mods::CTRL),
+            KeyInput::new('a', Mods::SUPER),
+            KeyInput::new('a', Mods::CMD),
+            KeyInput::new('a', Mods::HYPER),
+            KeyInput::new(Key::Enter, Mods::SHIFT),
+            KeyInput::new(Key::Enter, Mods::CTRL),
+            KeyInput::new(Key::Tab, Mods::CTRL | Mods::SHIFT),
+            KeyInput::new(Key::Backspace, Mods::CTRL),
+            KeyInput::new(Key::Esc, Mods::SHIFT),
+            KeyInput::new(Key::Str(crate::KeyStr::new("👍🏽").unwrap()), Mods::CTRL),
+            KeyInput::with_code('a', Mods::SHIFT, Code::KeyA),
+            KeyInput::with_code('a', Mods::CTRL | Mods::SHIFT, Code::KeyA),
+            KeyInput::with_code('é', Mods::RSHIFT, Code::Slash),
+            KeyInput::with_code(
+                Key::Str(crate::KeyStr::new("g\u{303}").unwrap()),
+                Mods::SHIFT,
+                Code::KeyA,
+            ),
+            KeyInput::new(Key::F21, Mods::NONE),
+            KeyInput::new(Key::Copy, Mods::NONE),
+            KeyInput::new(Key::MouseLeft, Mods::NONE),
+            KeyInput::new(Key::WheelDown, Mods::NONE),
+            KeyInput::new(Key::Physical(Code::KeyA), Mods::NONE),
+            KeyInput::new(Key::Unidentified, Mods::NONE),
+            KeyInput::new(Key::Ignored, Mods::NONE),
+        ] {
+            assert_eq!(
+                TerminalEvent::Key(input).encode_legacy(),
+                Err(Error::UnencodableInput(input)),
+                "{input:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn encode_legacy_decode_round_trip() {
+        for event in [
+            key('a', Mods::NONE),
+            key('Z', Mods::ALT),
+            key('あ', Mods::NONE),
+            key('x', Mods::CTRL),
+            key('h', Mods::CTRL),
+            key(' ', Mods::CTRL | Mods::ALT),
+            key('5', Mods::CTRL),
+            key(Key::Enter, Mods::NONE),
+            key(Key::Tab, Mods::SHIFT),
+            key(Key::Tab, Mods::SHIFT | Mods::ALT),
+            key(Key::Backspace, Mods::ALT),
+            key(Key::Down, Mods::NONE),
+            key(Key::Right, Mods::CTRL | Mods::SHIFT),
+            key(Key::End, Mods::SUPER),
+            key(Key::F2, Mods::NONE),
+            key(Key::F3, Mods::SHIFT),
+            key(Key::Delete, Mods::META),
+            key(Key::F12, Mods::CTRL | Mods::ALT | Mods::SHIFT),
+            key(Key::F17, Mods::NONE),
+            TerminalEvent::Paste("foo\nbar".into()),
+        ] {
+            let encoded = event.encode_legacy().unwrap();
+            assert_eq!(decode_all(&encoded), vec![event], "{encoded:?}");
+        }
+    }
+
+    #[test]
+    fn encode_legacy_paste_with_end_marker() {
+        let event = TerminalEvent::Paste("foo\x1b[201~\x1b[Abar".into());
+        let encoded = event.encode_legacy().unwrap();
+        assert_eq!(encoded, b"\x1b[200~foo[201~[Abar\x1b[201~");
+        assert_eq!(
+            decode_all(&encoded),
+            [TerminalEvent::Paste("foo[201~[Abar".into())],
+        );
+    }
+
+    #[test]
+    fn normalize_ambiguous_inputs() {
+        for (input, expected) in [
+            (
+                KeyInput::new('i', Mods::CTRL),
+                KeyInput::new(Key::Tab, Mods::NONE),
+            ),
+            (
+                KeyInput::new('I', Mods::CTRL),
+                KeyInput::new(Key::Tab, Mods::NONE),
+            ),
+            (
+                KeyInput::new('m', Mods::CTRL),
+                KeyInput::new(Key::Enter, Mods::NONE),
+            ),
+            (
+                KeyInput::new('[', Mods::CTRL),
+                KeyInput::new(Key::Esc, Mods::NONE),
+            ),
+            (
+                KeyInput::new('3', Mods::CTRL),
+                KeyInput::new(Key::Esc, Mods::NONE),
+            ),
+            (
+                KeyInput::new('h', Mods::CTRL),
+                KeyInput::new(Key::Backspace, Mods::NONE),
+            ),
+            (
+                KeyInput::new('?', Mods::CTRL),
+                KeyInput::new(Key::Backspace, Mods::NONE),
+            ),
+            (
+                KeyInput::new('8', Mods::CTRL),
+                KeyInput::new(Key::Backspace, Mods::NONE),
+            ),
+            (
+                KeyInput::new('@', Mods::CTRL),
+                KeyInput::new(' ', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('2', Mods::CTRL),
+                KeyInput::new(' ', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('\\', Mods::CTRL),
+                KeyInput::new('4', Mods::CTRL),
+            ),
+            (
+                KeyInput::new(']', Mods::CTRL),
+                KeyInput::new('5', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('^', Mods::CTRL),
+                KeyInput::new('6', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('_', Mods::CTRL),
+                KeyInput::new('7', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('/', Mods::CTRL),
+                KeyInput::new('7', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('X', Mods::CTRL),
+                KeyInput::new('x', Mods::CTRL),
+            ),
+            (
+                KeyInput::new('i', Mods::LCTRL | Mods::ALT),
+                KeyInput::new(Key::Tab, Mods::ALT),
+            ),
+            (
+                KeyInput::new('M', Mods::CTRL | Mods::SUPER),
+                KeyInput::new(Key::Enter, Mods::SUPER),
+            ),
+            (
+                KeyInput::with_code('i', Mods::CTRL, crate::Code::KeyI),
+                KeyInput::with_code(Key::Tab, Mods::NONE, crate::Code::KeyI),
+            ),
+        ] {
+            assert_eq!(input.normalize_for_terminal(), expected, "{input:?}");
+            // Normalization is idempotent
+            assert_eq!(expected.normalize_for_terminal(), expected, "{input:?}");
+        }
+
+        for input in [
+            KeyInput::new('x', Mods::CTRL),
+            KeyInput::new('i', Mods::NONE),
+            KeyInput::new('i', Mods::ALT),
+            KeyInput::new('i', Mods::MOD),
+            KeyInput::new('1', Mods::CTRL),
+            KeyInput::new('あ', Mods::CTRL),
+            KeyInput::new(Key::Tab, Mods::CTRL),
+            KeyInput::new(Key::Up, Mods::CTRL),
+        ] {
+            assert_eq!(input.normalize_for_terminal(), input, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn normalized_inputs_match_decoded_inputs() {
+        // Inputs decoded from the bytes sent by legacy terminals are already normalized
+        for b in (0x00..=0x1f).chain([0x7f]).filter(|&b| b != ESC) {
+            let [TerminalEvent::Key(input)] = decode_all(&[b])[..] else {
+                panic!("{b:#x}");
+            };
+            if b == 0x08 {
+                assert_eq!(
+                    input.normalize_for_terminal(),
+                    KeyInput::new(Key::Backspace, Mods::NONE)
+                );
+            } else {
+                assert_eq!(input.normalize_for_terminal(), input, "{b:#x}");
+            }
+        }
+    }
+
+    #[test]
+    fn dispatch_decoded_events() {
+        #[derive(Clone, PartialEq, Eq, Debug)]
+        enum A {
+            Foo,
+            Bar,
+        }
+
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+x Ctrl+c", A::Foo).unwrap();
+        keybinds.bind("Alt+Up", A::Bar).unwrap();
+
+        let mut decoder = TerminalDecoder::new();
+        decoder.feed(b"\x18\x03\x1b[1;3A\x1b[200~\x18\x03\x1b[201~");
+        let mut actions = vec![];
+        while let Some(event) = decoder.next_event() {
+            actions.extend(keybinds.dispatch(event).cloned());
+        }
+        assert_eq!(actions, [A::Foo, A::Bar]);
+    }
+}
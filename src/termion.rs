@@ -0,0 +1,314 @@
+//! Support for [`termion`] crate.
+//!
+//! This module provides the conversions from termion's event types to [`KeyInput`]. Since termion's keys contain the
+//! modifiers such as `Key::Ctrl('c')`, they are converted into [`KeyInput`] directly. This module is only available on
+//! Unix-like platforms because termion does not support Windows.
+//!
+//! ```no_run
+//! use keybinds::{KeyInput, Keybinds};
+//! use std::io::{stdin, stdout, Write};
+//! use termion::input::TermRead;
+//! use termion::raw::IntoRawMode;
+//!
+//! #[derive(PartialEq, Eq, Debug)]
+//! enum Action {
+//!     SayHi,
+//!     Exit,
+//! }
+//!
+//! let mut keybinds = Keybinds::default();
+//! keybinds.bind("h i", Action::SayHi).unwrap();
+//! keybinds.bind("Ctrl+x Ctrl+c", Action::Exit).unwrap();
+//!
+//! let mut stdout = stdout().into_raw_mode().unwrap();
+//!
+//! for event in stdin().events() {
+//!     let event = event.unwrap();
+//!
+//!     // Convert termion's `Event` into `KeyInput`
+//!     write!(stdout, "Key input `{:?}`\r\n", KeyInput::from(&event)).unwrap();
+//!
+//!     // `Keybinds::dispatch` accepts termion's `Event`
+//!     if let Some(action) = keybinds.dispatch(event) {
+//!         match action {
+//!             Action::SayHi => write!(stdout, "Hi!\r\n").unwrap(),
+//!             Action::Exit => break,
+//!         }
+//!     }
+//!     stdout.flush().unwrap();
+//! }
+//! ```
+use crate::{Key, KeyInput, Mods};
+use termion::event::{Event, Key as TermionKey, MouseButton, MouseEvent};
+
+// Terminals send Enter and Tab keys as control characters and termion reports them as they are.
+fn char_key(c: char) -> Key {
+    match c {
+        '\n' => Key::Enter,
+        '\t' => Key::Tab,
+        c => Key::Char(c),
+    }
+}
+
+fn function_key(n: u8) -> Key {
+    match n {
+        1 => Key::F1,
+        2 => Key::F2,
+        3 => Key::F3,
+        4 => Key::F4,
+        5 => Key::F5,
+        6 => Key::F6,
+        7 => Key::F7,
+        8 => Key::F8,
+        9 => Key::F9,
+        10 => Key::F10,
+        11 => Key::F11,
+        12 => Key::F12,
+        _ => Key::Unidentified,
+    }
+}
+
+impl From<TermionKey> for KeyInput {
+    /// Convert termion's key into [`KeyInput`]. The modifiers in the key such as `Key::Alt('x')` or `Key::CtrlUp` are
+    /// converted into [`Mods`]. `Key::BackTab` is converted into <kbd>Shift</kbd> + <kbd>Tab</kbd>. `Key::Char('\n')`
+    /// and `Key::Char('\t')` are converted into [`Key::Enter`] and [`Key::Tab`] because terminals send these keys as
+    /// the control characters.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Mods};
+    /// use termion::event::Key as TermionKey;
+    ///
+    /// assert_eq!(KeyInput::from(TermionKey::Ctrl('c')), KeyInput::new('c', Mods::CTRL));
+    /// assert_eq!(KeyInput::from(TermionKey::Alt('x')), KeyInput::new('x', Mods::ALT));
+    /// assert_eq!(KeyInput::from(TermionKey::BackTab), KeyInput::new(Key::Tab, Mods::SHIFT));
+    /// assert_eq!(KeyInput::from(TermionKey::F(5)), KeyInput::new(Key::F5, Mods::NONE));
+    /// ```
+    fn from(key: TermionKey) -> Self {
+        let (key, mods) = match key {
+            TermionKey::Backspace => (Key::Backspace, Mods::NONE),
+            TermionKey::Left => (Key::Left, Mods::NONE),
+            TermionKey::ShiftLeft => (Key::Left, Mods::SHIFT),
+            TermionKey::AltLeft => (Key::Left, Mods::ALT),
+            TermionKey::CtrlLeft => (Key::Left, Mods::CTRL),
+            TermionKey::Right => (Key::Right, Mods::NONE),
+            TermionKey::ShiftRight => (Key::Right, Mods::SHIFT),
+            TermionKey::AltRight => (Key::Right, Mods::ALT),
+            TermionKey::CtrlRight => (Key::Right, Mods::CTRL),
+            TermionKey::Up => (Key::Up, Mods::NONE),
+            TermionKey::ShiftUp => (Key::Up, Mods::SHIFT),
+            TermionKey::AltUp => (Key::Up, Mods::ALT),
+            TermionKey::CtrlUp => (Key::Up, Mods::CTRL),
+            TermionKey::Down => (Key::Down, Mods::NONE),
+            TermionKey::ShiftDown => (Key::Down, Mods::SHIFT),
+            TermionKey::AltDown => (Key::Down, Mods::ALT),
+            TermionKey::CtrlDown => (Key::Down, Mods::CTRL),
+            TermionKey::Home => (Key::Home, Mods::NONE),
+            TermionKey::CtrlHome => (Key::Home, Mods::CTRL),
+            TermionKey::End => (Key::End, Mods::NONE),
+            TermionKey::CtrlEnd => (Key::End, Mods::CTRL),
+            TermionKey::PageUp => (Key::PageUp, Mods::NONE),
+            TermionKey::PageDown => (Key::PageDown, Mods::NONE),
+            TermionKey::BackTab => (Key::Tab, Mods::SHIFT),
+            TermionKey::Delete => (Key::Delete, Mods::NONE),
+            TermionKey::Insert => (Key::Insert, Mods::NONE),
+            TermionKey::F(n) => (function_key(n), Mods::NONE),
+            TermionKey::Char(c) => (char_key(c), Mods::NONE),
+            TermionKey::Alt(c) => (char_key(c), Mods::ALT),
+            TermionKey::Ctrl(c) => (char_key(c), Mods::CTRL),
+            TermionKey::Null => (Key::Ignored, Mods::NONE),
+            TermionKey::Esc => (Key::Esc, Mods::NONE),
+            _ => (Key::Unidentified, Mods::NONE),
+        };
+        Self::new(key, mods)
+    }
+}
+
+impl From<&TermionKey> for KeyInput {
+    fn from(key: &TermionKey) -> Self {
+        Self::from(*key)
+    }
+}
+
+impl From<&MouseEvent> for KeyInput {
+    /// Convert termion's mouse events to [`KeyInput`]. Pressing mouse buttons is converted into [`Key::MouseLeft`],
+    /// [`Key::MouseRight`] or [`Key::MouseMiddle`], and wheel events are converted into [`Key::WheelUp`],
+    /// [`Key::WheelDown`], [`Key::WheelLeft`] or [`Key::WheelRight`]. Releasing and holding buttons are converted into
+    /// `Key::Ignored`. termion does not report modifiers for mouse events.
+    ///
+    /// Note that terminals report mouse events only when mouse capture is enabled by
+    /// [`termion::input::MouseTerminal`].
+    fn from(event: &MouseEvent) -> Self {
+        let key = match event {
+            MouseEvent::Press(MouseButton::Left, _, _) => Key::MouseLeft,
+            MouseEvent::Press(MouseButton::Right, _, _) => Key::MouseRight,
+            MouseEvent::Press(MouseButton::Middle, _, _) => Key::MouseMiddle,
+            MouseEvent::Press(MouseButton::WheelUp, _, _) => Key::WheelUp,
+            MouseEvent::Press(MouseButton::WheelDown, _, _) => Key::WheelDown,
+            MouseEvent::Press(MouseButton::WheelLeft, _, _) => Key::WheelLeft,
+            MouseEvent::Press(MouseButton::WheelRight, _, _) => Key::WheelRight,
+            MouseEvent::Release(_, _) | MouseEvent::Hold(_, _) => Key::Ignored,
+        };
+        key.into()
+    }
+}
+
+impl From<MouseEvent> for KeyInput {
+    fn from(event: MouseEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
+impl From<&Event> for KeyInput {
+    /// Convert termion's events to [`KeyInput`]. Key events and mouse events are converted in the same way as
+    /// converting [`termion::event::Key`] and [`MouseEvent`]. Unsupported events are converted into `Key::Ignored`.
+    fn from(event: &Event) -> Self {
+        match event {
+            Event::Key(key) => key.into(),
+            Event::Mouse(event) => event.into(),
+            Event::Unsupported(_) => Key::Ignored.into(),
+        }
+    }
+}
+
+impl From<Event> for KeyInput {
+    fn from(event: Event) -> Self {
+        Self::from(&event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_key() {
+        assert_eq!(
+            KeyInput::from(TermionKey::Backspace),
+            KeyInput::new(Key::Backspace, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Char('a')),
+            KeyInput::new('a', Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Char('A')),
+            KeyInput::new('A', Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Char('\n')),
+            KeyInput::new(Key::Enter, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Char('\t')),
+            KeyInput::new(Key::Tab, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Esc),
+            KeyInput::new(Key::Esc, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Null),
+            KeyInput::new(Key::Ignored, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(&TermionKey::PageDown),
+            KeyInput::new(Key::PageDown, Mods::NONE),
+        );
+    }
+
+    #[test]
+    fn convert_modified_key() {
+        assert_eq!(
+            KeyInput::from(TermionKey::Ctrl('x')),
+            KeyInput::new('x', Mods::CTRL),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Alt('x')),
+            KeyInput::new('x', Mods::ALT),
+        );
+        // Shift modifier is not available with character keys
+        assert_eq!(
+            KeyInput::from(TermionKey::Alt('X')),
+            KeyInput::new('X', Mods::ALT),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::Alt('\n')),
+            KeyInput::new(Key::Enter, Mods::ALT),
+        );
+        for (key, expected) in [
+            (TermionKey::ShiftLeft, KeyInput::new(Key::Left, Mods::SHIFT)),
+            (TermionKey::AltRight, KeyInput::new(Key::Right, Mods::ALT)),
+            (TermionKey::CtrlUp, KeyInput::new(Key::Up, Mods::CTRL)),
+            (TermionKey::ShiftDown, KeyInput::new(Key::Down, Mods::SHIFT)),
+            (TermionKey::CtrlHome, KeyInput::new(Key::Home, Mods::CTRL)),
+            (TermionKey::CtrlEnd, KeyInput::new(Key::End, Mods::CTRL)),
+        ] {
+            assert_eq!(KeyInput::from(key), expected, "{key:?}");
+        }
+        // Edge case
+        assert_eq!(
+            KeyInput::from(TermionKey::BackTab),
+            KeyInput::new(Key::Tab, Mods::SHIFT),
+        );
+    }
+
+    #[test]
+    fn convert_function_key() {
+        assert_eq!(
+            KeyInput::from(TermionKey::F(1)),
+            KeyInput::new(Key::F1, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::F(12)),
+            KeyInput::new(Key::F12, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::F(0)),
+            KeyInput::new(Key::Unidentified, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(TermionKey::F(13)),
+            KeyInput::new(Key::Unidentified, Mods::NONE),
+        );
+    }
+
+    #[test]
+    fn convert_mouse_event() {
+        for (button, key) in [
+            (MouseButton::Left, Key::MouseLeft),
+            (MouseButton::Right, Key::MouseRight),
+            (MouseButton::Middle, Key::MouseMiddle),
+            (MouseButton::WheelUp, Key::WheelUp),
+            (MouseButton::WheelDown, Key::WheelDown),
+            (MouseButton::WheelLeft, Key::WheelLeft),
+            (MouseButton::WheelRight, Key::WheelRight),
+        ] {
+            assert_eq!(
+                KeyInput::from(MouseEvent::Press(button, 3, 5)),
+                KeyInput::new(key, Mods::NONE),
+            );
+        }
+        for event in [MouseEvent::Release(3, 5), MouseEvent::Hold(3, 5)] {
+            assert_eq!(
+                KeyInput::from(event),
+                KeyInput::new(Key::Ignored, Mods::NONE),
+            );
+        }
+    }
+
+    #[test]
+    fn convert_event() {
+        assert_eq!(
+            KeyInput::from(Event::Key(TermionKey::Ctrl('c'))),
+            KeyInput::new('c', Mods::CTRL),
+        );
+        assert_eq!(
+            KeyInput::from(Event::Mouse(MouseEvent::Press(MouseButton::WheelUp, 0, 0))),
+            KeyInput::new(Key::WheelUp, Mods::NONE),
+        );
+        assert_eq!(
+            KeyInput::from(Event::Unsupported(vec![0x1b, b'[', b'9', b'9', b'~'])),
+            KeyInput::new(Key::Ignored, Mods::NONE),
+        );
+    }
+}
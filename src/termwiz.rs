@@ -1,7 +1,9 @@
 //! Support for [`termwiz`] crate.
 //!
 //! This module provides the conversions from termwiz's event types to [`Key`], [`Mods`],
-//! and [`KeyInput`].
+//! and [`KeyInput`]. The reverse conversion from [`KeyInput`] to termwiz's [`KeyEvent`] is also provided to
+//! synthesize key events. To handle mouse button presses without triggering key bindings repeatedly while dragging,
+//! see [`TermwizEventConverter`].
 //!
 //! ```no_run
 //! use keybinds::{KeyInput, Keybinds};
@@ -52,8 +54,10 @@
 //!     buf.flush().unwrap();
 //! }
 //! ```
-use crate::{Key, KeyInput, Mods};
-use termwiz::input::{InputEvent, KeyCode, KeyEvent, Modifiers};
+use crate::{Error, Key, KeyInput, Mods, Platform, Result};
+use termwiz::input::{
+    InputEvent, KeyCode, KeyEvent, Modifiers, MouseButtons, MouseEvent, PixelMouseEvent,
+};
 
 impl From<KeyCode> for Key {
     fn from(code: KeyCode) -> Self {
@@ -133,6 +137,17 @@ impl From<KeyCode> for Key {
             KeyCode::Cut => Self::Cut,
             KeyCode::Paste => Self::Paste,
             KeyCode::Help => Self::Help,
+            KeyCode::Cancel => Self::Cancel,
+            KeyCode::Select => Self::Select,
+            KeyCode::Print => Self::Print,
+            KeyCode::Execute => Self::Execute,
+            KeyCode::BrowserBack => Self::BrowserBack,
+            KeyCode::BrowserForward => Self::BrowserForward,
+            KeyCode::BrowserRefresh => Self::BrowserRefresh,
+            KeyCode::BrowserStop => Self::BrowserStop,
+            KeyCode::BrowserSearch => Self::BrowserSearch,
+            KeyCode::BrowserFavorites => Self::BrowserFavorites,
+            KeyCode::BrowserHome => Self::BrowserHome,
             KeyCode::VolumeMute => Self::Mute,
             KeyCode::VolumeDown => Self::VolumeDown,
             KeyCode::VolumeUp => Self::VolumeUp,
@@ -140,6 +155,22 @@ impl From<KeyCode> for Key {
             KeyCode::MediaPrevTrack => Self::PrevTrack,
             KeyCode::MediaStop => Self::Stop,
             KeyCode::MediaPlayPause => Self::PlayPause,
+            KeyCode::Numpad0 => Self::Char('0'),
+            KeyCode::Numpad1 => Self::Char('1'),
+            KeyCode::Numpad2 => Self::Char('2'),
+            KeyCode::Numpad3 => Self::Char('3'),
+            KeyCode::Numpad4 => Self::Char('4'),
+            KeyCode::Numpad5 => Self::Char('5'),
+            KeyCode::Numpad6 => Self::Char('6'),
+            KeyCode::Numpad7 => Self::Char('7'),
+            KeyCode::Numpad8 => Self::Char('8'),
+            KeyCode::Numpad9 => Self::Char('9'),
+            KeyCode::Multiply => Self::Char('*'),
+            KeyCode::Add => Self::Char('+'),
+            KeyCode::Separator => Self::Char(','),
+            KeyCode::Subtract => Self::Char('-'),
+            KeyCode::Decimal => Self::Char('.'),
+            KeyCode::Divide => Self::Char('/'),
             KeyCode::KeyPadHome => Self::Home,
             KeyCode::KeyPadEnd => Self::End,
             KeyCode::KeyPadPageUp => Self::PageUp,
@@ -173,21 +204,116 @@ impl From<Modifiers> for Mods {
 }
 
 impl From<&KeyEvent> for KeyInput {
+    /// Convert termwiz's key events to [`KeyInput`]. Keys on the numeric keypad such as `KeyCode::Numpad1` are
+    /// converted with [`Mods::NUMPAD`].
     fn from(event: &KeyEvent) -> Self {
-        Self::new(event.key, event.modifiers)
+        let mut mods = Mods::from(event.modifiers);
+        if is_numpad(event.key) {
+            mods |= Mods::NUMPAD;
+        }
+        Self::new(event.key, mods)
     }
 }
 
+fn is_numpad(code: KeyCode) -> bool {
+    matches!(
+        code,
+        KeyCode::Numpad0
+            | KeyCode::Numpad1
+            | KeyCode::Numpad2
+            | KeyCode::Numpad3
+            | KeyCode::Numpad4
+            | KeyCode::Numpad5
+            | KeyCode::Numpad6
+            | KeyCode::Numpad7
+            | KeyCode::Numpad8
+            | KeyCode::Numpad9
+            | KeyCode::Multiply
+            | KeyCode::Add
+            | KeyCode::Separator
+            | KeyCode::Subtract
+            | KeyCode::Decimal
+            | KeyCode::Divide
+            | KeyCode::KeyPadHome
+            | KeyCode::KeyPadEnd
+            | KeyCode::KeyPadPageUp
+            | KeyCode::KeyPadPageDown
+            | KeyCode::KeyPadBegin
+    )
+}
+
 impl From<KeyEvent> for KeyInput {
     fn from(event: KeyEvent) -> Self {
         Self::from(&event)
     }
 }
 
+fn mouse_input(buttons: &MouseButtons, mods: Modifiers) -> KeyInput {
+    let key = if buttons.contains(MouseButtons::VERT_WHEEL) {
+        if buttons.contains(MouseButtons::WHEEL_POSITIVE) {
+            Key::WheelUp
+        } else {
+            Key::WheelDown
+        }
+    } else if buttons.contains(MouseButtons::HORZ_WHEEL) {
+        if buttons.contains(MouseButtons::WHEEL_POSITIVE) {
+            Key::WheelLeft
+        } else {
+            Key::WheelRight
+        }
+    } else if buttons.contains(MouseButtons::LEFT) {
+        Key::MouseLeft
+    } else if buttons.contains(MouseButtons::RIGHT) {
+        Key::MouseRight
+    } else if buttons.contains(MouseButtons::MIDDLE) {
+        Key::MouseMiddle
+    } else {
+        return Key::Ignored.into();
+    };
+    KeyInput::new(key, mods)
+}
+
+impl From<&MouseEvent> for KeyInput {
+    /// Convert termwiz's mouse events to [`KeyInput`]. Pressed mouse buttons are converted into [`Key::MouseLeft`],
+    /// [`Key::MouseRight`] or [`Key::MouseMiddle`], and wheel movements are converted into [`Key::WheelUp`],
+    /// [`Key::WheelDown`], [`Key::WheelLeft`] or [`Key::WheelRight`]. Mouse events without any button such as
+    /// releasing buttons or moving the cursor are converted into `Key::Ignored` with no modifiers.
+    ///
+    /// Note that termwiz reports the pressed buttons on dragging the cursor as well. It means that dragging with a
+    /// button held is converted into the same key input as pressing the button. Use [`TermwizEventConverter`] to
+    /// convert only the button presses.
+    fn from(event: &MouseEvent) -> Self {
+        mouse_input(&event.mouse_buttons, event.modifiers)
+    }
+}
+
+impl From<MouseEvent> for KeyInput {
+    fn from(event: MouseEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
+impl From<&PixelMouseEvent> for KeyInput {
+    /// Convert termwiz's pixel mouse events to [`KeyInput`] in the same way as [`MouseEvent`].
+    fn from(event: &PixelMouseEvent) -> Self {
+        mouse_input(&event.mouse_buttons, event.modifiers)
+    }
+}
+
+impl From<PixelMouseEvent> for KeyInput {
+    fn from(event: PixelMouseEvent) -> Self {
+        Self::from(&event)
+    }
+}
+
 impl From<&InputEvent> for KeyInput {
+    /// Convert termwiz's input events to [`KeyInput`]. Key events and mouse events are converted in the same way as
+    /// converting [`KeyEvent`] and [`MouseEvent`]. Other events are converted into `Key::Ignored` with no modifiers.
     fn from(event: &InputEvent) -> Self {
         match event {
             InputEvent::Key(event) => event.into(),
+            InputEvent::Mouse(event) => event.into(),
+            InputEvent::PixelMouse(event) => event.into(),
             _ => Key::Ignored.into(),
         }
     }
@@ -199,6 +325,257 @@ impl From<InputEvent> for KeyInput {
     }
 }
 
+const MOUSE_BUTTONS: MouseButtons = MouseButtons::LEFT
+    .union(MouseButtons::RIGHT)
+    .union(MouseButtons::MIDDLE);
+
+/// Converter from termwiz's input events to [`KeyInput`] which tracks the mouse buttons being pressed.
+///
+/// termwiz reports the buttons being held on every mouse event including dragging the cursor, so `KeyInput::from`
+/// converts each event while dragging into a button press and key bindings like `Ctrl+MouseLeft` are triggered
+/// repeatedly. This converter remembers the held buttons and converts mouse events into [`Key::MouseLeft`],
+/// [`Key::MouseRight`] or [`Key::MouseMiddle`] only when the button is newly pressed. Other events are converted in
+/// the same way as `KeyInput::from`.
+///
+/// ```
+/// use keybinds::{Key, KeyInput, Mods};
+/// use keybinds::termwiz::TermwizEventConverter;
+/// use termwiz::input::{InputEvent, Modifiers, MouseButtons, MouseEvent};
+///
+/// let mut converter = TermwizEventConverter::default();
+/// let mouse = |x, mouse_buttons| InputEvent::Mouse(MouseEvent {
+///     x,
+///     y: 0,
+///     mouse_buttons,
+///     modifiers: Modifiers::CTRL,
+/// });
+///
+/// // Press the left button
+/// assert_eq!(
+///     converter.convert(&mouse(0, MouseButtons::LEFT)),
+///     KeyInput::new(Key::MouseLeft, Mods::CTRL),
+/// );
+/// // Drag the cursor with the left button held
+/// assert_eq!(converter.convert(&mouse(1, MouseButtons::LEFT)), KeyInput::from(Key::Ignored));
+/// // Release the left button
+/// assert_eq!(converter.convert(&mouse(1, MouseButtons::NONE)), KeyInput::from(Key::Ignored));
+/// ```
+#[derive(Default)]
+pub struct TermwizEventConverter {
+    pressed: MouseButtons,
+}
+
+impl TermwizEventConverter {
+    /// Convert termwiz's input event into [`KeyInput`]. See [`TermwizEventConverter`] for more details.
+    pub fn convert(&mut self, event: &InputEvent) -> KeyInput {
+        match event {
+            InputEvent::Mouse(event) => self.mouse_input(&event.mouse_buttons, event.modifiers),
+            InputEvent::PixelMouse(event) => {
+                self.mouse_input(&event.mouse_buttons, event.modifiers)
+            }
+            event => event.into(),
+        }
+    }
+
+    fn mouse_input(&mut self, buttons: &MouseButtons, mods: Modifiers) -> KeyInput {
+        // Wheel events do not report the held buttons
+        if buttons.intersects(MouseButtons::VERT_WHEEL | MouseButtons::HORZ_WHEEL) {
+            return mouse_input(buttons, mods);
+        }
+        let held = buttons.clone().intersection(MOUSE_BUTTONS);
+        let pressed = held.clone().difference(self.pressed.clone());
+        self.pressed = held;
+        mouse_input(&pressed, mods)
+    }
+}
+
+// Convert the key on the numeric keypad into termwiz's key code. This is the reverse of `is_numpad`.
+fn numpad_key_code(key: Key) -> Option<KeyCode> {
+    let code = match key {
+        Key::Char('0') => KeyCode::Numpad0,
+        Key::Char('1') => KeyCode::Numpad1,
+        Key::Char('2') => KeyCode::Numpad2,
+        Key::Char('3') => KeyCode::Numpad3,
+        Key::Char('4') => KeyCode::Numpad4,
+        Key::Char('5') => KeyCode::Numpad5,
+        Key::Char('6') => KeyCode::Numpad6,
+        Key::Char('7') => KeyCode::Numpad7,
+        Key::Char('8') => KeyCode::Numpad8,
+        Key::Char('9') => KeyCode::Numpad9,
+        Key::Char('*') => KeyCode::Multiply,
+        Key::Char('+') => KeyCode::Add,
+        Key::Char(',') => KeyCode::Separator,
+        Key::Char('-') => KeyCode::Subtract,
+        Key::Char('.') => KeyCode::Decimal,
+        Key::Char('/') => KeyCode::Divide,
+        Key::Home => KeyCode::KeyPadHome,
+        Key::End => KeyCode::KeyPadEnd,
+        Key::PageUp => KeyCode::KeyPadPageUp,
+        Key::PageDown => KeyCode::KeyPadPageDown,
+        _ => return None,
+    };
+    Some(code)
+}
+
+// Convert the key into termwiz's key code. This is the reverse of `From<KeyCode> for Key`.
+fn key_code(key: Key) -> Option<KeyCode> {
+    let code = match key {
+        Key::Char(c) => KeyCode::Char(c),
+        Key::Backspace => KeyCode::Backspace,
+        Key::Tab => KeyCode::Tab,
+        Key::Clear => KeyCode::Clear,
+        Key::Enter => KeyCode::Enter,
+        Key::Esc => KeyCode::Escape,
+        Key::Menu => KeyCode::Menu,
+        Key::PageUp => KeyCode::PageUp,
+        Key::PageDown => KeyCode::PageDown,
+        Key::End => KeyCode::End,
+        Key::Home => KeyCode::Home,
+        Key::Left => KeyCode::LeftArrow,
+        Key::Right => KeyCode::RightArrow,
+        Key::Up => KeyCode::UpArrow,
+        Key::Down => KeyCode::DownArrow,
+        Key::PrintScreen => KeyCode::PrintScreen,
+        Key::Insert => KeyCode::Insert,
+        Key::Delete => KeyCode::Delete,
+        Key::F1 => KeyCode::Function(1),
+        Key::F2 => KeyCode::Function(2),
+        Key::F3 => KeyCode::Function(3),
+        Key::F4 => KeyCode::Function(4),
+        Key::F5 => KeyCode::Function(5),
+        Key::F6 => KeyCode::Function(6),
+        Key::F7 => KeyCode::Function(7),
+        Key::F8 => KeyCode::Function(8),
+        Key::F9 => KeyCode::Function(9),
+        Key::F10 => KeyCode::Function(10),
+        Key::F11 => KeyCode::Function(11),
+        Key::F12 => KeyCode::Function(12),
+        Key::F13 => KeyCode::Function(13),
+        Key::F14 => KeyCode::Function(14),
+        Key::F15 => KeyCode::Function(15),
+        Key::F16 => KeyCode::Function(16),
+        Key::F17 => KeyCode::Function(17),
+        Key::F18 => KeyCode::Function(18),
+        Key::F19 => KeyCode::Function(19),
+        Key::F20 => KeyCode::Function(20),
+        Key::F21 => KeyCode::Function(21),
+        Key::F22 => KeyCode::Function(22),
+        Key::F23 => KeyCode::Function(23),
+        Key::F24 => KeyCode::Function(24),
+        Key::F25 => KeyCode::Function(25),
+        Key::F26 => KeyCode::Function(26),
+        Key::F27 => KeyCode::Function(27),
+        Key::F28 => KeyCode::Function(28),
+        Key::F29 => KeyCode::Function(29),
+        Key::F30 => KeyCode::Function(30),
+        Key::F31 => KeyCode::Function(31),
+        Key::F32 => KeyCode::Function(32),
+        Key::F33 => KeyCode::Function(33),
+        Key::F34 => KeyCode::Function(34),
+        Key::F35 => KeyCode::Function(35),
+        Key::NumLock => KeyCode::NumLock,
+        Key::ScrollLock => KeyCode::ScrollLock,
+        Key::Copy => KeyCode::Copy,
+        Key::Cut => KeyCode::Cut,
+        Key::Paste => KeyCode::Paste,
+        Key::Help => KeyCode::Help,
+        Key::Cancel => KeyCode::Cancel,
+        Key::Select => KeyCode::Select,
+        Key::Print => KeyCode::Print,
+        Key::Execute => KeyCode::Execute,
+        Key::BrowserBack => KeyCode::BrowserBack,
+        Key::BrowserForward => KeyCode::BrowserForward,
+        Key::BrowserRefresh => KeyCode::BrowserRefresh,
+        Key::BrowserStop => KeyCode::BrowserStop,
+        Key::BrowserSearch => KeyCode::BrowserSearch,
+        Key::BrowserFavorites => KeyCode::BrowserFavorites,
+        Key::BrowserHome => KeyCode::BrowserHome,
+        Key::Mute => KeyCode::VolumeMute,
+        Key::VolumeDown => KeyCode::VolumeDown,
+        Key::VolumeUp => KeyCode::VolumeUp,
+        Key::NextTrack => KeyCode::MediaNextTrack,
+        Key::PrevTrack => KeyCode::MediaPrevTrack,
+        Key::Stop => KeyCode::MediaStop,
+        Key::PlayPause => KeyCode::MediaPlayPause,
+        _ => return None,
+    };
+    Some(code)
+}
+
+impl TryFrom<KeyInput> for KeyEvent {
+    type Error = Error;
+
+    /// Convert [`KeyInput`] into termwiz's key event. Keys with [`Mods::NUMPAD`] are converted into the key codes on
+    /// the numeric keypad such as `KeyCode::Numpad1`. termwiz does not report the lock states, the left and right
+    /// modifiers nor physical keys so they are dropped. [`Mods::META`], [`Mods::HYPER`] and [`Mods::ALTGR`] cause
+    /// [`Error::UnconvertibleInput`]. See the error for the common behavior of the conversions.
+    ///
+    /// ```
+    /// use keybinds::{Key, KeyInput, Mods};
+    /// use termwiz::input::{KeyCode, KeyEvent, Modifiers};
+    ///
+    /// let input = KeyInput::new('1', Mods::CTRL | Mods::NUMPAD);
+    /// let event = KeyEvent::try_from(input).unwrap();
+    /// assert_eq!(event.key, KeyCode::Numpad1);
+    /// assert_eq!(event.modifiers, Modifiers::CTRL);
+    /// assert_eq!(KeyInput::from(event), input);
+    ///
+    /// // termwiz has no Meta modifier
+    /// assert!(KeyEvent::try_from(KeyInput::new(Key::Up, Mods::META)).is_err());
+    /// ```
+    fn try_from(input: KeyInput) -> Result<Self> {
+        to_key_event(input, Platform::current())
+    }
+}
+
+/// Convert [`KeyInput`] into termwiz's key event on the given platform. See [`Platform`] for which to pass.
+///
+/// ```
+/// use keybinds::termwiz::to_key_event;
+/// use keybinds::{KeyInput, Mods, Platform};
+/// use termwiz::input::{KeyCode, Modifiers};
+///
+/// let input = KeyInput::new('s', Mods::MOD);
+/// let event = to_key_event(input, Platform::MacOS).unwrap();
+/// assert_eq!(event.key, KeyCode::Char('s'));
+/// assert_eq!(event.modifiers, Modifiers::SUPER);
+///
+/// let event = to_key_event(input, Platform::Linux).unwrap();
+/// assert_eq!(event.modifiers, Modifiers::CTRL);
+/// ```
+pub fn to_key_event(input: KeyInput, platform: Platform) -> Result<KeyEvent> {
+    let unconvertible = || Error::UnconvertibleInput(input);
+    let mods = input.physical_mods().resolve(platform);
+    if mods.intersects(Mods::META | Mods::HYPER | Mods::ALTGR) {
+        return Err(unconvertible());
+    }
+
+    let mut modifiers = Modifiers::NONE;
+    for (from, to) in [
+        (Mods::CTRL, Modifiers::CTRL),
+        (Mods::ALT, Modifiers::ALT),
+        (Mods::CMD, Modifiers::SUPER),
+        (Mods::WIN, Modifiers::SUPER),
+        (Mods::SHIFT, Modifiers::SHIFT),
+    ] {
+        if mods.contains(from) {
+            modifiers |= to;
+        }
+    }
+
+    let key = input.key();
+    let numpad = if mods.contains(Mods::NUMPAD) {
+        numpad_key_code(key)
+    } else {
+        None
+    };
+    let key = match numpad {
+        Some(code) => code,
+        None => key_code(key).ok_or_else(unconvertible)?,
+    };
+    Ok(KeyEvent { key, modifiers })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,6 +587,10 @@ mod tests {
         assert_eq!(Key::from(KeyCode::UpArrow), Key::Up);
         assert_eq!(Key::from(KeyCode::Control), Key::Ignored);
         assert_eq!(Key::from(KeyCode::Sleep), Key::Unidentified);
+        assert_eq!(Key::from(KeyCode::Numpad1), Key::Char('1'));
+        assert_eq!(Key::from(KeyCode::Add), Key::Char('+'));
+        assert_eq!(Key::from(KeyCode::BrowserBack), Key::BrowserBack);
+        assert_eq!(Key::from(KeyCode::Execute), Key::Execute);
     }
 
     #[test]
@@ -234,6 +615,236 @@ mod tests {
         });
         let expected = KeyInput::new('A', Mods::CTRL | Mods::ALT);
         assert_eq!(actual, expected);
+
+        let actual = KeyInput::from(KeyEvent {
+            key: KeyCode::Numpad5,
+            modifiers: Modifiers::CTRL,
+        });
+        let expected = KeyInput::new('5', Mods::CTRL | Mods::NUMPAD);
+        assert_eq!(actual, expected);
+
+        let actual = KeyInput::from(KeyEvent {
+            key: KeyCode::KeyPadHome,
+            modifiers: Modifiers::NONE,
+        });
+        let expected = KeyInput::new(Key::Home, Mods::NUMPAD);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn convert_key_input_to_key_event() {
+        let event = KeyEvent::try_from(KeyInput::new('A', Mods::CTRL | Mods::ALT)).unwrap();
+        assert_eq!(event.key, KeyCode::Char('A'));
+        assert_eq!(event.modifiers, Modifiers::CTRL | Modifiers::ALT);
+
+        // Sides of modifiers and physical key code are dropped
+        let input = KeyInput::with_code('x', Mods::LCTRL | Mods::RALT, crate::Code::KeyX);
+        let event = KeyEvent::try_from(input).unwrap();
+        assert_eq!(event.key, KeyCode::Char('x'));
+        assert_eq!(event.modifiers, Modifiers::CTRL | Modifiers::ALT);
+
+        let event = KeyEvent::try_from(KeyInput::new('5', Mods::NUMPAD)).unwrap();
+        assert_eq!(event.key, KeyCode::Numpad5);
+        assert_eq!(event.modifiers, Modifiers::NONE);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Home, Mods::NUMPAD)).unwrap();
+        assert_eq!(event.key, KeyCode::KeyPadHome);
+
+        // Keys which are not on the numeric keypad
+        let event = KeyEvent::try_from(KeyInput::new(Key::Enter, Mods::NUMPAD)).unwrap();
+        assert_eq!(event.key, KeyCode::Enter);
+
+        let event = KeyEvent::try_from(KeyInput::new(Key::Esc, Mods::SUPER)).unwrap();
+        assert_eq!(event.key, KeyCode::Escape);
+        assert_eq!(event.modifiers, Modifiers::SUPER);
+
+        let event = KeyEvent::try_from(KeyInput::new('x', Mods::CTRL | Mods::CAPS_LOCK)).unwrap();
+        assert_eq!(event.modifiers, Modifiers::CTRL);
+    }
+
+    #[test]
+    fn convert_key_input_to_key_event_on_platform() {
+        for (platform, expected) in [
+            (Platform::MacOS, Modifiers::SUPER),
+            (Platform::Linux, Modifiers::CTRL),
+            (Platform::Windows, Modifiers::CTRL),
+        ] {
+            let event = to_key_event(KeyInput::new('a', Mods::MOD), platform).unwrap();
+            assert_eq!(event.modifiers, expected, "{platform:?}");
+        }
+        let input = KeyInput::new('a', Mods::MOD);
+        assert_eq!(
+            KeyEvent::try_from(input),
+            to_key_event(input, Platform::current()),
+        );
+    }
+
+    #[test]
+    fn convert_numpad_key_input_to_key_event() {
+        for (input, key) in [
+            (KeyInput::new('0', Mods::NUMPAD), KeyCode::Numpad0),
+            (KeyInput::new('9', Mods::NUMPAD), KeyCode::Numpad9),
+            (
+                KeyInput::new('/', Mods::CTRL | Mods::NUMPAD),
+                KeyCode::Divide,
+            ),
+            (
+                KeyInput::new(Key::PageDown, Mods::NUMPAD),
+                KeyCode::KeyPadPageDown,
+            ),
+            (KeyInput::new(Key::End, Mods::NUMPAD), KeyCode::KeyPadEnd),
+        ] {
+            let event = KeyEvent::try_from(input).unwrap();
+            assert_eq!(event.key, key, "{input:?}");
+            assert_eq!(KeyInput::from(&event), input, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn convert_unconvertible_key_input() {
+        for input in [
+            // Modifiers which termwiz cannot represent
+            KeyInput::new('x', Mods::META),
+            KeyInput::new('x', Mods::HYPER),
+            KeyInput::new('@', Mods::ALTGR),
+            // Keys which termwiz has no key code for
+            KeyInput::new(Key::Play, Mods::NONE),
+            KeyInput::new(Key::MouseLeft, Mods::NONE),
+        ] {
+            assert_eq!(
+                KeyEvent::try_from(input),
+                Err(Error::UnconvertibleInput(input)),
+                "{input:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn key_code_round_trip() {
+        for key in [
+            KeyCode::Backspace,
+            KeyCode::Char('x'),
+            KeyCode::Function(12),
+            KeyCode::LeftArrow,
+            KeyCode::KeyPadEnd,
+            KeyCode::BrowserHome,
+            KeyCode::MediaNextTrack,
+            KeyCode::MediaPlayPause,
+            KeyCode::VolumeMute,
+        ] {
+            let event = KeyEvent {
+                key,
+                modifiers: Modifiers::ALT | Modifiers::SUPER,
+            };
+            let input = KeyInput::from(&event);
+            assert_eq!(KeyEvent::try_from(input), Ok(event), "{key:?}");
+        }
+    }
+
+    #[test]
+    fn convert_mouse_event() {
+        let mouse = |mouse_buttons, modifiers| MouseEvent {
+            x: 3,
+            y: 5,
+            mouse_buttons,
+            modifiers,
+        };
+        for (buttons, key) in [
+            (MouseButtons::LEFT, Key::MouseLeft),
+            (MouseButtons::RIGHT, Key::MouseRight),
+            (MouseButtons::MIDDLE, Key::MouseMiddle),
+            (
+                MouseButtons::VERT_WHEEL | MouseButtons::WHEEL_POSITIVE,
+                Key::WheelUp,
+            ),
+            (MouseButtons::VERT_WHEEL, Key::WheelDown),
+            (
+                MouseButtons::HORZ_WHEEL | MouseButtons::WHEEL_POSITIVE,
+                Key::WheelLeft,
+            ),
+            (MouseButtons::HORZ_WHEEL, Key::WheelRight),
+        ] {
+            assert_eq!(
+                KeyInput::from(mouse(buttons.clone(), Modifiers::CTRL)),
+                KeyInput::new(key, Mods::CTRL),
+                "buttons={buttons:?}",
+            );
+        }
+        assert_eq!(
+            KeyInput::from(mouse(MouseButtons::NONE, Modifiers::CTRL)),
+            KeyInput::from(Key::Ignored),
+        );
+
+        let input = KeyInput::from(PixelMouseEvent {
+            x_pixels: 30,
+            y_pixels: 50,
+            mouse_buttons: MouseButtons::LEFT,
+            modifiers: Modifiers::SHIFT,
+        });
+        assert_eq!(input, KeyInput::new(Key::MouseLeft, Mods::SHIFT));
+    }
+
+    #[test]
+    fn converter_mouse_event() {
+        let mouse = |mouse_buttons, modifiers| {
+            InputEvent::Mouse(MouseEvent {
+                x: 3,
+                y: 5,
+                mouse_buttons,
+                modifiers,
+            })
+        };
+        let ignored = KeyInput::from(Key::Ignored);
+        let mut conv = TermwizEventConverter::default();
+
+        for (buttons, mods, expected) in [
+            (
+                MouseButtons::LEFT,
+                Modifiers::CTRL,
+                KeyInput::new(Key::MouseLeft, Mods::CTRL),
+            ),
+            // Dragging
+            (MouseButtons::LEFT, Modifiers::CTRL, ignored),
+            (MouseButtons::LEFT, Modifiers::NONE, ignored),
+            (
+                MouseButtons::VERT_WHEEL,
+                Modifiers::CTRL,
+                KeyInput::new(Key::WheelDown, Mods::CTRL),
+            ),
+            (MouseButtons::LEFT, Modifiers::CTRL, ignored),
+            (
+                MouseButtons::LEFT | MouseButtons::RIGHT,
+                Modifiers::NONE,
+                KeyInput::new(Key::MouseRight, Mods::NONE),
+            ),
+            (MouseButtons::RIGHT, Modifiers::NONE, ignored),
+            (MouseButtons::NONE, Modifiers::NONE, ignored),
+            (
+                MouseButtons::LEFT,
+                Modifiers::SHIFT,
+                KeyInput::new(Key::MouseLeft, Mods::SHIFT),
+            ),
+        ] {
+            assert_eq!(
+                conv.convert(&mouse(buttons.clone(), mods)),
+                expected,
+                "buttons={buttons:?} mods={mods:?}",
+            );
+        }
+
+        let input = conv.convert(&InputEvent::PixelMouse(PixelMouseEvent {
+            x_pixels: 30,
+            y_pixels: 50,
+            mouse_buttons: MouseButtons::LEFT,
+            modifiers: Modifiers::SHIFT,
+        }));
+        assert_eq!(input, ignored);
+
+        let input = conv.convert(&InputEvent::Key(KeyEvent {
+            key: KeyCode::Char('a'),
+            modifiers: Modifiers::CTRL,
+        }));
+        assert_eq!(input, KeyInput::new('a', Mods::CTRL));
     }
 
     #[test]
@@ -244,6 +855,14 @@ mod tests {
         }));
         assert_eq!(input, KeyInput::new('A', Mods::CTRL | Mods::ALT));
 
+        let input = KeyInput::from(InputEvent::Mouse(MouseEvent {
+            x: 0,
+            y: 0,
+            mouse_buttons: MouseButtons::VERT_WHEEL | MouseButtons::WHEEL_POSITIVE,
+            modifiers: Modifiers::ALT,
+        }));
+        assert_eq!(input, KeyInput::new(Key::WheelUp, Mods::ALT));
+
         let input = KeyInput::from(InputEvent::Resized { cols: 80, rows: 24 });
         assert_eq!(input, KeyInput::from(Key::Ignored));
     }
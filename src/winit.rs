@@ -4,6 +4,7 @@
 //!
 //! - the conversion from winit's key and modifier types to [`Key`] and [`Mods`]
 //! - [`WinitEventConverter`] struct to track the modifier state and converts key events to [`KeyInput`]
+//! - [`KeybindsHandler`] struct to wrap [`ApplicationHandler`] and dispatch actions for each window
 //!
 //! ```no_run
 //! use keybinds::winit::WinitEventConverter;
@@ -70,9 +71,18 @@
 //! let event_loop = EventLoop::new().unwrap();
 //! event_loop.run_app(&mut App::default()).unwrap();
 //! ```
-use crate::{Key, KeyInput, Mods};
-use winit::event::{ElementState, Event, KeyEvent, Modifiers, WindowEvent};
-use winit::keyboard::{Key as WinitKey, ModifiersState, NamedKey};
+use crate::{Code, Key, KeyInput, Keybinds, LatinFallback, Mods, Platform};
+use std::collections::{HashMap, HashSet};
+use winit::application::ApplicationHandler;
+use winit::event::{
+    DeviceEvent, DeviceId, ElementState, Event, KeyEvent, Modifiers, MouseButton, MouseScrollDelta,
+    StartCause, WindowEvent,
+};
+use winit::event_loop::ActiveEventLoop;
+use winit::keyboard::{
+    Key as WinitKey, KeyCode, KeyLocation, ModifiersKeyState, ModifiersState, NamedKey, PhysicalKey,
+};
+use winit::window::WindowId;
 
 impl From<&WinitKey> for Key {
     fn from(key: &WinitKey) -> Self {
@@ -100,6 +110,57 @@ impl From<&WinitKey> for Key {
                 NamedKey::Undo => Self::Undo,
                 NamedKey::Redo => Self::Redo,
                 NamedKey::Help => Self::Help,
+                NamedKey::Accept => Self::Accept,
+                NamedKey::Again => Self::Again,
+                NamedKey::Attn => Self::Attn,
+                NamedKey::Cancel => Self::Cancel,
+                NamedKey::Execute => Self::Execute,
+                NamedKey::Find => Self::Find,
+                NamedKey::Props => Self::Props,
+                NamedKey::Select => Self::Select,
+                NamedKey::CrSel => Self::CrSel,
+                NamedKey::EraseEof => Self::EraseEof,
+                NamedKey::ExSel => Self::ExSel,
+                NamedKey::BrightnessDown => Self::BrightnessDown,
+                NamedKey::BrightnessUp => Self::BrightnessUp,
+                NamedKey::Eject => Self::Eject,
+                NamedKey::LogOff => Self::LogOff,
+                NamedKey::Power => Self::Power,
+                NamedKey::PowerOff => Self::PowerOff,
+                NamedKey::Hibernate => Self::Hibernate,
+                NamedKey::Standby => Self::Standby,
+                NamedKey::WakeUp => Self::WakeUp,
+                NamedKey::MediaFastForward => Self::FastForward,
+                NamedKey::MediaRecord => Self::Record,
+                NamedKey::BrowserBack => Self::BrowserBack,
+                NamedKey::BrowserFavorites => Self::BrowserFavorites,
+                NamedKey::BrowserForward => Self::BrowserForward,
+                NamedKey::BrowserHome => Self::BrowserHome,
+                NamedKey::BrowserRefresh => Self::BrowserRefresh,
+                NamedKey::BrowserSearch => Self::BrowserSearch,
+                NamedKey::BrowserStop => Self::BrowserStop,
+                NamedKey::LaunchApplication1 => Self::LaunchApplication1,
+                NamedKey::LaunchApplication2 => Self::LaunchApplication2,
+                NamedKey::LaunchCalendar => Self::LaunchCalendar,
+                NamedKey::LaunchContacts => Self::LaunchContacts,
+                NamedKey::LaunchMail => Self::LaunchMail,
+                NamedKey::LaunchMediaPlayer => Self::LaunchMediaPlayer,
+                NamedKey::LaunchMusicPlayer => Self::LaunchMusicPlayer,
+                NamedKey::LaunchPhone => Self::LaunchPhone,
+                NamedKey::LaunchScreenSaver => Self::LaunchScreenSaver,
+                NamedKey::LaunchSpreadsheet => Self::LaunchSpreadsheet,
+                NamedKey::LaunchWebBrowser => Self::LaunchWebBrowser,
+                NamedKey::LaunchWebCam => Self::LaunchWebCam,
+                NamedKey::LaunchWordProcessor => Self::LaunchWordProcessor,
+                NamedKey::Close => Self::Close,
+                NamedKey::New => Self::New,
+                NamedKey::Open => Self::Open,
+                NamedKey::Print => Self::Print,
+                NamedKey::Save => Self::Save,
+                NamedKey::SpellCheck => Self::SpellCheck,
+                NamedKey::MailForward => Self::MailForward,
+                NamedKey::MailReply => Self::MailReply,
+                NamedKey::MailSend => Self::MailSend,
                 NamedKey::ZoomIn => Self::ZoomIn,
                 NamedKey::ZoomOut => Self::ZoomOut,
                 NamedKey::ZoomToggle => Self::ZoomToggle,
@@ -153,6 +214,7 @@ impl From<&WinitKey> for Key {
                 NamedKey::F34 => Self::F34,
                 NamedKey::F35 => Self::F35,
                 NamedKey::Alt
+                | NamedKey::AltGraph
                 | NamedKey::Control
                 | NamedKey::Shift
                 | NamedKey::Super
@@ -161,14 +223,7 @@ impl From<&WinitKey> for Key {
                 | NamedKey::Symbol => Self::Ignored,
                 _ => Self::Unidentified,
             },
-            WinitKey::Character(s) => {
-                let mut chars = s.chars();
-                if let (Some(c), None) = (chars.next(), chars.next()) {
-                    Self::Char(c)
-                } else {
-                    Self::Unidentified
-                }
-            }
+            WinitKey::Character(s) => Self::from_text(s),
             _ => Self::Unidentified,
         }
     }
@@ -180,6 +235,208 @@ impl From<WinitKey> for Key {
     }
 }
 
+impl From<&PhysicalKey> for Key {
+    /// Convert winit's physical key into [`Key::Physical`]. Physical keys which cannot be represented with [`Code`]
+    /// are converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::{Key, Code};
+    /// use winit::keyboard::{KeyCode, PhysicalKey};
+    ///
+    /// assert_eq!(Key::from(PhysicalKey::Code(KeyCode::KeyW)), Key::Physical(Code::KeyW));
+    /// assert_eq!(Key::from(PhysicalKey::Code(KeyCode::Fn)), Key::Unidentified);
+    /// ```
+    fn from(key: &PhysicalKey) -> Self {
+        match key {
+            PhysicalKey::Code(code) => match code {
+                KeyCode::Backquote => Self::Physical(Code::Backquote),
+                KeyCode::Backslash => Self::Physical(Code::Backslash),
+                KeyCode::BracketLeft => Self::Physical(Code::BracketLeft),
+                KeyCode::BracketRight => Self::Physical(Code::BracketRight),
+                KeyCode::Comma => Self::Physical(Code::Comma),
+                KeyCode::Digit0 => Self::Physical(Code::Digit0),
+                KeyCode::Digit1 => Self::Physical(Code::Digit1),
+                KeyCode::Digit2 => Self::Physical(Code::Digit2),
+                KeyCode::Digit3 => Self::Physical(Code::Digit3),
+                KeyCode::Digit4 => Self::Physical(Code::Digit4),
+                KeyCode::Digit5 => Self::Physical(Code::Digit5),
+                KeyCode::Digit6 => Self::Physical(Code::Digit6),
+                KeyCode::Digit7 => Self::Physical(Code::Digit7),
+                KeyCode::Digit8 => Self::Physical(Code::Digit8),
+                KeyCode::Digit9 => Self::Physical(Code::Digit9),
+                KeyCode::Equal => Self::Physical(Code::Equal),
+                KeyCode::IntlBackslash => Self::Physical(Code::IntlBackslash),
+                KeyCode::IntlRo => Self::Physical(Code::IntlRo),
+                KeyCode::IntlYen => Self::Physical(Code::IntlYen),
+                KeyCode::KeyA => Self::Physical(Code::KeyA),
+                KeyCode::KeyB => Self::Physical(Code::KeyB),
+                KeyCode::KeyC => Self::Physical(Code::KeyC),
+                KeyCode::KeyD => Self::Physical(Code::KeyD),
+                KeyCode::KeyE => Self::Physical(Code::KeyE),
+                KeyCode::KeyF => Self::Physical(Code::KeyF),
+                KeyCode::KeyG => Self::Physical(Code::KeyG),
+                KeyCode::KeyH => Self::Physical(Code::KeyH),
+                KeyCode::KeyI => Self::Physical(Code::KeyI),
+                KeyCode::KeyJ => Self::Physical(Code::KeyJ),
+                KeyCode::KeyK => Self::Physical(Code::KeyK),
+                KeyCode::KeyL => Self::Physical(Code::KeyL),
+                KeyCode::KeyM => Self::Physical(Code::KeyM),
+                KeyCode::KeyN => Self::Physical(Code::KeyN),
+                KeyCode::KeyO => Self::Physical(Code::KeyO),
+                KeyCode::KeyP => Self::Physical(Code::KeyP),
+                KeyCode::KeyQ => Self::Physical(Code::KeyQ),
+                KeyCode::KeyR => Self::Physical(Code::KeyR),
+                KeyCode::KeyS => Self::Physical(Code::KeyS),
+                KeyCode::KeyT => Self::Physical(Code::KeyT),
+                KeyCode::KeyU => Self::Physical(Code::KeyU),
+                KeyCode::KeyV => Self::Physical(Code::KeyV),
+                KeyCode::KeyW => Self::Physical(Code::KeyW),
+                KeyCode::KeyX => Self::Physical(Code::KeyX),
+                KeyCode::KeyY => Self::Physical(Code::KeyY),
+                KeyCode::KeyZ => Self::Physical(Code::KeyZ),
+                KeyCode::Minus => Self::Physical(Code::Minus),
+                KeyCode::Period => Self::Physical(Code::Period),
+                KeyCode::Quote => Self::Physical(Code::Quote),
+                KeyCode::Semicolon => Self::Physical(Code::Semicolon),
+                KeyCode::Slash => Self::Physical(Code::Slash),
+                KeyCode::AltLeft => Self::Physical(Code::AltLeft),
+                KeyCode::AltRight => Self::Physical(Code::AltRight),
+                KeyCode::Backspace => Self::Physical(Code::Backspace),
+                KeyCode::CapsLock => Self::Physical(Code::CapsLock),
+                KeyCode::ContextMenu => Self::Physical(Code::ContextMenu),
+                KeyCode::ControlLeft => Self::Physical(Code::ControlLeft),
+                KeyCode::ControlRight => Self::Physical(Code::ControlRight),
+                KeyCode::Enter => Self::Physical(Code::Enter),
+                KeyCode::SuperLeft => Self::Physical(Code::MetaLeft),
+                KeyCode::SuperRight => Self::Physical(Code::MetaRight),
+                KeyCode::ShiftLeft => Self::Physical(Code::ShiftLeft),
+                KeyCode::ShiftRight => Self::Physical(Code::ShiftRight),
+                KeyCode::Space => Self::Physical(Code::Space),
+                KeyCode::Tab => Self::Physical(Code::Tab),
+                KeyCode::Convert => Self::Physical(Code::Convert),
+                KeyCode::KanaMode => Self::Physical(Code::KanaMode),
+                KeyCode::Lang1 => Self::Physical(Code::Lang1),
+                KeyCode::Lang2 => Self::Physical(Code::Lang2),
+                KeyCode::NonConvert => Self::Physical(Code::NonConvert),
+                KeyCode::Delete => Self::Physical(Code::Delete),
+                KeyCode::End => Self::Physical(Code::End),
+                KeyCode::Help => Self::Physical(Code::Help),
+                KeyCode::Home => Self::Physical(Code::Home),
+                KeyCode::Insert => Self::Physical(Code::Insert),
+                KeyCode::PageDown => Self::Physical(Code::PageDown),
+                KeyCode::PageUp => Self::Physical(Code::PageUp),
+                KeyCode::ArrowDown => Self::Physical(Code::ArrowDown),
+                KeyCode::ArrowLeft => Self::Physical(Code::ArrowLeft),
+                KeyCode::ArrowRight => Self::Physical(Code::ArrowRight),
+                KeyCode::ArrowUp => Self::Physical(Code::ArrowUp),
+                KeyCode::NumLock => Self::Physical(Code::NumLock),
+                KeyCode::Numpad0 => Self::Physical(Code::Numpad0),
+                KeyCode::Numpad1 => Self::Physical(Code::Numpad1),
+                KeyCode::Numpad2 => Self::Physical(Code::Numpad2),
+                KeyCode::Numpad3 => Self::Physical(Code::Numpad3),
+                KeyCode::Numpad4 => Self::Physical(Code::Numpad4),
+                KeyCode::Numpad5 => Self::Physical(Code::Numpad5),
+                KeyCode::Numpad6 => Self::Physical(Code::Numpad6),
+                KeyCode::Numpad7 => Self::Physical(Code::Numpad7),
+                KeyCode::Numpad8 => Self::Physical(Code::Numpad8),
+                KeyCode::Numpad9 => Self::Physical(Code::Numpad9),
+                KeyCode::NumpadAdd => Self::Physical(Code::NumpadAdd),
+                KeyCode::NumpadComma => Self::Physical(Code::NumpadComma),
+                KeyCode::NumpadDecimal => Self::Physical(Code::NumpadDecimal),
+                KeyCode::NumpadDivide => Self::Physical(Code::NumpadDivide),
+                KeyCode::NumpadEnter => Self::Physical(Code::NumpadEnter),
+                KeyCode::NumpadEqual => Self::Physical(Code::NumpadEqual),
+                KeyCode::NumpadMultiply => Self::Physical(Code::NumpadMultiply),
+                KeyCode::NumpadSubtract => Self::Physical(Code::NumpadSubtract),
+                KeyCode::Escape => Self::Physical(Code::Escape),
+                KeyCode::F1 => Self::Physical(Code::F1),
+                KeyCode::F2 => Self::Physical(Code::F2),
+                KeyCode::F3 => Self::Physical(Code::F3),
+                KeyCode::F4 => Self::Physical(Code::F4),
+                KeyCode::F5 => Self::Physical(Code::F5),
+                KeyCode::F6 => Self::Physical(Code::F6),
+                KeyCode::F7 => Self::Physical(Code::F7),
+                KeyCode::F8 => Self::Physical(Code::F8),
+                KeyCode::F9 => Self::Physical(Code::F9),
+                KeyCode::F10 => Self::Physical(Code::F10),
+                KeyCode::F11 => Self::Physical(Code::F11),
+                KeyCode::F12 => Self::Physical(Code::F12),
+                KeyCode::F13 => Self::Physical(Code::F13),
+                KeyCode::F14 => Self::Physical(Code::F14),
+                KeyCode::F15 => Self::Physical(Code::F15),
+                KeyCode::F16 => Self::Physical(Code::F16),
+                KeyCode::F17 => Self::Physical(Code::F17),
+                KeyCode::F18 => Self::Physical(Code::F18),
+                KeyCode::F19 => Self::Physical(Code::F19),
+                KeyCode::F20 => Self::Physical(Code::F20),
+                KeyCode::F21 => Self::Physical(Code::F21),
+                KeyCode::F22 => Self::Physical(Code::F22),
+                KeyCode::F23 => Self::Physical(Code::F23),
+                KeyCode::F24 => Self::Physical(Code::F24),
+                KeyCode::PrintScreen => Self::Physical(Code::PrintScreen),
+                KeyCode::ScrollLock => Self::Physical(Code::ScrollLock),
+                KeyCode::Pause => Self::Physical(Code::Pause),
+                _ => Self::Unidentified,
+            },
+            PhysicalKey::Unidentified(_) => Self::Unidentified,
+        }
+    }
+}
+
+impl From<PhysicalKey> for Key {
+    fn from(key: PhysicalKey) -> Self {
+        Self::from(&key)
+    }
+}
+
+impl From<MouseButton> for Key {
+    /// Convert winit's mouse button into [`Key`]. [`MouseButton::Other`] is converted into [`Key::Unidentified`].
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use winit::event::MouseButton;
+    ///
+    /// assert_eq!(Key::from(MouseButton::Left), Key::MouseLeft);
+    /// assert_eq!(Key::from(MouseButton::Other(8)), Key::Unidentified);
+    /// ```
+    fn from(button: MouseButton) -> Self {
+        match button {
+            MouseButton::Left => Self::MouseLeft,
+            MouseButton::Right => Self::MouseRight,
+            MouseButton::Middle => Self::MouseMiddle,
+            MouseButton::Back => Self::MouseBack,
+            MouseButton::Forward => Self::MouseForward,
+            MouseButton::Other(_) => Self::Unidentified,
+        }
+    }
+}
+
+impl From<&MouseScrollDelta> for Key {
+    /// Convert winit's scroll amount into one of [`Key::WheelUp`], [`Key::WheelDown`], [`Key::WheelLeft`] and
+    /// [`Key::WheelRight`]. The direction of the larger amount is chosen. No scroll is converted into [`Key::Ignored`].
+    ///
+    /// ```
+    /// use keybinds::Key;
+    /// use winit::event::MouseScrollDelta;
+    ///
+    /// assert_eq!(Key::from(MouseScrollDelta::LineDelta(0.0, 1.0)), Key::WheelUp);
+    /// assert_eq!(Key::from(MouseScrollDelta::LineDelta(-2.0, 0.5)), Key::WheelRight);
+    /// assert_eq!(Key::from(MouseScrollDelta::LineDelta(0.0, 0.0)), Key::Ignored);
+    /// ```
+    fn from(delta: &MouseScrollDelta) -> Self {
+        match delta {
+            MouseScrollDelta::LineDelta(x, y) => Self::from_wheel_delta((*x).into(), (*y).into()),
+            MouseScrollDelta::PixelDelta(pos) => Self::from_wheel_delta(pos.x, pos.y),
+        }
+    }
+}
+
+impl From<MouseScrollDelta> for Key {
+    fn from(delta: MouseScrollDelta) -> Self {
+        Self::from(&delta)
+    }
+}
+
 impl From<&ModifiersState> for Mods {
     fn from(state: &ModifiersState) -> Self {
         let mut mods = Mods::NONE;
@@ -206,8 +463,23 @@ impl From<ModifiersState> for Mods {
 }
 
 impl From<&Modifiers> for Mods {
+    /// Convert winit's modifiers into [`Mods`]. When winit reports which side of the modifier key is pressed, the
+    /// side-aware modifiers such as [`Mods::RALT`] are set. Note that some platforms do not report the sides.
     fn from(mods: &Modifiers) -> Self {
-        Self::from(mods.state())
+        let mut ret = Self::from(mods.state());
+        for (state, side) in [
+            (mods.lcontrol_state(), Mods::LCTRL),
+            (mods.rcontrol_state(), Mods::RCTRL),
+            (mods.lalt_state(), Mods::LALT),
+            (mods.ralt_state(), Mods::RALT),
+            (mods.lshift_state(), Mods::LSHIFT),
+            (mods.rshift_state(), Mods::RSHIFT),
+        ] {
+            if state == ModifiersKeyState::Pressed {
+                ret |= side;
+            }
+        }
+        ret
     }
 }
 
@@ -222,7 +494,13 @@ pub trait WinitEvent {
 
 impl WinitEvent for KeyEvent {
     fn to_key_input(&self, conv: &mut WinitEventConverter) -> KeyInput {
-        KeyInput::new(Key::from(&self.logical_key), conv.mods)
+        if self.logical_key == WinitKey::Named(NamedKey::AltGraph) {
+            conv.altgr = self.state == ElementState::Pressed;
+        }
+        if self.state == ElementState::Released {
+            return Key::Ignored.into();
+        }
+        conv.key_input(&self.logical_key, &self.physical_key, self.location)
     }
 }
 
@@ -233,9 +511,16 @@ impl WinitEvent for WindowEvent {
                 conv.on_modifiers_changed(mods);
                 Key::Ignored.into()
             }
-            WindowEvent::KeyboardInput { event, .. } if event.state == ElementState::Pressed => {
-                event.to_key_input(conv)
-            }
+            WindowEvent::KeyboardInput { event, .. } => event.to_key_input(conv),
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button,
+                ..
+            } => KeyInput::new(*button, conv.mods()),
+            WindowEvent::MouseWheel { delta, .. } => match Key::from(delta) {
+                Key::Ignored => Key::Ignored.into(),
+                key => KeyInput::new(key, conv.mods()),
+            },
             _ => Key::Ignored.into(),
         }
     }
@@ -291,13 +576,32 @@ impl<T> WinitEvent for Event<T> {
 /// # let event = Dummy(winit::keyboard::Key::Character("x".into()));
 /// assert_eq!(converter.convert(&event), KeyInput::new('x', Mods::CTRL));
 /// ```
+///
+/// When a non-Latin keyboard layout such as Russian is active, the non-Latin characters typed with modifiers are
+/// converted into the Latin characters at the same key position by default so that key bindings like `Ctrl+c` work.
+/// See [`WinitEventConverter::set_latin_fallback`] to change the behavior.
+///
+/// While <kbd>AltGr</kbd> key is pressed, the modifiers are converted into [`Mods::ALTGR`] instead of
+/// <kbd>Ctrl</kbd> + <kbd>Alt</kbd> which some platforms like Windows report for <kbd>AltGr</kbd>. To track the
+/// <kbd>AltGr</kbd> key, the key release events also need to be passed to [`WinitEventConverter::convert`]. They are
+/// converted into `Key::Ignored`.
+///
+/// Keys on the numeric keypad are converted with [`Mods::NUMPAD`] so that key bindings like `Numpad+Enter` can
+/// distinguish them from the keys on the main area.
+///
+/// `WindowEvent::MouseInput` and `WindowEvent::MouseWheel` events are converted into the mouse keys such as
+/// [`Key::MouseLeft`] and [`Key::WheelUp`] with the current modifiers state so that key bindings like `Ctrl+MouseLeft`
+/// work. Releasing mouse buttons is converted into `Key::Ignored`. Note that touchpads may report many small scroll
+/// events for one gesture and each of them is converted into a wheel key.
 #[derive(Default)]
 pub struct WinitEventConverter {
     mods: Mods,
+    altgr: bool,
+    fallback: LatinFallback,
 }
 
 impl WinitEventConverter {
-    /// Returns the current modifiers state.
+    /// Returns the current modifiers state. [`Mods::ALTGR`] is included while <kbd>AltGr</kbd> key is pressed.
     ///
     /// ```
     /// use winit::event::{Event, WindowEvent};
@@ -322,7 +626,11 @@ impl WinitEventConverter {
     /// assert_eq!(converter.mods(), Mods::CTRL);
     /// ```
     pub fn mods(&self) -> Mods {
-        self.mods
+        if self.altgr {
+            self.mods.with_altgr(Platform::current())
+        } else {
+            self.mods
+        }
     }
 
     /// Update the current modifiers state. This method needs to be called only when you pass winit's `KeyEvent` to the
@@ -347,6 +655,47 @@ impl WinitEventConverter {
         self.mods = mods.into();
     }
 
+    /// Set the policy to fall back from non-Latin logical keys to the Latin keys at the same physical key positions.
+    /// The default policy is [`LatinFallback::WithModifiers`]. See [`LatinFallback`] for more details.
+    ///
+    /// ```
+    /// use keybinds::LatinFallback;
+    /// use keybinds::winit::WinitEventConverter;
+    ///
+    /// let mut converter = WinitEventConverter::default();
+    /// assert_eq!(converter.latin_fallback(), LatinFallback::WithModifiers);
+    ///
+    /// // Use the logical keys as-is
+    /// converter.set_latin_fallback(LatinFallback::Never);
+    /// assert_eq!(converter.latin_fallback(), LatinFallback::Never);
+    /// ```
+    pub fn set_latin_fallback(&mut self, fallback: LatinFallback) {
+        self.fallback = fallback;
+    }
+
+    /// Returns the policy to fall back from non-Latin logical keys to the Latin keys. See
+    /// [`WinitEventConverter::set_latin_fallback`] for more details.
+    pub fn latin_fallback(&self) -> LatinFallback {
+        self.fallback
+    }
+
+    fn key_input(
+        &self,
+        logical: &WinitKey,
+        physical: &PhysicalKey,
+        location: KeyLocation,
+    ) -> KeyInput {
+        let key = Key::from(logical);
+        let mut mods = self.mods();
+        if location == KeyLocation::Numpad {
+            mods |= Mods::NUMPAD;
+        }
+        match Key::from(physical) {
+            Key::Physical(code) => self.fallback.apply(KeyInput::with_code(key, mods, code)),
+            _ => KeyInput::new(key, mods),
+        }
+    }
+
     /// Convert winit's events into [`KeyInput`] instances with managing the current modifiers state. See the document
     /// for [`WinitEventConverter`] for an example.
     pub fn convert<E: WinitEvent>(&mut self, event: &E) -> KeyInput {
@@ -354,10 +703,297 @@ impl WinitEventConverter {
     }
 }
 
+/// Application handler wrapped by [`KeybindsHandler`]. In addition to winit's [`ApplicationHandler`], it receives the
+/// actions dispatched by the key bindings of each window. See [`KeybindsHandler`] for an example.
+pub trait ActionHandler<A, T: 'static = ()>: ApplicationHandler<T> {
+    /// Called when the key bindings of the window dispatch the action.
+    fn on_action(&mut self, event_loop: &ActiveEventLoop, window_id: WindowId, action: &A);
+}
+
+// Result of dispatching a window event
+enum Dispatched<A> {
+    // The action was dispatched
+    Action(A),
+    // The key input is a part of the ongoing key sequence or the release of the consumed key
+    Consumed,
+    // The event should be forwarded to the wrapped handler
+    Forward,
+}
+
+// Physical key or mouse button whose press was consumed by the key bindings
+#[derive(PartialEq, Eq, Hash)]
+enum Pressed {
+    Key(PhysicalKey),
+    Mouse(MouseButton),
+}
+
+// States to dispatch actions for a window
+struct Window<A> {
+    keybinds: Keybinds<A>,
+    converter: WinitEventConverter,
+    consumed: HashSet<Pressed>,
+}
+
+// States to dispatch actions for each window
+struct Dispatcher<A> {
+    keybinds: Keybinds<A>,
+    windows: HashMap<WindowId, Window<A>>,
+}
+
+impl<A: Clone> Dispatcher<A> {
+    fn window(&mut self, window_id: WindowId) -> &mut Window<A> {
+        self.windows.entry(window_id).or_insert_with(|| Window {
+            keybinds: self.keybinds.clone(),
+            converter: WinitEventConverter::default(),
+            consumed: HashSet::new(),
+        })
+    }
+
+    fn dispatch(&mut self, window_id: WindowId, event: &WindowEvent) -> Dispatched<A> {
+        if let WindowEvent::Destroyed = event {
+            self.windows.remove(&window_id);
+            return Dispatched::Forward;
+        }
+        let window = self.window(window_id);
+        let input = window.converter.convert(event);
+        let pressed = match event {
+            WindowEvent::KeyboardInput { event, .. } => {
+                Some((Pressed::Key(event.physical_key), event.state))
+            }
+            WindowEvent::MouseInput { button, state, .. } => {
+                Some((Pressed::Mouse(*button), *state))
+            }
+            WindowEvent::Focused(false) => {
+                // Releases while the window is not focused are never received
+                window.consumed.clear();
+                None
+            }
+            _ => None,
+        };
+
+        // Swallow the release of the key whose press was consumed so that the wrapped handler does not receive the
+        // release without the press
+        if let Some((pressed, ElementState::Released)) = &pressed {
+            return if window.consumed.remove(pressed) {
+                Dispatched::Consumed
+            } else {
+                Dispatched::Forward
+            };
+        }
+        if input.key() == Key::Ignored {
+            return Dispatched::Forward;
+        }
+        let dispatched = if let Some(action) = window.keybinds.dispatch(input) {
+            Dispatched::Action(action.clone())
+        } else if window.keybinds.is_ongoing() {
+            Dispatched::Consumed
+        } else {
+            return Dispatched::Forward;
+        };
+        if let Some((pressed, _)) = pressed {
+            window.consumed.insert(pressed);
+        }
+        dispatched
+    }
+}
+
+/// Adapter of winit's [`ApplicationHandler`] to dispatch actions with the key bindings. It wraps the application
+/// handler implementing [`ActionHandler`] and owns [`Keybinds`] and [`WinitEventConverter`] for each window.
+///
+/// Key and mouse events in `ApplicationHandler::window_event` are dispatched with the key bindings of the window and
+/// the dispatched actions are passed to [`ActionHandler::on_action`]. Window events which do not dispatch any action
+/// are forwarded to `ApplicationHandler::window_event` of the wrapped handler. For example, unmatched key events are
+/// forwarded as-is with their text so that IME and text input keep working. Key events consumed by the key bindings,
+/// including the key inputs of ongoing key sequences such as <kbd>Ctrl</kbd> + <kbd>x</kbd> in `Ctrl+x Ctrl+c`, are not
+/// forwarded. The releases of the consumed keys and mouse buttons are not forwarded either so that the wrapped handler
+/// never receives a release without its press. Other methods of `ApplicationHandler` are simply delegated to the
+/// wrapped handler.
+///
+/// The key bindings of each window are cloned from the key bindings passed to [`KeybindsHandler::new`] when the
+/// window receives its first event. They are dropped when the window is destroyed. To customize the key bindings of a
+/// specific window, use [`KeybindsHandler::keybinds_mut`].
+///
+/// ```no_run
+/// use keybinds::winit::{ActionHandler, KeybindsHandler};
+/// use keybinds::Keybinds;
+/// use winit::application::ApplicationHandler;
+/// use winit::event::WindowEvent;
+/// use winit::event_loop::{ActiveEventLoop, EventLoop};
+/// use winit::window::{Window, WindowId};
+///
+/// // Actions dispatched by key bindings
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     SayHi,
+///     Exit,
+/// }
+///
+/// #[derive(Default)]
+/// struct App {
+///     window: Option<Window>,
+/// }
+///
+/// impl ApplicationHandler for App {
+///     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+///         let window = event_loop.create_window(Window::default_attributes()).unwrap();
+///         self.window = Some(window);
+///     }
+///
+///     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
+///         // Events which did not dispatch any action are received here
+///         match event {
+///             WindowEvent::KeyboardInput { event, .. } => {
+///                 if let Some(text) = event.text {
+///                     println!("Text input: {text}");
+///                 }
+///             }
+///             WindowEvent::CloseRequested => event_loop.exit(),
+///             _ => {}
+///         }
+///     }
+/// }
+///
+/// impl ActionHandler<Action> for App {
+///     fn on_action(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, action: &Action) {
+///         match action {
+///             Action::SayHi => println!("Hi!"),
+///             Action::Exit => event_loop.exit(),
+///         }
+///     }
+/// }
+///
+/// let mut keybinds = Keybinds::default();
+/// keybinds.bind("Ctrl+h Ctrl+i", Action::SayHi).unwrap();
+/// keybinds.bind("Mod+q", Action::Exit).unwrap();
+///
+/// let mut handler = KeybindsHandler::new(App::default(), keybinds);
+///
+/// let event_loop = EventLoop::new().unwrap();
+/// event_loop.run_app(&mut handler).unwrap();
+/// ```
+pub struct KeybindsHandler<H, A> {
+    handler: H,
+    dispatcher: Dispatcher<A>,
+}
+
+impl<H, A: Clone> KeybindsHandler<H, A> {
+    /// Create a new adapter wrapping the application handler. The key bindings are used for each window.
+    pub fn new(handler: H, keybinds: Keybinds<A>) -> Self {
+        Self {
+            handler,
+            dispatcher: Dispatcher {
+                keybinds,
+                windows: HashMap::new(),
+            },
+        }
+    }
+
+    /// Returns the reference to the wrapped application handler.
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Returns the mutable reference to the wrapped application handler.
+    pub fn handler_mut(&mut self) -> &mut H {
+        &mut self.handler
+    }
+
+    /// Unwrap the application handler.
+    pub fn into_handler(self) -> H {
+        self.handler
+    }
+
+    /// Returns the key bindings of the window. `None` is returned when the window has not received any event yet.
+    pub fn keybinds(&self, window_id: WindowId) -> Option<&Keybinds<A>> {
+        self.dispatcher.windows.get(&window_id).map(|w| &w.keybinds)
+    }
+
+    /// Returns the mutable reference to the key bindings of the window. When the window has not received any event
+    /// yet, its key bindings are cloned from the key bindings passed to [`KeybindsHandler::new`].
+    ///
+    /// ```
+    /// use keybinds::winit::KeybindsHandler;
+    /// use keybinds::Keybinds;
+    /// use winit::window::WindowId;
+    ///
+    /// let mut keybinds = Keybinds::default();
+    /// keybinds.bind("Ctrl+s", "save").unwrap();
+    ///
+    /// let mut handler = KeybindsHandler::new((), keybinds);
+    ///
+    /// // Add a key binding only to the specific window
+    /// let id = WindowId::from(42);
+    /// handler.keybinds_mut(id).bind("Ctrl+w", "close").unwrap();
+    ///
+    /// assert_eq!(handler.keybinds(id).unwrap().as_slice().len(), 2);
+    /// ```
+    pub fn keybinds_mut(&mut self, window_id: WindowId) -> &mut Keybinds<A> {
+        &mut self.dispatcher.window(window_id).keybinds
+    }
+}
+
+impl<H, A, T> ApplicationHandler<T> for KeybindsHandler<H, A>
+where
+    H: ActionHandler<A, T>,
+    A: Clone,
+    T: 'static,
+{
+    fn new_events(&mut self, event_loop: &ActiveEventLoop, cause: StartCause) {
+        self.handler.new_events(event_loop, cause);
+    }
+
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        self.handler.resumed(event_loop);
+    }
+
+    fn user_event(&mut self, event_loop: &ActiveEventLoop, event: T) {
+        self.handler.user_event(event_loop, event);
+    }
+
+    fn window_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        window_id: WindowId,
+        event: WindowEvent,
+    ) {
+        match self.dispatcher.dispatch(window_id, &event) {
+            Dispatched::Action(action) => self.handler.on_action(event_loop, window_id, &action),
+            Dispatched::Consumed => {}
+            Dispatched::Forward => self.handler.window_event(event_loop, window_id, event),
+        }
+    }
+
+    fn device_event(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        device_id: DeviceId,
+        event: DeviceEvent,
+    ) {
+        self.handler.device_event(event_loop, device_id, event);
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        self.handler.about_to_wait(event_loop);
+    }
+
+    fn suspended(&mut self, event_loop: &ActiveEventLoop) {
+        self.handler.suspended(event_loop);
+    }
+
+    fn exiting(&mut self, event_loop: &ActiveEventLoop) {
+        self.handler.exiting(event_loop);
+    }
+
+    fn memory_warning(&mut self, event_loop: &ActiveEventLoop) {
+        self.handler.memory_warning(event_loop);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use winit::keyboard::NativeKey;
+    use crate::KeyStr;
+    use winit::keyboard::{NativeKey, NativeKeyCode};
     use NamedKey::*;
     use WinitKey::*;
 
@@ -366,11 +1002,27 @@ mod tests {
         assert_eq!(Key::from(Named(Space)), Key::Char(' '));
         assert_eq!(Key::from(Named(ArrowUp)), Key::Up);
         assert_eq!(Key::from(Named(F1)), Key::F1);
+        assert_eq!(Key::from(Named(BrowserBack)), Key::BrowserBack);
+        assert_eq!(Key::from(Named(MediaFastForward)), Key::FastForward);
+        assert_eq!(
+            Key::from(Named(LaunchApplication1)),
+            Key::LaunchApplication1
+        );
         assert_eq!(Key::from(Named(Control)), Key::Ignored);
         assert_eq!(Key::from(Named(TVInput)), Key::Unidentified);
         assert_eq!(Key::from(Character("a".into())), Key::Char('a'));
         assert_eq!(Key::from(Character("A".into())), Key::Char('A'));
         assert_eq!(Key::from(Character("foo".into())), Key::Unidentified);
+        assert_eq!(
+            Key::from(Character("👨\u{200D}👩\u{200D}👦".into())),
+            Key::Unidentified,
+        );
+        let family = KeyStr::new("👨\u{200D}👩\u{200D}👧").unwrap();
+        assert_eq!(
+            Key::from(Character("👨\u{200D}👩\u{200D}👧".into())),
+            Key::Str(family),
+        );
+        assert_eq!(Key::from(Character("".into())), Key::Unidentified);
         assert_eq!(
             Key::from(Unidentified(NativeKey::Unidentified)),
             Key::Unidentified,
@@ -378,6 +1030,23 @@ mod tests {
         assert_eq!(Key::from(Dead(None)), Key::Unidentified);
     }
 
+    #[test]
+    fn convert_physical_key() {
+        assert_eq!(
+            Key::from(PhysicalKey::Code(KeyCode::KeyW)),
+            Key::Physical(Code::KeyW),
+        );
+        assert_eq!(
+            Key::from(PhysicalKey::Code(KeyCode::SuperRight)),
+            Key::Physical(Code::MetaRight),
+        );
+        assert_eq!(
+            Key::from(PhysicalKey::Code(KeyCode::ArrowUp)),
+            Key::Physical(Code::ArrowUp),
+        );
+        assert_eq!(Key::from(PhysicalKey::Code(KeyCode::Fn)), Key::Unidentified);
+    }
+
     #[test]
     fn convert_modifiers_state() {
         assert_eq!(Mods::from(ModifiersState::CONTROL), Mods::CTRL);
@@ -429,4 +1098,283 @@ mod tests {
             KeyInput::new('x', Mods::CTRL),
         );
     }
+
+    #[test]
+    fn converter_latin_fallback() {
+        let mut conv = WinitEventConverter::default();
+        let cyrillic_c = Character("с".into());
+        let key_c = PhysicalKey::Code(KeyCode::KeyC);
+
+        assert_eq!(
+            conv.key_input(&cyrillic_c, &key_c, KeyLocation::Standard),
+            KeyInput::with_code('с', Mods::NONE, Code::KeyC),
+        );
+
+        conv.on_modifiers_changed(&ModifiersState::CONTROL.into());
+        assert_eq!(
+            conv.key_input(&cyrillic_c, &key_c, KeyLocation::Standard),
+            KeyInput::with_code('c', Mods::CTRL, Code::KeyC),
+        );
+        assert_eq!(
+            conv.key_input(
+                &cyrillic_c,
+                &PhysicalKey::Unidentified(NativeKeyCode::Unidentified),
+                KeyLocation::Standard
+            ),
+            KeyInput::new('с', Mods::CTRL),
+        );
+
+        conv.set_latin_fallback(LatinFallback::Never);
+        assert_eq!(
+            conv.key_input(&cyrillic_c, &key_c, KeyLocation::Standard),
+            KeyInput::with_code('с', Mods::CTRL, Code::KeyC),
+        );
+    }
+
+    #[test]
+    fn converter_altgr() {
+        let mut conv = WinitEventConverter::default();
+        // Windows reports AltGr as Ctrl+Alt and other platforms report it as Alt
+        let state = if cfg!(windows) {
+            ModifiersState::CONTROL | ModifiersState::ALT
+        } else {
+            ModifiersState::ALT
+        };
+        let mut mods = Modifiers::from(state);
+        conv.on_modifiers_changed(&mods);
+        // `KeyEvent` cannot be constructed outside winit
+        conv.altgr = true;
+
+        assert_eq!(conv.mods(), Mods::ALTGR);
+        assert_eq!(
+            conv.key_input(
+                &Character("@".into()),
+                &PhysicalKey::Code(KeyCode::KeyQ),
+                KeyLocation::Standard,
+            ),
+            KeyInput::with_code('@', Mods::ALTGR, Code::KeyQ),
+        );
+        // Latin fallback does not happen with AltGr
+        assert_eq!(
+            conv.key_input(
+                &Character("ђ".into()),
+                &PhysicalKey::Code(KeyCode::KeyC),
+                KeyLocation::Standard,
+            ),
+            KeyInput::with_code('ђ', Mods::ALTGR, Code::KeyC),
+        );
+
+        mods = Modifiers::from(ModifiersState::SHIFT);
+        conv.on_modifiers_changed(&mods);
+        assert_eq!(conv.mods(), Mods::ALTGR | Mods::SHIFT);
+
+        // Ctrl pressed with AltGr is kept
+        mods = Modifiers::from(state | ModifiersState::CONTROL);
+        conv.on_modifiers_changed(&mods);
+        let expected = if cfg!(windows) {
+            Mods::ALTGR
+        } else {
+            Mods::CTRL | Mods::ALTGR
+        };
+        assert_eq!(conv.mods(), expected);
+
+        conv.altgr = false;
+        conv.on_modifiers_changed(&Modifiers::from(ModifiersState::SHIFT));
+        assert_eq!(conv.mods(), Mods::SHIFT);
+    }
+
+    #[test]
+    fn converter_numpad() {
+        let conv = WinitEventConverter::default();
+        let plus = Character("+".into());
+        let add = PhysicalKey::Code(KeyCode::NumpadAdd);
+        assert_eq!(
+            conv.key_input(&plus, &add, KeyLocation::Numpad),
+            KeyInput::with_code('+', Mods::NUMPAD, Code::NumpadAdd),
+        );
+        assert_eq!(
+            conv.key_input(
+                &Named(Enter),
+                &PhysicalKey::Code(KeyCode::NumpadEnter),
+                KeyLocation::Numpad
+            ),
+            KeyInput::with_code(Key::Enter, Mods::NUMPAD, Code::NumpadEnter),
+        );
+        assert_eq!(
+            conv.key_input(
+                &plus,
+                &PhysicalKey::Code(KeyCode::Equal),
+                KeyLocation::Standard
+            ),
+            KeyInput::with_code('+', Mods::NONE, Code::Equal),
+        );
+    }
+
+    #[test]
+    fn convert_mouse() {
+        for (button, key) in [
+            (MouseButton::Left, Key::MouseLeft),
+            (MouseButton::Right, Key::MouseRight),
+            (MouseButton::Middle, Key::MouseMiddle),
+            (MouseButton::Back, Key::MouseBack),
+            (MouseButton::Forward, Key::MouseForward),
+            (MouseButton::Other(9), Key::Unidentified),
+        ] {
+            assert_eq!(Key::from(button), key, "button={button:?}");
+        }
+
+        for (delta, key) in [
+            (MouseScrollDelta::LineDelta(0.0, 1.0), Key::WheelUp),
+            (MouseScrollDelta::LineDelta(0.0, -1.0), Key::WheelDown),
+            (MouseScrollDelta::LineDelta(1.0, 0.0), Key::WheelLeft),
+            (MouseScrollDelta::LineDelta(-1.0, 0.0), Key::WheelRight),
+            (MouseScrollDelta::LineDelta(0.5, -1.0), Key::WheelDown),
+            (MouseScrollDelta::LineDelta(-3.0, 1.0), Key::WheelRight),
+            (MouseScrollDelta::LineDelta(0.0, 0.0), Key::Ignored),
+            (
+                MouseScrollDelta::PixelDelta((0.0, 12.0).into()),
+                Key::WheelUp,
+            ),
+            (
+                MouseScrollDelta::PixelDelta((-8.0, 0.0).into()),
+                Key::WheelRight,
+            ),
+        ] {
+            assert_eq!(Key::from(delta), key, "delta={delta:?}");
+        }
+    }
+
+    #[test]
+    fn converter_mouse() {
+        use winit::event::{DeviceId, TouchPhase};
+
+        let mut conv = WinitEventConverter::default();
+        conv.on_modifiers_changed(&ModifiersState::CONTROL.into());
+
+        let click = |state| WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state,
+            button: MouseButton::Left,
+        };
+        assert_eq!(
+            conv.convert(&click(ElementState::Pressed)),
+            KeyInput::new(Key::MouseLeft, Mods::CTRL),
+        );
+        assert_eq!(
+            conv.convert(&click(ElementState::Released)),
+            KeyInput::from(Key::Ignored),
+        );
+
+        let wheel = |delta| WindowEvent::MouseWheel {
+            device_id: DeviceId::dummy(),
+            delta,
+            phase: TouchPhase::Moved,
+        };
+        assert_eq!(
+            conv.convert(&wheel(MouseScrollDelta::LineDelta(0.0, 1.0))),
+            KeyInput::new(Key::WheelUp, Mods::CTRL),
+        );
+        assert_eq!(
+            conv.convert(&wheel(MouseScrollDelta::LineDelta(0.0, 0.0))),
+            KeyInput::from(Key::Ignored),
+        );
+    }
+
+    #[test]
+    fn dispatch_for_each_window() {
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("Ctrl+MouseLeft", 0).unwrap();
+        keybinds.bind("MouseRight MouseMiddle", 1).unwrap();
+        let mut handler = KeybindsHandler::new((), keybinds);
+
+        let window1 = WindowId::from(1);
+        let window2 = WindowId::from(2);
+        assert!(handler.keybinds(window1).is_none());
+        handler.keybinds_mut(window2).bind("MouseLeft", 2).unwrap();
+
+        let mouse = |button| WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state: ElementState::Pressed,
+            button,
+        };
+        let ctrl = WindowEvent::ModifiersChanged(ModifiersState::CONTROL.into());
+        let no_mods = WindowEvent::ModifiersChanged(ModifiersState::empty().into());
+        let mut dispatch = |id, event: &WindowEvent| match handler.dispatcher.dispatch(id, event) {
+            Dispatched::Action(action) => Some(action),
+            Dispatched::Consumed => None,
+            Dispatched::Forward => Some(-1),
+        };
+
+        // Modifiers state is tracked for each window
+        assert_eq!(dispatch(window1, &ctrl), Some(-1));
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Left)), Some(0));
+        assert_eq!(dispatch(window2, &mouse(MouseButton::Left)), Some(2));
+        assert_eq!(dispatch(window1, &no_mods), Some(-1));
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Left)), Some(-1));
+
+        // Ongoing key sequence is not forwarded
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Right)), None);
+        assert_eq!(dispatch(window2, &mouse(MouseButton::Middle)), Some(-1));
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Middle)), Some(1));
+
+        // Unmatched input cancels the ongoing key sequence and is forwarded
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Right)), None);
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Back)), Some(-1));
+        assert_eq!(dispatch(window1, &mouse(MouseButton::Middle)), Some(-1));
+
+        // Other events are forwarded
+        assert_eq!(dispatch(window1, &WindowEvent::Focused(true)), Some(-1));
+
+        // The states are dropped when the window is destroyed
+        assert_eq!(dispatch(window2, &WindowEvent::Destroyed), Some(-1));
+        assert!(handler.keybinds(window1).is_some());
+        assert!(handler.keybinds(window2).is_none());
+        assert_eq!(handler.keybinds_mut(window2).as_slice().len(), 2);
+    }
+
+    #[test]
+    fn swallow_releases_of_consumed_presses() {
+        let mut keybinds = Keybinds::default();
+        keybinds.bind("MouseRight MouseMiddle", 0).unwrap();
+        keybinds.bind("MouseBack", 1).unwrap();
+        let mut handler = KeybindsHandler::new((), keybinds);
+
+        let mouse = |button, state| WindowEvent::MouseInput {
+            device_id: DeviceId::dummy(),
+            state,
+            button,
+        };
+        let press = |button| mouse(button, ElementState::Pressed);
+        let release = |button| mouse(button, ElementState::Released);
+        let mut dispatch = |id, event: &WindowEvent| match handler.dispatcher.dispatch(id, event) {
+            Dispatched::Action(action) => Some(action),
+            Dispatched::Consumed => None,
+            Dispatched::Forward => Some(-1),
+        };
+        let window1 = WindowId::from(1);
+        let window2 = WindowId::from(2);
+
+        // Releases of the presses consumed by the ongoing key sequence and the action are not forwarded
+        assert_eq!(dispatch(window1, &press(MouseButton::Right)), None);
+        assert_eq!(dispatch(window1, &release(MouseButton::Right)), None);
+        assert_eq!(dispatch(window1, &press(MouseButton::Middle)), Some(0));
+        assert_eq!(dispatch(window1, &release(MouseButton::Middle)), None);
+        assert_eq!(dispatch(window1, &press(MouseButton::Back)), Some(1));
+        assert_eq!(dispatch(window1, &release(MouseButton::Back)), None);
+
+        // Releases of the forwarded presses are forwarded
+        assert_eq!(dispatch(window1, &press(MouseButton::Left)), Some(-1));
+        assert_eq!(dispatch(window1, &release(MouseButton::Left)), Some(-1));
+        assert_eq!(dispatch(window1, &release(MouseButton::Back)), Some(-1));
+
+        // Consumed presses are tracked for each window
+        assert_eq!(dispatch(window1, &press(MouseButton::Back)), Some(1));
+        assert_eq!(dispatch(window2, &release(MouseButton::Back)), Some(-1));
+        assert_eq!(dispatch(window1, &release(MouseButton::Back)), None);
+
+        // Consumed presses are forgotten when the window loses focus
+        assert_eq!(dispatch(window1, &press(MouseButton::Back)), Some(1));
+        assert_eq!(dispatch(window1, &WindowEvent::Focused(false)), Some(-1));
+        assert_eq!(dispatch(window1, &release(MouseButton::Back)), Some(-1));
+    }
 }